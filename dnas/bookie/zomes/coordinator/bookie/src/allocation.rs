@@ -0,0 +1,188 @@
+use crate::{
+    availability::check_availability,
+    booker_to_bookings::get_current_bookings_for_booker,
+    booking::{check_concurrent_bookings, create_booking},
+    booking_policy::get_booking_policy_for_resource,
+    booking_request::{
+        get_all_booking_requests_for_resource, is_booking_request_expired,
+        reject_booking_request,
+    },
+    quota::check_booking_quotas,
+    resource::get_resource_owner,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AllocateSlotInput {
+    pub resource_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    // Only the requests made before the deadline take part in the allocation
+    pub deadline: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AllocateSlotOutput {
+    pub bookings: Vec<Record>,
+    pub rejected_booking_requests: Vec<ActionHash>,
+}
+
+#[hdk_extern]
+pub fn allocate_slot(input: AllocateSlotInput) -> ExternResult<AllocateSlotOutput> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    if get_resource_owner(input.resource_hash.clone())?.ne(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the owner of the resource can allocate its slots"
+        ))));
+    }
+    if sys_time()? < input.deadline {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Slots can't be allocated before their deadline"
+        ))));
+    }
+    let policy = get_booking_policy_for_resource(input.resource_hash.clone())?;
+    let Some(rule) = policy.allocation_rule.clone() else {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The resource doesn't have an allocation rule"
+        ))));
+    };
+
+    let mut output = AllocateSlotOutput {
+        bookings: vec![],
+        rejected_booking_requests: vec![],
+    };
+    let now = sys_time()?;
+    let mut candidates: Vec<(Record, BookingRequest)> = Vec::new();
+    for record in get_all_booking_requests_for_resource(input.resource_hash.clone())? {
+        let booking_request = BookingRequest::try_from(record.clone())?;
+        if record.action().timestamp() > input.deadline
+            || !time_ranges_overlap(
+                &booking_request.start_time,
+                &booking_request.end_time,
+                &input.start_time,
+                &input.end_time,
+            )
+        {
+            continue;
+        }
+        // Requests that expired while waiting for the allocation lose it explicitly
+        if is_booking_request_expired(&record, &policy, &now)? {
+            let booking_request_hash = record.action_address().clone();
+            reject_booking_request(booking_request_hash.clone())?;
+            output.rejected_booking_requests.push(booking_request_hash);
+            continue;
+        }
+        candidates.push((record, booking_request));
+    }
+
+    for (record, booking_request) in order_candidates(&rule, &input.resource_hash, candidates)? {
+        let booking_request_hash = record.action_address().clone();
+        let requester = record.action().author().clone();
+        let available = check_availability(
+            &input.resource_hash,
+            &booking_request.start_time,
+            &booking_request.end_time,
+            &policy,
+            None,
+            &[requester.clone()],
+        )
+        .is_ok()
+            && check_concurrent_bookings(&[requester.clone()], &policy).is_ok()
+            && check_booking_quotas(
+                &[requester.clone()],
+                &input.resource_hash,
+                &booking_request.start_time,
+                &booking_request.end_time,
+                None,
+            )
+            .is_ok();
+
+        if available {
+            let booking = create_booking(Booking {
+                title: booking_request.title,
+                start_time: booking_request.start_time,
+                end_time: booking_request.end_time,
+                bookers: vec![requester],
+                booking_request_hash: Some(booking_request_hash),
+                reschedule_proposal_hash: None,
+                price: None,
+                resource_hash: booking_request.resource_hash,
+                resource_version_hash: None,
+            })?;
+            output.bookings.push(booking);
+        } else {
+            reject_booking_request(booking_request_hash.clone())?;
+            output.rejected_booking_requests.push(booking_request_hash);
+        }
+    }
+
+    Ok(output)
+}
+
+// Candidates are sorted by the rule of the resource, and then by the time of their request
+fn order_candidates(
+    rule: &AllocationRule,
+    resource_hash: &ActionHash,
+    candidates: Vec<(Record, BookingRequest)>,
+) -> ExternResult<Vec<(Record, BookingRequest)>> {
+    let now = sys_time()?;
+    let lottery_seed = match rule {
+        AllocationRule::Lottery => {
+            let mut hashes: Vec<ActionHash> = candidates
+                .iter()
+                .map(|(record, _)| record.action_address().clone())
+                .collect();
+            hashes.sort();
+            let seed: Vec<u8> = hashes
+                .into_iter()
+                .flat_map(|hash| hash.get_raw_39().to_vec())
+                .collect();
+            hash_blake2b(seed, 32)?
+        }
+        _ => vec![],
+    };
+
+    let mut keyed_candidates = Vec::new();
+    for (record, booking_request) in candidates {
+        let requester = record.action().author().clone();
+        let key: (i64, Vec<u8>) = match rule {
+            AllocationRule::Priority { roles } => {
+                let rank = roles
+                    .iter()
+                    .position(|role| role.agents.contains(&requester))
+                    .unwrap_or(roles.len());
+                (rank as i64, vec![])
+            }
+            AllocationRule::LeastRecentUsage => {
+                let last_usage = get_current_bookings_for_booker(requester)?
+                    .into_iter()
+                    .filter(|(_, booking)| {
+                        booking.resource_hash.eq(resource_hash) && booking.end_time <= now
+                    })
+                    .map(|(_, booking)| booking.end_time.as_micros())
+                    .max()
+                    .unwrap_or(i64::MIN);
+                (last_usage, vec![])
+            }
+            AllocationRule::Lottery => {
+                let mut input = lottery_seed.clone();
+                input.extend_from_slice(record.action_address().get_raw_39());
+                (0, hash_blake2b(input, 32)?)
+            }
+        };
+        keyed_candidates.push((key, record, booking_request));
+    }
+
+    keyed_candidates.sort_by(|(key_a, record_a, _), (key_b, record_b, _)| {
+        key_a
+            .cmp(key_b)
+            .then(record_a.action().timestamp().cmp(&record_b.action().timestamp()))
+            .then(record_a.action_address().cmp(record_b.action_address()))
+    });
+
+    Ok(keyed_candidates
+        .into_iter()
+        .map(|(_, record, booking_request)| (record, booking_request))
+        .collect())
+}
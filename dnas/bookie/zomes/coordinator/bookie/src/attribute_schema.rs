@@ -0,0 +1,141 @@
+use crate::{all_resources::get_all_resources, resource::get_resource};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[hdk_extern]
+pub fn create_attribute_schema(attribute_schema: AttributeSchema) -> ExternResult<Record> {
+    if get_attribute_schema_for_category(attribute_schema.category.clone())?.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The category already has an attribute schema"
+        ))));
+    }
+    let attribute_schema_hash =
+        create_entry(&EntryTypes::AttributeSchema(attribute_schema.clone()))?;
+    create_link(
+        attribute_schema_path(&attribute_schema.category).path_entry_hash()?,
+        attribute_schema_hash.clone(),
+        LinkTypes::CategoryToAttributeSchemas,
+        (),
+    )?;
+    let record = get(attribute_schema_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created AttributeSchema"
+        ))
+    ))?;
+    Ok(record)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateAttributeSchemaInput {
+    pub previous_attribute_schema_hash: ActionHash,
+    pub updated_attribute_schema: AttributeSchema,
+}
+
+// Resources keep referencing the version of the schema they were validated against
+#[hdk_extern]
+pub fn update_attribute_schema(input: UpdateAttributeSchemaInput) -> ExternResult<Record> {
+    let updated_attribute_schema_hash = update_entry(
+        input.previous_attribute_schema_hash,
+        &input.updated_attribute_schema,
+    )?;
+    let record = get(updated_attribute_schema_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the newly updated AttributeSchema"
+        ))),
+    )?;
+    Ok(record)
+}
+
+fn get_latest_attribute_schema(attribute_schema_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let Some(details) = get_details(attribute_schema_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    let record_details = match details {
+        Details::Entry(_) => Err(wasm_error!(WasmErrorInner::Guest(
+            "Malformed details".into()
+        ))),
+        Details::Record(record_details) => Ok(record_details),
+    }?;
+    match record_details
+        .updates
+        .iter()
+        .max_by_key(|update| update.action().timestamp())
+    {
+        Some(update) => get_latest_attribute_schema(update.action_address().clone()),
+        None => Ok(Some(record_details.record)),
+    }
+}
+
+// The latest version of the first schema created for the category
+#[hdk_extern]
+pub fn get_attribute_schema_for_category(category: String) -> ExternResult<Option<Record>> {
+    let links = get_links(
+        attribute_schema_path(&category).path_entry_hash()?,
+        LinkTypes::CategoryToAttributeSchemas,
+        None,
+    )?;
+    let Some(link) = links.into_iter().min_by_key(|link| link.timestamp) else {
+        return Ok(None);
+    };
+    get_latest_attribute_schema(ActionHash::from(link.target))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum AttributeCondition {
+    Equals { value: AttributeValue },
+    AtLeast { value: f64 },
+    AtMost { value: f64 },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AttributeFilter {
+    pub name: String,
+    pub condition: AttributeCondition,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetResourcesByAttributesInput {
+    pub category: Option<String>,
+    pub filters: Vec<AttributeFilter>,
+}
+
+fn matches_filter(resource: &Resource, filter: &AttributeFilter) -> bool {
+    let Some(value) = resource.attributes.get(&filter.name) else {
+        return false;
+    };
+    match (&filter.condition, value) {
+        (AttributeCondition::Equals { value: expected }, value) => expected.eq(value),
+        (AttributeCondition::AtLeast { value: min }, AttributeValue::Number { value }) => {
+            value >= min
+        }
+        (AttributeCondition::AtMost { value: max }, AttributeValue::Number { value }) => {
+            value <= max
+        }
+        _ => false,
+    }
+}
+
+#[hdk_extern]
+pub fn get_resources_by_attributes(
+    input: GetResourcesByAttributesInput,
+) -> ExternResult<Vec<Record>> {
+    let mut resources = Vec::new();
+    for original_record in get_all_resources(())? {
+        let Some(record) = get_resource(original_record.action_address().clone())? else {
+            continue;
+        };
+        let resource = Resource::try_from(record.clone())?;
+        if input.category.is_some() && resource.category.ne(&input.category) {
+            continue;
+        }
+        if input
+            .filters
+            .iter()
+            .all(|filter| matches_filter(&resource, filter))
+        {
+            resources.push(record);
+        }
+    }
+    Ok(resources)
+}
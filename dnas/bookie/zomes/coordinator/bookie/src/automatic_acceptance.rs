@@ -0,0 +1,107 @@
+use crate::{
+    availability::check_availability,
+    booking::create_booking,
+    booking_request::create_booking_request,
+    resource::{get_resource, get_resource_owner},
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum RequestBookingOutput {
+    Accepted { booking: Record },
+    Pending { booking_request: Record },
+}
+
+#[hdk_extern]
+pub fn request_booking(booking_request: BookingRequest) -> ExternResult<RequestBookingOutput> {
+    let resource_owner = get_resource_owner(booking_request.resource_hash.clone())?;
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+
+    if resource_owner != my_pub_key {
+        let response = call_remote(
+            resource_owner,
+            zome_info()?.name,
+            FunctionName::from("process_booking_request"),
+            None,
+            booking_request.clone(),
+        );
+        // If the owner is offline or doesn't accept the request automatically,
+        // it stays pending until they review it
+        if let Ok(ZomeCallResponse::Ok(result)) = response {
+            let booking: Option<Record> = result.decode().map_err(|e| wasm_error!(e))?;
+            if let Some(booking) = booking {
+                return Ok(RequestBookingOutput::Accepted { booking });
+            }
+        }
+    }
+
+    let booking_request = create_booking_request(booking_request)?;
+    Ok(RequestBookingOutput::Pending { booking_request })
+}
+
+#[hdk_extern]
+pub fn process_booking_request(booking_request: BookingRequest) -> ExternResult<Option<Record>> {
+    let requester = call_info()?.provenance;
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+
+    if get_resource_owner(booking_request.resource_hash.clone())? != my_pub_key {
+        return Ok(None);
+    }
+
+    let record = get_resource(booking_request.resource_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Resource not found"))
+    ))?;
+    let resource = Resource::try_from(record)?;
+    let policy = booking_policy_for_resource(&resource)?;
+
+    if !policy.auto_accept_requests || !is_member(requester.clone())? {
+        return Ok(None);
+    }
+
+    if check_availability(
+        &booking_request.resource_hash,
+        &booking_request.start_time,
+        &booking_request.end_time,
+        &policy,
+        None,
+        &[requester.clone()],
+    )
+    .is_err()
+    {
+        return Ok(None);
+    }
+
+    let booking = create_booking(Booking {
+        title: booking_request.title,
+        start_time: booking_request.start_time,
+        end_time: booking_request.end_time,
+        bookers: vec![requester],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: booking_request.resource_hash,
+        resource_version_hash: None,
+    })?;
+
+    Ok(Some(booking))
+}
+
+fn is_member(agent: AgentPubKey) -> ExternResult<bool> {
+    let response = call(
+        CallTargetCell::Local,
+        ZomeName::from("profiles"),
+        FunctionName::from("get_agent_profile"),
+        None,
+        agent,
+    )?;
+
+    match response {
+        ZomeCallResponse::Ok(result) => {
+            let profile: Option<Record> = result.decode().map_err(|e| wasm_error!(e))?;
+            Ok(profile.is_some())
+        }
+        _ => Ok(false),
+    }
+}
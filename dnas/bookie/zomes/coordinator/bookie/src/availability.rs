@@ -0,0 +1,116 @@
+use crate::{
+    booking::get_latest_booking,
+    condition_report::{get_resource_status, ResourceStatus},
+    holds::get_active_holds_for_resource,
+    maintenance::get_maintenance_windows_for_resource,
+    resource_hierarchy::{get_ancestor_resource_hashes, get_descendant_resource_hashes},
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+pub fn get_current_bookings_for_resource(
+    resource_hash: ActionHash,
+) -> ExternResult<Vec<(ActionHash, Booking)>> {
+    let links = get_links(resource_hash, LinkTypes::ResourceToBookings, None)?;
+
+    let mut bookings = Vec::new();
+    for link in links {
+        let booking_hash = ActionHash::from(link.target);
+        if let Some(record) = get_latest_booking(booking_hash.clone())? {
+            bookings.push((booking_hash, Booking::try_from(record)?));
+        }
+    }
+
+    Ok(bookings)
+}
+
+pub fn check_availability(
+    resource_hash: &ActionHash,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+    policy: &BookingPolicy,
+    ignored_booking_hash: Option<&ActionHash>,
+    bookers: &[AgentPubKey],
+) -> ExternResult<()> {
+    // A resource is unavailable when any resource containing it is, and its bookings
+    // and holds block the resources it contains and the ones that contain it
+    let ancestors = get_ancestor_resource_hashes(resource_hash.clone())?;
+    let descendants = get_descendant_resource_hashes(resource_hash.clone())?;
+    let mut blocking_resources = vec![resource_hash.clone()];
+    blocking_resources.extend(ancestors.iter().cloned());
+    let mut related_resources = blocking_resources.clone();
+    related_resources.extend(descendants);
+
+    // Validation only sees the out of service reports left open in the chain of the booking
+    // author, the ones filed by bookers or on containing resources are found through links
+    for blocking_resource_hash in blocking_resources.iter() {
+        if let ResourceStatus::OutOfService = get_resource_status(blocking_resource_hash.clone())? {
+            return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                "The resource is out of service"
+            ))));
+        }
+    }
+
+    let buffer = seconds_to_micros(policy.buffer_seconds.unwrap_or(0))?;
+    let start_with_buffer = Timestamp::from_micros(start_time.as_micros().saturating_sub(buffer));
+    let end_with_buffer = Timestamp::from_micros(end_time.as_micros().saturating_add(buffer));
+
+    for related_resource_hash in related_resources.iter() {
+        for (booking_hash, booking) in get_current_bookings_for_resource(related_resource_hash.clone())? {
+            if Some(&booking_hash) == ignored_booking_hash {
+                continue;
+            }
+            if time_ranges_overlap(
+                &start_with_buffer,
+                &end_with_buffer,
+                &booking.start_time,
+                &booking.end_time,
+            ) {
+                return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                    if related_resource_hash.eq(resource_hash) {
+                        "The resource is already booked for that time"
+                    } else {
+                        "A resource containing or contained in this one is already booked for that time"
+                    }
+                ))));
+            }
+        }
+    }
+
+    for blocking_resource_hash in blocking_resources.iter() {
+        for (_, window) in get_maintenance_windows_for_resource(blocking_resource_hash.clone())? {
+            if time_ranges_overlap(
+                &start_with_buffer,
+                &end_with_buffer,
+                &window.start_time,
+                &window.end_time,
+            ) {
+                return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                    "The resource is under maintenance for that time"
+                ))));
+            }
+        }
+    }
+
+    // Holds of the bookers themselves don't block them
+    for related_resource_hash in related_resources.iter() {
+        for record in get_active_holds_for_resource(related_resource_hash.clone())? {
+            if bookers.contains(record.action().author()) {
+                continue;
+            }
+            let hold = Hold::try_from(record)?;
+            if time_ranges_overlap(
+                &start_with_buffer,
+                &end_with_buffer,
+                &hold.start_time,
+                &hold.end_time,
+            ) {
+                return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                    "The resource is on hold for that time"
+                ))));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,32 +1,111 @@
+use crate::{
+    booking::{get_latest_booking, get_uncancelled_bookings},
+    booking_cancellation::get_cancellation_for_booking,
+};
 use bookie_integrity::*;
 use hdk::prelude::*;
 
-pub fn add_booking_for_booker(booker: AgentPubKey, booking_hash: ActionHash) -> ExternResult<()> {
-    create_link(
-        booker.clone(),
-        booking_hash.clone(),
-        LinkTypes::BookerToBookings,
-        (),
-    )?;
+pub fn add_booking_for_booker(
+    booker: AgentPubKey,
+    booking_hash: ActionHash,
+    booking: &Booking,
+) -> ExternResult<()> {
+    let tag = booker_to_bookings_tag(booker.clone(), booking, None)?;
+    create_link(booker, booking_hash, LinkTypes::BookerToBookings, tag)?;
 
     Ok(())
 }
 
+// Proves to the validation which of the bookings I linked to the booker before were cancelled
+// or rescheduled, for the ones that can still count towards the limits of the new booking
+pub fn booker_to_bookings_tag(
+    booker: AgentPubKey,
+    booking: &Booking,
+    booking_invitation_response_hash: Option<ActionHash>,
+) -> ExternResult<LinkTag> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    let since = sys_time()?
+        .min(start_of_week(&booking.start_time))
+        .min(start_of_month(&booking.start_time));
+    let mut tag = BookerToBookingsTag {
+        booking_invitation_response_hash,
+        ..Default::default()
+    };
+    for link in get_links(booker, LinkTypes::BookerToBookings, None)? {
+        if link.author.ne(&my_pub_key) {
+            continue;
+        }
+        let booking_hash = ActionHash::from(link.target);
+        let Some(record) = get(booking_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        let original_end_time = Booking::try_from(record)?.end_time;
+        match get_latest_booking(booking_hash.clone())? {
+            None => {
+                if original_end_time <= since {
+                    continue;
+                }
+                if let Some(cancellation) = get_cancellation_for_booking(booking_hash)? {
+                    tag.cancellation_hashes
+                        .push(cancellation.action_address().clone());
+                }
+            }
+            Some(latest) if latest.action_address().ne(&booking_hash) => {
+                let latest_end_time = Booking::try_from(latest.clone())?.end_time;
+                if original_end_time > since || latest_end_time > since {
+                    tag.booking_version_hashes
+                        .push(latest.action_address().clone());
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    let bytes = SerializedBytes::try_from(tag).map_err(|e| wasm_error!(e))?;
+    Ok(LinkTag::new(bytes.bytes().clone()))
+}
+
 #[hdk_extern]
 pub fn get_bookings_for_booker(booker: AgentPubKey) -> ExternResult<Vec<Record>> {
     let links = get_links(booker, LinkTypes::BookerToBookings, None)?;
 
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
-        .collect();
+    get_uncancelled_bookings(
+        links
+            .into_iter()
+            .map(|link| ActionHash::from(link.target))
+            .collect(),
+    )
+}
+
+pub fn get_upcoming_bookings_for_booker(booker: AgentPubKey) -> ExternResult<Vec<Record>> {
+    let links = get_links(booker, LinkTypes::BookerToBookings, None)?;
+    let now = sys_time()?;
+
+    let mut bookings = Vec::new();
+    for link in links {
+        let Some(record) = get_latest_booking(ActionHash::from(link.target))? else {
+            continue;
+        };
+        let booking = Booking::try_from(record.clone())?;
+        if booking.end_time > now {
+            bookings.push(record);
+        }
+    }
+
+    Ok(bookings)
+}
+
+pub fn get_current_bookings_for_booker(
+    booker: AgentPubKey,
+) -> ExternResult<Vec<(ActionHash, Booking)>> {
+    let links = get_links(booker, LinkTypes::BookerToBookings, None)?;
 
-    // Get the records to filter out the deleted ones
-    let records: Vec<Record> = HDK
-        .with(|hdk| hdk.borrow().get(get_input))?
-        .into_iter()
-        .filter_map(|r| r)
-        .collect();
+    let mut bookings = Vec::new();
+    for link in links {
+        let booking_hash = ActionHash::from(link.target);
+        if let Some(record) = get_latest_booking(booking_hash.clone())? {
+            bookings.push((booking_hash, Booking::try_from(record)?));
+        }
+    }
 
-    Ok(records)
+    Ok(bookings)
 }
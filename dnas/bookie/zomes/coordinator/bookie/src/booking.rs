@@ -1,11 +1,60 @@
 use crate::{
-    booker_to_bookings::add_booking_for_booker,
-    booking_request::remove_booking_request_for_resource,
+    availability::check_availability,
+    booker_to_bookings::{add_booking_for_booker, get_upcoming_bookings_for_booker},
+    booking_bundle::{add_booking_to_bundle, check_booking_bundle_open},
+    booking_policy::get_booking_policy_for_resource,
+    booking_request::{is_booking_request_expired, remove_booking_request_for_resource},
+    credit_transfer::transfer_credits_for_booking,
+    holds::release_my_holds,
+    pricing::price_resource_version,
+    quota::check_booking_quotas,
+    resource::get_resource,
+    waitlist::remove_booking_request_from_waitlist,
 };
 use bookie_integrity::*;
 use hdk::prelude::*;
 #[hdk_extern]
-pub fn create_booking(booking: Booking) -> ExternResult<Record> {
+pub fn create_booking(mut booking: Booking) -> ExternResult<Record> {
+    let policy = get_booking_policy_for_resource(booking.resource_hash.clone())?;
+    check_availability(
+        &booking.resource_hash,
+        &booking.start_time,
+        &booking.end_time,
+        &policy,
+        None,
+        &booking.bookers,
+    )?;
+    let mut bundle: Option<(ActionHash, AgentPubKey)> = None;
+    if let Some(booking_request_hash) = booking.booking_request_hash.clone() {
+        let record = get(booking_request_hash, GetOptions::default())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest(String::from("Booking Request was not found"))
+        ))?;
+        if is_booking_request_expired(&record, &policy, &sys_time()?)? {
+            return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                "The booking request has expired"
+            ))));
+        }
+        let requester = record.action().author().clone();
+        if let Some(bundle_hash) = BookingRequest::try_from(record)?.bundle_hash {
+            check_booking_bundle_open(bundle_hash.clone())?;
+            bundle = Some((bundle_hash, requester));
+        }
+    }
+    check_concurrent_bookings(&booking.bookers, &policy)?;
+    check_booking_quotas(
+        &booking.bookers,
+        &booking.resource_hash,
+        &booking.start_time,
+        &booking.end_time,
+        None,
+    )?;
+    apply_latest_resource_version(&mut booking)?;
+    // The booking is invalid while its author still holds the same time
+    release_my_holds(
+        booking.resource_hash.clone(),
+        &booking.start_time,
+        &booking.end_time,
+    )?;
     let booking_hash = create_entry(&EntryTypes::Booking(booking.clone()))?;
     if let Some(booking_request_hash) = booking.booking_request_hash.clone() {
         create_link(
@@ -15,10 +64,11 @@ pub fn create_booking(booking: Booking) -> ExternResult<Record> {
             (),
         )?;
         remove_booking_request_for_resource(booking_request_hash.clone())?;
+        remove_booking_request_from_waitlist(booking_request_hash.clone())?;
     }
 
-    for booker in booking.bookers {
-        add_booking_for_booker(booker.clone(), booking_hash.clone())?;
+    for booker in booking.bookers.iter() {
+        add_booking_for_booker(booker.clone(), booking_hash.clone(), &booking)?;
     }
 
     create_link(
@@ -27,17 +77,87 @@ pub fn create_booking(booking: Booking) -> ExternResult<Record> {
         LinkTypes::ResourceToBookings,
         (),
     )?;
-
+    // Bundled bookings are only charged once every owner has accepted the bundle
+    match bundle {
+        Some((bundle_hash, requester)) => {
+            add_booking_to_bundle(bundle_hash, requester, booking_hash.clone())?
+        }
+        None => {
+            transfer_credits_for_booking(booking_hash.clone(), &booking)?;
+        }
+    }
     let record = get(booking_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest(String::from("Could not find the newly created Booking"))
     ))?;
     Ok(record)
 }
+pub fn check_concurrent_bookings(
+    bookers: &[AgentPubKey],
+    policy: &BookingPolicy,
+) -> ExternResult<()> {
+    let Some(max_concurrent_bookings) = policy.max_concurrent_bookings_per_agent else {
+        return Ok(());
+    };
+    for booker in bookers.iter() {
+        let upcoming_bookings = get_upcoming_bookings_for_booker(booker.clone())?;
+        if upcoming_bookings.len() >= max_concurrent_bookings as usize {
+            return Err(wasm_error!(WasmErrorInner::Guest(format!(
+                "Agent {} already has the maximum of {} upcoming bookings",
+                booker, max_concurrent_bookings
+            ))));
+        }
+    }
+    Ok(())
+}
+// Bookings are validated against the policy and the pricing of the latest version of the
+// resource at the time they are made or rescheduled
+pub fn apply_latest_resource_version(booking: &mut Booking) -> ExternResult<()> {
+    let resource_record = get_resource(booking.resource_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Resource not found"))
+    ))?;
+    let quantity = booking.price.as_ref().map(|price| price.quantity).unwrap_or(1);
+    booking.resource_version_hash = Some(resource_record.action_address().clone());
+    booking.price = price_resource_version(
+        resource_record,
+        &booking.start_time,
+        &booking.end_time,
+        quantity,
+    )?;
+    Ok(())
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateInstantBookingInput {
+    pub resource_hash: ActionHash,
+    pub title: String,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+}
+#[hdk_extern]
+pub fn create_instant_booking(input: CreateInstantBookingInput) -> ExternResult<Record> {
+    let policy = get_booking_policy_for_resource(input.resource_hash.clone())?;
+    if policy.requests_require_approval {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "This resource can't be booked without the approval of its owner"
+        ))));
+    }
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    create_booking(Booking {
+        title: input.title,
+        start_time: input.start_time,
+        end_time: input.end_time,
+        bookers: vec![my_pub_key],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: input.resource_hash,
+        resource_version_hash: None,
+    })
+}
 #[hdk_extern]
 pub fn get_booking(original_booking_hash: ActionHash) -> ExternResult<Option<Record>> {
     get_latest_booking(original_booking_hash)
 }
-fn get_latest_booking(booking_hash: ActionHash) -> ExternResult<Option<Record>> {
+pub fn get_latest_booking(booking_hash: ActionHash) -> ExternResult<Option<Record>> {
     let details = get_details(booking_hash, GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Booking not found".into())
     ))?;
@@ -47,31 +167,14 @@ fn get_latest_booking(booking_hash: ActionHash) -> ExternResult<Option<Record>>
         ))),
         Details::Record(record_details) => Ok(record_details),
     }?;
-    if record_details.deletes.len() > 0 {
-        return Ok(None);
-    }
+    // Cancellations delete the version they cancel, so only deleting the latest version
+    // cancels the booking
     match record_details.updates.last() {
         Some(update) => get_latest_booking(update.action_address().clone()),
+        None if record_details.deletes.len() > 0 => Ok(None),
         None => Ok(Some(record_details.record)),
     }
 }
-#[derive(Serialize, Deserialize, Debug)]
-pub struct UpdateBookingInput {
-    pub previous_booking_hash: ActionHash,
-    pub updated_booking: Booking,
-}
-#[hdk_extern]
-pub fn update_booking(input: UpdateBookingInput) -> ExternResult<Record> {
-    let updated_booking_hash = update_entry(input.previous_booking_hash, &input.updated_booking)?;
-    let record = get(updated_booking_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
-        WasmErrorInner::Guest(String::from("Could not find the newly updated Booking"))
-    ))?;
-    Ok(record)
-}
-#[hdk_extern]
-pub fn delete_booking(original_booking_hash: ActionHash) -> ExternResult<ActionHash> {
-    delete_entry(original_booking_hash)
-}
 #[hdk_extern]
 pub fn get_bookings_for_booking_request(
     booking_request_hash: ActionHash,
@@ -81,28 +184,33 @@ pub fn get_bookings_for_booking_request(
         LinkTypes::BookingRequestToBookings,
         None,
     )?;
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
-        .collect();
-    let records: Vec<Record> = HDK
-        .with(|hdk| hdk.borrow().get(get_input))?
-        .into_iter()
-        .filter_map(|r| r)
-        .collect();
-    Ok(records)
+    get_uncancelled_bookings(
+        links
+            .into_iter()
+            .map(|link| ActionHash::from(link.target))
+            .collect(),
+    )
 }
 #[hdk_extern]
 pub fn get_bookings_for_resource(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
     let links = get_links(resource_hash, LinkTypes::ResourceToBookings, None)?;
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
-        .collect();
-    let records: Vec<Record> = HDK
-        .with(|hdk| hdk.borrow().get(get_input))?
-        .into_iter()
-        .filter_map(|r| r)
-        .collect();
+    get_uncancelled_bookings(
+        links
+            .into_iter()
+            .map(|link| ActionHash::from(link.target))
+            .collect(),
+    )
+}
+// Original records of the bookings whose latest version hasn't been deleted by a cancellation
+pub fn get_uncancelled_bookings(booking_hashes: Vec<ActionHash>) -> ExternResult<Vec<Record>> {
+    let mut records = Vec::new();
+    for booking_hash in booking_hashes {
+        if get_latest_booking(booking_hash.clone())?.is_none() {
+            continue;
+        }
+        if let Some(record) = get(booking_hash, GetOptions::default())? {
+            records.push(record);
+        }
+    }
     Ok(records)
 }
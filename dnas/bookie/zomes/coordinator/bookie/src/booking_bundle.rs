@@ -0,0 +1,375 @@
+use crate::{
+    booking::get_latest_booking,
+    booking_cancellation::{cancel_booking, CancelBookingInput},
+    booking_request::{cancel_booking_request, create_booking_request},
+    credit_transfer::{get_credit_transfers_for_booking, transfer_credits_for_booking},
+    notifications::{notify, Notification},
+    resource::get_resource_owner,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RequestBookingBundleOutput {
+    pub booking_bundle: Record,
+    pub booking_requests: Vec<Record>,
+}
+
+// Creates the bundle and one booking request for each of its resources, so that
+// either all the requests are made or none is
+#[hdk_extern]
+pub fn request_booking_bundle(booking_bundle: BookingBundle) -> ExternResult<RequestBookingBundleOutput> {
+    let bundle_hash = create_entry(&EntryTypes::BookingBundle(booking_bundle.clone()))?;
+
+    let mut booking_requests = Vec::new();
+    for resource_hash in booking_bundle.resource_hashes {
+        let record = create_booking_request(BookingRequest {
+            resource_hash,
+            title: booking_bundle.title.clone(),
+            comment: booking_bundle.comment.clone(),
+            start_time: booking_bundle.start_time,
+            end_time: booking_bundle.end_time,
+            bundle_hash: Some(bundle_hash.clone()),
+        })?;
+        create_link(
+            bundle_hash.clone(),
+            record.action_address().clone(),
+            LinkTypes::BundleToBookingRequests,
+            (),
+        )?;
+        booking_requests.push(record);
+    }
+
+    let record = get(bundle_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly created BookingBundle"))
+    ))?;
+    Ok(RequestBookingBundleOutput {
+        booking_bundle: record,
+        booking_requests,
+    })
+}
+
+#[hdk_extern]
+pub fn get_booking_bundle(bundle_hash: ActionHash) -> ExternResult<Option<Record>> {
+    get(bundle_hash, GetOptions::default())
+}
+
+// Includes the requests that were rejected or cancelled, together with their deletes
+fn get_bundle_booking_requests_details(
+    bundle_hash: ActionHash,
+) -> ExternResult<Vec<RecordDetails>> {
+    let links = get_links(bundle_hash, LinkTypes::BundleToBookingRequests, None)?;
+    let mut requests = Vec::new();
+    for link in links {
+        if let Some(Details::Record(record_details)) =
+            get_details(ActionHash::from(link.target), GetOptions::default())?
+        {
+            requests.push(record_details);
+        }
+    }
+    Ok(requests)
+}
+
+#[hdk_extern]
+pub fn get_booking_requests_for_bundle(bundle_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    Ok(get_bundle_booking_requests_details(bundle_hash)?
+        .into_iter()
+        .filter(|record_details| record_details.deletes.is_empty())
+        .map(|record_details| record_details.record)
+        .collect())
+}
+
+// Bookings made from the requests of the bundle, including the cancelled ones
+fn get_all_bookings_for_bundle(bundle_hash: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let links = get_links(bundle_hash, LinkTypes::BundleToBookings, None)?;
+    Ok(links
+        .into_iter()
+        .map(|link| ActionHash::from(link.target))
+        .collect())
+}
+
+#[hdk_extern]
+pub fn get_bookings_for_bundle(bundle_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let mut bookings = Vec::new();
+    for booking_hash in get_all_bookings_for_bundle(bundle_hash)? {
+        if let Some(record) = get_latest_booking(booking_hash)? {
+            bookings.push(record);
+        }
+    }
+    Ok(bookings)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum BookingBundleStatus {
+    // Waiting for the remaining owners to accept their requests
+    Pending { accepted: u32, total: u32 },
+    // Every owner has accepted, all the bookings exist and can be charged
+    Confirmed,
+    // These owners rejected their requests, so the bundle can't be booked
+    Rejected { resource_hashes: Vec<ActionHash> },
+    Cancelled,
+}
+
+#[hdk_extern]
+pub fn get_booking_bundle_status(bundle_hash: ActionHash) -> ExternResult<BookingBundleStatus> {
+    let record = get(bundle_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking Bundle not found"))
+    ))?;
+    let requester = record.action().author().clone();
+    let booking_bundle = BookingBundle::try_from(record)?;
+
+    let mut rejected_resource_hashes = Vec::new();
+    let mut cancelled = false;
+    for record_details in get_bundle_booking_requests_details(bundle_hash.clone())? {
+        let booking_request = BookingRequest::try_from(record_details.record)?;
+        for delete in record_details.deletes {
+            if delete.action().author().eq(&requester) {
+                cancelled = true;
+            } else if !rejected_resource_hashes.contains(&booking_request.resource_hash) {
+                rejected_resource_hashes.push(booking_request.resource_hash.clone());
+            }
+        }
+    }
+    if !rejected_resource_hashes.is_empty() {
+        return Ok(BookingBundleStatus::Rejected {
+            resource_hashes: rejected_resource_hashes,
+        });
+    }
+
+    let mut accepted: u32 = 0;
+    for booking_hash in get_all_bookings_for_bundle(bundle_hash)? {
+        match get_latest_booking(booking_hash)? {
+            Some(_) => accepted += 1,
+            None => cancelled = true,
+        }
+    }
+    if cancelled {
+        return Ok(BookingBundleStatus::Cancelled);
+    }
+    let total = booking_bundle.resource_hashes.len() as u32;
+    if accepted >= total {
+        return Ok(BookingBundleStatus::Confirmed);
+    }
+    Ok(BookingBundleStatus::Pending { accepted, total })
+}
+
+// Called by the owner accepting a bundled request, after the booking has been created.
+// The owner completing the bundle charges its bookings and asks the other owners to
+// charge theirs
+pub fn add_booking_to_bundle(
+    bundle_hash: ActionHash,
+    requester: AgentPubKey,
+    booking_hash: ActionHash,
+) -> ExternResult<()> {
+    create_link(
+        bundle_hash.clone(),
+        booking_hash,
+        LinkTypes::BundleToBookings,
+        (),
+    )?;
+    let BookingBundleStatus::Confirmed = get_booking_bundle_status(bundle_hash.clone())? else {
+        return Ok(());
+    };
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    for owner in get_bundle_booking_owners(bundle_hash.clone())? {
+        if owner.eq(&my_pub_key) {
+            charge_bundle_bookings(bundle_hash.clone())?;
+        } else {
+            // Owners that can't be reached charge their bookings by calling
+            // `charge_bundle_bookings` themselves
+            let response = call_remote(
+                owner.clone(),
+                zome_info()?.name,
+                FunctionName::from("charge_bundle_bookings"),
+                None,
+                bundle_hash.clone(),
+            );
+            if !matches!(response, Ok(ZomeCallResponse::Ok(_))) {
+                warn!("Owner {} couldn't charge their bundled bookings: {:?}", owner, response);
+            }
+        }
+    }
+    notify(
+        vec![requester],
+        Notification::BookingBundleConfirmed { bundle_hash },
+    )?;
+    Ok(())
+}
+
+fn get_bundle_booking_owners(bundle_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let mut owners: Vec<AgentPubKey> = Vec::new();
+    for record in get_bookings_for_bundle(bundle_hash)? {
+        let booking = Booking::try_from(record)?;
+        let owner = get_resource_owner(booking.resource_hash)?;
+        if !owners.contains(&owner) {
+            owners.push(owner);
+        }
+    }
+    Ok(owners)
+}
+
+// Charges the bookings of my resources in a confirmed bundle that haven't been charged yet
+#[hdk_extern]
+pub fn charge_bundle_bookings(bundle_hash: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let BookingBundleStatus::Confirmed = get_booking_bundle_status(bundle_hash.clone())? else {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the bookings of confirmed bundles can be charged"
+        ))));
+    };
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    let mut credit_transfer_hashes = Vec::new();
+    for booking_hash in get_all_bookings_for_bundle(bundle_hash)? {
+        let Some(record) = get(booking_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        if record.action().author().ne(&my_pub_key)
+            || !get_credit_transfers_for_booking(booking_hash.clone())?.is_empty()
+        {
+            continue;
+        }
+        let booking = Booking::try_from(record)?;
+        credit_transfer_hashes.extend(transfer_credits_for_booking(booking_hash, &booking)?);
+    }
+    Ok(credit_transfer_hashes)
+}
+
+pub fn check_booking_bundle_open(bundle_hash: ActionHash) -> ExternResult<()> {
+    match get_booking_bundle_status(bundle_hash)? {
+        BookingBundleStatus::Rejected { .. } => Err(wasm_error!(WasmErrorInner::Guest(
+            String::from("Another resource of the bundle was rejected")
+        ))),
+        BookingBundleStatus::Cancelled => Err(wasm_error!(WasmErrorInner::Guest(
+            String::from("The booking bundle was cancelled")
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// Called by the owner rejecting a bundled request: the owners that already accepted are
+// asked to release their bookings, and the requester is notified of the ones that couldn't
+pub fn reject_bundled_booking_request(
+    bundle_hash: ActionHash,
+    requester: AgentPubKey,
+    booking_request_hash: ActionHash,
+    resource_hash: ActionHash,
+) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    let input = ReleaseBundleBookingsInput {
+        bundle_hash: bundle_hash.clone(),
+        rejected_booking_request_hash: booking_request_hash,
+    };
+    let mut unreleased_owners: Vec<AgentPubKey> = Vec::new();
+    for owner in get_bundle_booking_owners(bundle_hash.clone())? {
+        if owner.eq(&my_pub_key) {
+            release_bundle_bookings(input.clone())?;
+        } else {
+            let response = call_remote(
+                owner.clone(),
+                zome_info()?.name,
+                FunctionName::from("release_bundle_bookings"),
+                None,
+                input.clone(),
+            );
+            if !matches!(response, Ok(ZomeCallResponse::Ok(_))) {
+                warn!("Owner {} couldn't release their bundled bookings: {:?}", owner, response);
+                unreleased_owners.push(owner);
+            }
+        }
+    }
+
+    let mut unreleased_resource_hashes = Vec::new();
+    for record in get_bookings_for_bundle(bundle_hash.clone())? {
+        let booking = Booking::try_from(record)?;
+        if unreleased_owners.contains(&get_resource_owner(booking.resource_hash.clone())?) {
+            unreleased_resource_hashes.push(booking.resource_hash);
+        }
+    }
+    notify(
+        vec![requester],
+        Notification::BookingBundleRejected {
+            bundle_hash,
+            resource_hash,
+            unreleased_resource_hashes,
+        },
+    )?;
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseBundleBookingsInput {
+    pub bundle_hash: ActionHash,
+    pub rejected_booking_request_hash: ActionHash,
+}
+
+// Cancels the bookings of my resources in the bundle, once the owner of another
+// of its resources has rejected their request
+#[hdk_extern]
+pub fn release_bundle_bookings(input: ReleaseBundleBookingsInput) -> ExternResult<()> {
+    let caller = call_info()?.provenance;
+    let record = get(input.rejected_booking_request_hash.clone(), GetOptions::default())?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Booking Request was not found"
+        ))))?;
+    let rejected_booking_request = BookingRequest::try_from(record)?;
+    if rejected_booking_request.bundle_hash.ne(&Some(input.bundle_hash.clone()))
+        || get_resource_owner(rejected_booking_request.resource_hash)?.ne(&caller)
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the owner that rejected a request of the bundle can release its bookings"
+        ))));
+    }
+
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    for record in get_bookings_for_bundle(input.bundle_hash)? {
+        let booking_hash = record.action_address().clone();
+        let booking = Booking::try_from(record)?;
+        if get_resource_owner(booking.resource_hash)?.eq(&my_pub_key) {
+            cancel_booking(CancelBookingInput {
+                booking_hash,
+                booking_version_hash: None,
+                reason: String::from("Another resource of the bundle was rejected"),
+            })?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CancelBookingBundleInput {
+    pub bundle_hash: ActionHash,
+    pub reason: String,
+}
+
+// Cancels all the bookings of the bundle together, and withdraws its pending requests
+#[hdk_extern]
+pub fn cancel_booking_bundle(input: CancelBookingBundleInput) -> ExternResult<()> {
+    let record = get(input.bundle_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking Bundle not found"))
+    ))?;
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    if record.action().author().ne(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the requester can cancel a booking bundle"
+        ))));
+    }
+
+    let mut accepted_booking_request_hashes = Vec::new();
+    for record in get_bookings_for_bundle(input.bundle_hash.clone())? {
+        let booking_hash = record.action_address().clone();
+        let booking = Booking::try_from(record)?;
+        accepted_booking_request_hashes.extend(booking.booking_request_hash);
+        cancel_booking(CancelBookingInput {
+            booking_hash,
+            booking_version_hash: None,
+            reason: input.reason.clone(),
+        })?;
+    }
+    for record in get_booking_requests_for_bundle(input.bundle_hash)? {
+        let booking_request_hash = record.action_address().clone();
+        if !accepted_booking_request_hashes.contains(&booking_request_hash) {
+            cancel_booking_request(booking_request_hash)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use crate::{
+    booking::get_latest_booking,
+    booking_invitation::get_booking_participants,
+    booking_policy::get_booking_policy_for_booking,
+    credit_transfer::refund_credits_for_booking,
+    notifications::{notify, Notification},
+    resource::get_resource_owner,
+    waitlist::promote_from_waitlist,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CancelBookingInput {
+    pub booking_hash: ActionHash,
+    // Version of the booking shown to the canceller, the latest one if None
+    pub booking_version_hash: Option<ActionHash>,
+    pub reason: String,
+}
+
+#[hdk_extern]
+pub fn cancel_booking(input: CancelBookingInput) -> ExternResult<Record> {
+    let record = match input.booking_version_hash {
+        Some(booking_version_hash) => get(booking_version_hash, GetOptions::default())?,
+        None => get_latest_booking(input.booking_hash.clone())?,
+    }
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "Booking not found"
+    ))))?;
+    let booking_version_hash = record.action_address().clone();
+    let booking = Booking::try_from(record)?;
+
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    let owner = get_resource_owner(booking.resource_hash.clone())?;
+    let is_owner = owner.eq(&my_pub_key);
+    if !is_owner && !booking.bookers.contains(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the bookers or the owner of the resource can cancel a booking"
+        ))));
+    }
+
+    let policy = get_booking_policy_for_booking(&booking)?;
+    let late = match &policy.cancellation_policy {
+        Some(cancellation_policy) if !is_owner => {
+            let notice = booking
+                .start_time
+                .as_micros()
+                .checked_sub(sys_time()?.as_micros())
+                .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                    "The start time of the booking is out of range"
+                ))))?;
+            notice < seconds_to_micros(cancellation_policy.notice_seconds)?
+        }
+        _ => false,
+    };
+    if let (true, Some(cancellation_policy)) = (late, &policy.cancellation_policy) {
+        if let LateCancellation::Forbidden = cancellation_policy.late_cancellation {
+            return Err(wasm_error!(WasmErrorInner::Guest(format!(
+                "Bookings can't be cancelled less than {} seconds before they start",
+                cancellation_policy.notice_seconds
+            ))));
+        }
+    }
+
+    // Accepted invitees are found through the booking, so before it's deleted
+    let mut participants = get_booking_participants(input.booking_hash.clone())?;
+    participants.push(owner);
+
+    let cancellation_hash = create_entry(&EntryTypes::BookingCancellation(BookingCancellation {
+        booking_hash: input.booking_hash.clone(),
+        booking_version_hash: booking_version_hash.clone(),
+        reason: input.reason,
+        fee_applies: late,
+    }))?;
+    // The cancelled version must be deleted right after its cancellation
+    delete_entry(booking_version_hash)?;
+    create_link(
+        input.booking_hash.clone(),
+        cancellation_hash.clone(),
+        LinkTypes::BookingToCancellations,
+        (),
+    )?;
+
+    // Only the bookers keep paying for the bookings they cancel
+    if is_owner {
+        refund_credits_for_booking(input.booking_hash.clone())?;
+    }
+
+    promote_from_waitlist(
+        booking.resource_hash.clone(),
+        &booking.start_time,
+        &booking.end_time,
+        &input.booking_hash,
+    )?;
+
+    notify(
+        participants,
+        Notification::BookingCancelled {
+            booking_hash: input.booking_hash,
+            cancellation_hash: cancellation_hash.clone(),
+        },
+    )?;
+
+    let record = get(cancellation_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created BookingCancellation"
+        ))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_cancellation_for_booking(booking_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let links = get_links(booking_hash, LinkTypes::BookingToCancellations, None)?;
+    let Some(link) = links.into_iter().min_by_key(|link| link.timestamp) else {
+        return Ok(None);
+    };
+    get(ActionHash::from(link.target), GetOptions::default())
+}
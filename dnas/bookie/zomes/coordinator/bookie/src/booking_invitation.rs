@@ -0,0 +1,316 @@
+use crate::{
+    booker_to_bookings::booker_to_bookings_tag,
+    booking::get_latest_booking,
+    credit_transfer::transfer_credits_for_participant,
+    notifications::{notify, Notification},
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InviteToBookingInput {
+    pub booking_hash: ActionHash,
+    pub invitee: AgentPubKey,
+}
+
+#[hdk_extern]
+pub fn invite_to_booking(input: InviteToBookingInput) -> ExternResult<Record> {
+    check_invitations_capacity(input.booking_hash.clone())?;
+
+    let booking_invitation_hash = create_entry(&EntryTypes::BookingInvitation(BookingInvitation {
+        booking_hash: input.booking_hash.clone(),
+        invitee: input.invitee.clone(),
+    }))?;
+    create_link(
+        input.booking_hash.clone(),
+        booking_invitation_hash.clone(),
+        LinkTypes::BookingToInvitations,
+        (),
+    )?;
+    create_link(
+        input.invitee.clone(),
+        booking_invitation_hash.clone(),
+        LinkTypes::InviteeToInvitations,
+        (),
+    )?;
+
+    notify(
+        vec![input.invitee],
+        Notification::BookingInvitationReceived {
+            booking_hash: input.booking_hash,
+            booking_invitation_hash: booking_invitation_hash.clone(),
+        },
+    )?;
+
+    let record = get(booking_invitation_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the newly created BookingInvitation"
+        ))),
+    )?;
+    Ok(record)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RespondToBookingInvitationInput {
+    pub booking_invitation_hash: ActionHash,
+    pub accept: bool,
+}
+
+#[hdk_extern]
+pub fn respond_to_booking_invitation(
+    input: RespondToBookingInvitationInput,
+) -> ExternResult<Record> {
+    let invitation_record = get(input.booking_invitation_hash.clone(), GetOptions::default())?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Booking Invitation not found"
+        ))))?;
+    let inviter = invitation_record.action().author().clone();
+    let booking_invitation = BookingInvitation::try_from(invitation_record)?;
+    if get_response_for_invitation(input.booking_invitation_hash.clone())?.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The invitation has already been answered"
+        ))));
+    }
+    if input.accept {
+        check_booking_capacity(booking_invitation.booking_hash.clone())?;
+    }
+    let inviter_activity = get_agent_activity(
+        inviter.clone(),
+        ChainQueryFilter::new(),
+        ActivityRequest::Full,
+    )?;
+    let inviter_activity_hash = inviter_activity
+        .valid_activity
+        .into_iter()
+        .max_by_key(|(seq, _)| *seq)
+        .map(|(_, hash)| hash)
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not get the activity of the inviter"
+        ))))?;
+
+    let response_hash = create_entry(&EntryTypes::BookingInvitationResponse(
+        BookingInvitationResponse {
+            booking_invitation_hash: input.booking_invitation_hash.clone(),
+            accepted: input.accept,
+            inviter_activity_hash,
+        },
+    ))?;
+    create_link(
+        input.booking_invitation_hash.clone(),
+        response_hash.clone(),
+        LinkTypes::InvitationToResponses,
+        (),
+    )?;
+    if input.accept {
+        let record = get_latest_booking(booking_invitation.booking_hash.clone())?.ok_or(
+            wasm_error!(WasmErrorInner::Guest(String::from("Booking not found"))),
+        )?;
+        let tag = booker_to_bookings_tag(
+            booking_invitation.invitee.clone(),
+            &Booking::try_from(record)?,
+            Some(response_hash.clone()),
+        )?;
+        create_link(
+            booking_invitation.invitee.clone(),
+            booking_invitation.booking_hash.clone(),
+            LinkTypes::BookerToBookings,
+            tag,
+        )?;
+        // Invitees pay their share of the booking as they join it
+        let original_record = get(booking_invitation.booking_hash.clone(), GetOptions::default())?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Booking not found"
+            ))))?;
+        transfer_credits_for_participant(
+            booking_invitation.booking_hash.clone(),
+            &Booking::try_from(original_record)?,
+            booking_invitation.invitee,
+        )?;
+    }
+
+    notify(
+        vec![inviter],
+        Notification::BookingInvitationAnswered {
+            booking_hash: booking_invitation.booking_hash,
+            booking_invitation_hash: input.booking_invitation_hash,
+            accepted: input.accept,
+        },
+    )?;
+
+    let record = get(response_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created BookingInvitationResponse"
+        ))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn withdraw_booking_invitation(booking_invitation_hash: ActionHash) -> ExternResult<()> {
+    let record = get(booking_invitation_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking Invitation not found"))
+    ))?;
+    let booking_invitation = BookingInvitation::try_from(record)?;
+    let links = get_links(
+        booking_invitation.booking_hash,
+        LinkTypes::BookingToInvitations,
+        None,
+    )?;
+    for link in links {
+        if ActionHash::from(link.target.clone()).eq(&booking_invitation_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(booking_invitation_hash)?;
+    Ok(())
+}
+
+fn get_response_record_for_invitation(
+    booking_invitation_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+    let links = get_links(booking_invitation_hash, LinkTypes::InvitationToResponses, None)?;
+    let Some(link) = links.into_iter().next() else {
+        return Ok(None);
+    };
+    get(ActionHash::from(link.target), GetOptions::default())
+}
+
+fn get_response_for_invitation(
+    booking_invitation_hash: ActionHash,
+) -> ExternResult<Option<BookingInvitationResponse>> {
+    get_response_record_for_invitation(booking_invitation_hash)?
+        .map(BookingInvitationResponse::try_from)
+        .transpose()
+}
+
+// The acceptance of the agent's invitation to the booking, which proves they participate in it
+pub fn get_accepted_invitation_response_hash(
+    booking_hash: ActionHash,
+    invitee: &AgentPubKey,
+) -> ExternResult<Option<ActionHash>> {
+    for record in get_invitations_for_booking(booking_hash)? {
+        let booking_invitation_hash = record.action_address().clone();
+        if BookingInvitation::try_from(record)?.invitee.ne(invitee) {
+            continue;
+        }
+        if let Some(response_record) = get_response_record_for_invitation(booking_invitation_hash)? {
+            let response_hash = response_record.action_address().clone();
+            if BookingInvitationResponse::try_from(response_record)?.accepted {
+                return Ok(Some(response_hash));
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[hdk_extern]
+pub fn get_invitations_for_booking(booking_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(booking_hash, LinkTypes::BookingToInvitations, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(records)
+}
+
+// Invitations that haven't been answered nor withdrawn yet
+#[hdk_extern]
+pub fn get_my_pending_booking_invitations(_: ()) -> ExternResult<Vec<Record>> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    let links = get_links(my_pub_key, LinkTypes::InviteeToInvitations, None)?;
+
+    let mut invitations = Vec::new();
+    for link in links {
+        let booking_invitation_hash = ActionHash::from(link.target);
+        let Some(record) = get(booking_invitation_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        if get_response_for_invitation(booking_invitation_hash)?.is_none() {
+            invitations.push(record);
+        }
+    }
+    Ok(invitations)
+}
+
+// The bookers of the booking plus the invitees who accepted to join it
+#[hdk_extern]
+pub fn get_booking_participants(booking_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let record = get_latest_booking(booking_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking not found"))
+    ))?;
+    let mut participants = Booking::try_from(record)?.bookers;
+
+    for record in get_invitations_for_booking(booking_hash)? {
+        let booking_invitation_hash = record.action_address().clone();
+        let booking_invitation = BookingInvitation::try_from(record)?;
+        if let Some(response) = get_response_for_invitation(booking_invitation_hash)? {
+            if response.accepted && !participants.contains(&booking_invitation.invitee) {
+                participants.push(booking_invitation.invitee);
+            }
+        }
+    }
+    Ok(participants)
+}
+
+// Capacity of the version of the resource the booking was made against
+fn get_booking_capacity(booking: &Booking) -> ExternResult<Option<u32>> {
+    let resource_version_hash = booking
+        .resource_version_hash
+        .clone()
+        .unwrap_or(booking.resource_hash.clone());
+    let record = get(resource_version_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Resource not found"))
+    ))?;
+    Ok(Resource::try_from(record)?.capacity)
+}
+
+// Mirrors the validation, which counts every invitation to the booking in the chain of the
+// primary booker, including the withdrawn ones
+fn check_invitations_capacity(booking_hash: ActionHash) -> ExternResult<()> {
+    let record = get_latest_booking(booking_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking not found"))
+    ))?;
+    let booking = Booking::try_from(record)?;
+    let Some(capacity) = get_booking_capacity(&booking)? else {
+        return Ok(());
+    };
+    let my_invitations = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::BookingInvitation.try_into()?)
+            .action_type(ActionType::Create)
+            .include_entries(true),
+    )?;
+    let mut invitations = 0;
+    for record in my_invitations {
+        if BookingInvitation::try_from(record)?.booking_hash.eq(&booking_hash) {
+            invitations += 1;
+        }
+    }
+    if booking.bookers.len() + invitations >= capacity as usize {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The booking is already at the capacity of the resource"
+        ))));
+    }
+    Ok(())
+}
+
+fn check_booking_capacity(booking_hash: ActionHash) -> ExternResult<()> {
+    let record = get_latest_booking(booking_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking not found"))
+    ))?;
+    let booking = Booking::try_from(record)?;
+    let Some(capacity) = get_booking_capacity(&booking)? else {
+        return Ok(());
+    };
+    if get_booking_participants(booking_hash)?.len() >= capacity as usize {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The booking is already at the capacity of the resource"
+        ))));
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::resource::get_resource;
+use bookie_integrity::*;
+use hdk::prelude::*;
+#[hdk_extern]
+pub fn get_booking_policy(_: ()) -> ExternResult<BookingPolicy> {
+    booking_policy()
+}
+#[hdk_extern]
+pub fn get_booking_policy_for_resource(resource_hash: ActionHash) -> ExternResult<BookingPolicy> {
+    let record = get_resource(resource_hash)?.ok_or(wasm_error!(WasmErrorInner::Guest(
+        String::from("Resource not found")
+    )))?;
+    let resource = Resource::try_from(record)?;
+    booking_policy_for_resource(&resource)
+}
+// Policy of the version of the resource the booking was made against
+pub fn get_booking_policy_for_booking(booking: &Booking) -> ExternResult<BookingPolicy> {
+    let resource_version_hash = booking
+        .resource_version_hash
+        .clone()
+        .unwrap_or(booking.resource_hash.clone());
+    let record = get(resource_version_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Resource not found"))
+    ))?;
+    let resource = Resource::try_from(record)?;
+    booking_policy_for_resource(&resource)
+}
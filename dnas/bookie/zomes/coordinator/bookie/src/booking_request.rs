@@ -1,7 +1,32 @@
+use crate::{
+    booker_to_bookings::get_upcoming_bookings_for_booker,
+    booking_bundle::reject_bundled_booking_request,
+    booking_policy::get_booking_policy_for_resource, holds::release_my_holds,
+    quota::check_booking_quotas, resource::get_resource_owner,
+    waitlist::remove_booking_request_from_waitlist,
+};
 use bookie_integrity::*;
 use hdk::prelude::*;
 #[hdk_extern]
 pub fn create_booking_request(booking_request: BookingRequest) -> ExternResult<Record> {
+    let my_agent_pub_key = agent_info()?.agent_latest_pubkey;
+    let policy = get_booking_policy_for_resource(booking_request.resource_hash.clone())?;
+    if let Some(max_concurrent_bookings) = policy.max_concurrent_bookings_per_agent {
+        let upcoming_bookings = get_upcoming_bookings_for_booker(my_agent_pub_key.clone())?;
+        if upcoming_bookings.len() >= max_concurrent_bookings as usize {
+            return Err(wasm_error!(WasmErrorInner::Guest(format!(
+                "You already have the maximum of {} upcoming bookings",
+                max_concurrent_bookings
+            ))));
+        }
+    }
+    check_booking_quotas(
+        &[my_agent_pub_key.clone()],
+        &booking_request.resource_hash,
+        &booking_request.start_time,
+        &booking_request.end_time,
+        None,
+    )?;
     let booking_request_hash = create_entry(&EntryTypes::BookingRequest(booking_request.clone()))?;
     create_link(
         booking_request.resource_hash.clone(),
@@ -14,13 +39,17 @@ pub fn create_booking_request(booking_request: BookingRequest) -> ExternResult<R
             "Could not find the newly created BookingRequest"
         ))
     ))?;
-    let my_agent_pub_key = agent_info()?.agent_latest_pubkey;
     create_link(
         my_agent_pub_key,
         booking_request_hash.clone(),
         LinkTypes::MyBookingRequests,
         (),
     )?;
+    release_my_holds(
+        booking_request.resource_hash.clone(),
+        &booking_request.start_time,
+        &booking_request.end_time,
+    )?;
     Ok(record)
 }
 
@@ -28,6 +57,7 @@ pub fn create_booking_request(booking_request: BookingRequest) -> ExternResult<R
 pub struct GetBookingRequestOutput {
     booking_request: Record,
     deletes: Vec<SignedActionHashed>,
+    expired: bool,
 }
 
 #[hdk_extern]
@@ -46,10 +76,14 @@ pub fn get_booking_request(
         ))),
         Details::Record(record_details) => Ok(record_details),
     }?;
+    let request = BookingRequest::try_from(booking_request.clone())?;
+    let policy = get_booking_policy_for_resource(request.resource_hash)?;
+    let expired = is_booking_request_expired(&record_details.record, &policy, &sys_time()?)?;
 
     Ok(Some(GetBookingRequestOutput {
         booking_request,
         deletes: record_details.deletes,
+        expired,
     }))
 }
 fn get_latest_booking_request(booking_request_hash: ActionHash) -> ExternResult<Option<Record>> {
@@ -87,9 +121,27 @@ pub fn update_booking_request(input: UpdateBookingRequestInput) -> ExternResult<
 }
 #[hdk_extern]
 pub fn reject_booking_request(original_booking_request_hash: ActionHash) -> ExternResult<()> {
+    let record = get(original_booking_request_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Booking Request was not found"
+        ))),
+    )?;
+    let requester = record.action().author().clone();
+    let booking_request = BookingRequest::try_from(record)?;
+
     remove_booking_request_for_resource(original_booking_request_hash.clone())?;
+    remove_booking_request_from_waitlist(original_booking_request_hash.clone())?;
     delete_entry(original_booking_request_hash.clone())?;
 
+    if let Some(bundle_hash) = booking_request.bundle_hash {
+        reject_bundled_booking_request(
+            bundle_hash,
+            requester,
+            original_booking_request_hash,
+            booking_request.resource_hash,
+        )?;
+    }
+
     Ok(())
 }
 
@@ -106,6 +158,7 @@ pub fn cancel_booking_request(original_booking_request_hash: ActionHash) -> Exte
     }
 
     remove_booking_request_for_resource(original_booking_request_hash.clone())?;
+    remove_booking_request_from_waitlist(original_booking_request_hash.clone())?;
     delete_entry(original_booking_request_hash.clone())?;
 
     Ok(())
@@ -113,6 +166,18 @@ pub fn cancel_booking_request(original_booking_request_hash: ActionHash) -> Exte
 
 #[hdk_extern]
 pub fn get_booking_requests_for_resource(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let policy = get_booking_policy_for_resource(resource_hash.clone())?;
+    let now = sys_time()?;
+    let mut records: Vec<Record> = vec![];
+    for record in get_all_booking_requests_for_resource(resource_hash)? {
+        if !is_booking_request_expired(&record, &policy, &now)? {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+pub fn get_all_booking_requests_for_resource(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
     let links = get_links(resource_hash, LinkTypes::ResourceToBookingRequests, None)?;
     let get_input: Vec<GetInput> = links
         .into_iter()
@@ -126,6 +191,51 @@ pub fn get_booking_requests_for_resource(resource_hash: ActionHash) -> ExternRes
     Ok(records)
 }
 
+// A request expires once its start time has passed, or once the owner has
+// not answered it within the response deadline of the resource
+pub fn is_booking_request_expired(
+    original_record: &Record,
+    policy: &BookingPolicy,
+    now: &Timestamp,
+) -> ExternResult<bool> {
+    let booking_request = BookingRequest::try_from(original_record.clone())?;
+    if booking_request.start_time <= *now {
+        return Ok(true);
+    }
+    if let Some(response_seconds) = policy.request_response_seconds {
+        let deadline = original_record.action().timestamp().as_micros()
+            + seconds_to_micros(response_seconds)?;
+        if deadline <= now.as_micros() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[hdk_extern]
+pub fn clear_expired_booking_requests(resource_hash: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    if get_resource_owner(resource_hash.clone())?.ne(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the owner of the resource can clear its booking requests"
+        ))));
+    }
+
+    let policy = get_booking_policy_for_resource(resource_hash.clone())?;
+    let now = sys_time()?;
+    let mut cleared: Vec<ActionHash> = vec![];
+    for record in get_all_booking_requests_for_resource(resource_hash)? {
+        if is_booking_request_expired(&record, &policy, &now)? {
+            let booking_request_hash = record.action_address().clone();
+            remove_booking_request_for_resource(booking_request_hash.clone())?;
+            remove_booking_request_from_waitlist(booking_request_hash.clone())?;
+            cleared.push(booking_request_hash);
+        }
+    }
+
+    Ok(cleared)
+}
+
 pub fn remove_booking_request_for_resource(
     original_booking_request_hash: ActionHash,
 ) -> ExternResult<()> {
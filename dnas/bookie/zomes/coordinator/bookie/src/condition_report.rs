@@ -0,0 +1,112 @@
+use crate::{
+    notifications::{notify, Notification},
+    resource::get_resource_owner,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[hdk_extern]
+pub fn create_condition_report(condition_report: ConditionReport) -> ExternResult<Record> {
+    let condition_report_hash =
+        create_entry(&EntryTypes::ConditionReport(condition_report.clone()))?;
+    create_link(
+        condition_report.resource_hash.clone(),
+        condition_report_hash.clone(),
+        LinkTypes::ResourceToConditionReports,
+        (),
+    )?;
+
+    notify(
+        vec![get_resource_owner(condition_report.resource_hash.clone())?],
+        Notification::ConditionReported {
+            resource_hash: condition_report.resource_hash,
+            condition_report_hash: condition_report_hash.clone(),
+        },
+    )?;
+
+    let record = get(condition_report_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created ConditionReport"
+        ))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn resolve_condition_report(original_condition_report_hash: ActionHash) -> ExternResult<Record> {
+    let record = get_latest_condition_report(original_condition_report_hash)?;
+    let mut condition_report = ConditionReport::try_from(record.clone())?;
+    if condition_report.resolved {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The report has already been resolved"
+        ))));
+    }
+    condition_report.resolved = true;
+
+    let updated_condition_report_hash =
+        update_entry(record.action_address().clone(), &condition_report)?;
+    let record = get(updated_condition_report_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the newly updated ConditionReport"
+        ))),
+    )?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_condition_report(original_condition_report_hash: ActionHash) -> ExternResult<Record> {
+    get_latest_condition_report(original_condition_report_hash)
+}
+
+fn get_latest_condition_report(condition_report_hash: ActionHash) -> ExternResult<Record> {
+    let details = get_details(condition_report_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("ConditionReport not found".into())
+    ))?;
+    let record_details = match details {
+        Details::Entry(_) => Err(wasm_error!(WasmErrorInner::Guest(
+            "Malformed details".into()
+        ))),
+        Details::Record(record_details) => Ok(record_details),
+    }?;
+    match record_details.updates.last() {
+        Some(update) => get_latest_condition_report(update.action_address().clone()),
+        None => Ok(record_details.record),
+    }
+}
+
+#[hdk_extern]
+pub fn get_condition_reports_for_resource(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let mut links = get_links(resource_hash, LinkTypes::ResourceToConditionReports, None)?;
+    links.sort_by_key(|link| link.timestamp);
+
+    let mut records: Vec<Record> = vec![];
+    for link in links {
+        records.push(get_latest_condition_report(ActionHash::from(link.target))?);
+    }
+    Ok(records)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum ResourceStatus {
+    Ok,
+    NeedsAttention,
+    OutOfService,
+}
+
+#[hdk_extern]
+pub fn get_resource_status(resource_hash: ActionHash) -> ExternResult<ResourceStatus> {
+    let mut status = ResourceStatus::Ok;
+    for record in get_condition_reports_for_resource(resource_hash)? {
+        let condition_report = ConditionReport::try_from(record)?;
+        if condition_report.resolved {
+            continue;
+        }
+        match condition_report.severity {
+            ConditionReportSeverity::OutOfService => return Ok(ResourceStatus::OutOfService),
+            ConditionReportSeverity::NeedsAttention => status = ResourceStatus::NeedsAttention,
+            ConditionReportSeverity::Minor => {}
+        }
+    }
+    Ok(status)
+}
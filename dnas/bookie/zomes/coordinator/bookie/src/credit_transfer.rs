@@ -0,0 +1,230 @@
+use bookie_integrity::*;
+use hdk::prelude::*;
+use std::collections::BTreeMap;
+
+pub fn transfer_credits_for_booking(
+    booking_hash: ActionHash,
+    booking: &Booking,
+) -> ExternResult<Vec<ActionHash>> {
+    transfer_credits(booking_hash, booking, &booking.bookers)
+}
+
+// Charges an accepted invitee the same share as each of the bookers
+pub fn transfer_credits_for_participant(
+    booking_hash: ActionHash,
+    booking: &Booking,
+    participant: AgentPubKey,
+) -> ExternResult<Vec<ActionHash>> {
+    transfer_credits(booking_hash, booking, &[participant])
+}
+
+fn transfer_credits(
+    booking_hash: ActionHash,
+    booking: &Booking,
+    payers: &[AgentPubKey],
+) -> ExternResult<Vec<ActionHash>> {
+    let Some(settings) = booking_policy()?.credit_ledger else {
+        return Ok(vec![]);
+    };
+    let Some((amount, unit)) = credit_for_booking(booking, &settings.unit) else {
+        return Ok(vec![]);
+    };
+    let resource_record = get(booking.resource_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from("Resource not found"))),
+    )?;
+    let owner = resource_record.action().author().clone();
+
+    let mut credit_transfer_hashes = Vec::new();
+    for payer in payers.iter() {
+        if payer.eq(&owner) {
+            continue;
+        }
+        let credit_transfer = CreditTransfer {
+            booking_hash: booking_hash.clone(),
+            from: payer.clone(),
+            to: owner.clone(),
+            amount,
+            unit: unit.clone(),
+            refund_of: None,
+        };
+        let credit_transfer_hash = match &settings.external_zome {
+            Some(zome) => create_external_credit_transfer(zome.clone(), credit_transfer)?,
+            None => create_credit_transfer(credit_transfer)?,
+        };
+        create_link(
+            booking_hash.clone(),
+            credit_transfer_hash.clone(),
+            LinkTypes::BookingToCreditTransfers,
+            (),
+        )?;
+        credit_transfer_hashes.push(credit_transfer_hash);
+    }
+
+    Ok(credit_transfer_hashes)
+}
+
+// Gives back the charges of the booking that haven't been refunded yet, after the owner has
+// cancelled it
+pub fn refund_credits_for_booking(booking_hash: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let Some(settings) = booking_policy()?.credit_ledger else {
+        return Ok(vec![]);
+    };
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+
+    let mut charges: Vec<(ActionHash, CreditTransfer)> = Vec::new();
+    let mut refunded_hashes: Vec<ActionHash> = Vec::new();
+    for record in get_credit_transfers_for_booking(booking_hash.clone())? {
+        let credit_transfer_hash = record.action_address().clone();
+        let credit_transfer = CreditTransfer::try_from(record)?;
+        match credit_transfer.refund_of.clone() {
+            Some(refunded_hash) => refunded_hashes.push(refunded_hash),
+            None if credit_transfer.to.eq(&my_pub_key) => {
+                charges.push((credit_transfer_hash, credit_transfer))
+            }
+            None => {}
+        }
+    }
+
+    let mut refund_hashes = Vec::new();
+    for (charge_hash, charge) in charges {
+        if refunded_hashes.contains(&charge_hash) {
+            continue;
+        }
+        let refund = CreditTransfer {
+            booking_hash: booking_hash.clone(),
+            from: my_pub_key.clone(),
+            to: charge.from,
+            amount: charge.amount,
+            unit: charge.unit,
+            refund_of: Some(charge_hash),
+        };
+        let refund_hash = match &settings.external_zome {
+            Some(zome) => create_external_credit_transfer(zome.clone(), refund)?,
+            None => create_credit_transfer(refund)?,
+        };
+        create_link(
+            booking_hash.clone(),
+            refund_hash.clone(),
+            LinkTypes::BookingToCreditTransfers,
+            (),
+        )?;
+        refund_hashes.push(refund_hash);
+    }
+
+    Ok(refund_hashes)
+}
+
+fn create_credit_transfer(credit_transfer: CreditTransfer) -> ExternResult<ActionHash> {
+    let credit_transfer_hash = create_entry(&EntryTypes::CreditTransfer(credit_transfer.clone()))?;
+    create_link(
+        credit_transfer.from,
+        credit_transfer_hash.clone(),
+        LinkTypes::AgentToCreditTransfers,
+        (),
+    )?;
+    create_link(
+        credit_transfer.to,
+        credit_transfer_hash.clone(),
+        LinkTypes::AgentToCreditTransfers,
+        (),
+    )?;
+    Ok(credit_transfer_hash)
+}
+
+// External ledgers must expose `create_credit_transfer(CreditTransfer) -> ActionHash` and
+// `get_credit_balance(AgentPubKey) -> BTreeMap<String, i64>`
+fn create_external_credit_transfer(
+    zome: String,
+    credit_transfer: CreditTransfer,
+) -> ExternResult<ActionHash> {
+    let response = call(
+        CallTargetCell::Local,
+        ZomeName::from(zome),
+        FunctionName::from("create_credit_transfer"),
+        None,
+        credit_transfer,
+    )?;
+
+    match response {
+        ZomeCallResponse::Ok(result) => result.decode().map_err(|e| wasm_error!(e)),
+        _ => Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "Failed to create the credit transfer in the external ledger: {:?}",
+            response
+        )))),
+    }
+}
+
+#[hdk_extern]
+pub fn get_credit_transfers_for_booking(booking_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(booking_hash, LinkTypes::BookingToCreditTransfers, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(records)
+}
+
+#[hdk_extern]
+pub fn get_credit_transfers_for_agent(agent: AgentPubKey) -> ExternResult<Vec<Record>> {
+    let links = get_links(agent, LinkTypes::AgentToCreditTransfers, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(records)
+}
+
+// Balances per unit: positive for agents who have earned more credits than they have spent
+#[hdk_extern]
+pub fn get_credit_balance(agent: AgentPubKey) -> ExternResult<BTreeMap<String, i64>> {
+    if let Some(zome) = booking_policy()?.credit_ledger.and_then(|s| s.external_zome) {
+        let response = call(
+            CallTargetCell::Local,
+            ZomeName::from(zome),
+            FunctionName::from("get_credit_balance"),
+            None,
+            agent,
+        )?;
+        return match response {
+            ZomeCallResponse::Ok(result) => result.decode().map_err(|e| wasm_error!(e)),
+            _ => Err(wasm_error!(WasmErrorInner::Guest(format!(
+                "Failed to get the balance from the external ledger: {:?}",
+                response
+            )))),
+        };
+    }
+
+    // Any sum of u64 amounts fits in an i128, only the final balances can be out of range
+    let mut totals: BTreeMap<String, i128> = BTreeMap::new();
+    for record in get_credit_transfers_for_agent(agent.clone())? {
+        let credit_transfer = CreditTransfer::try_from(record)?;
+        let entry = totals.entry(credit_transfer.unit).or_insert(0);
+        if credit_transfer.to.eq(&agent) {
+            *entry += credit_transfer.amount as i128;
+        }
+        if credit_transfer.from.eq(&agent) {
+            *entry -= credit_transfer.amount as i128;
+        }
+    }
+    let mut balance: BTreeMap<String, i64> = BTreeMap::new();
+    for (unit, total) in totals {
+        let total = i64::try_from(total).map_err(|_| {
+            wasm_error!(WasmErrorInner::Guest(format!(
+                "The balance of {} is out of range",
+                unit
+            )))
+        })?;
+        balance.insert(unit, total);
+    }
+    Ok(balance)
+}
@@ -0,0 +1,102 @@
+use crate::{availability::check_availability, booking_policy::get_booking_policy_for_resource};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateHoldInput {
+    pub resource_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+}
+
+#[hdk_extern]
+pub fn create_hold(input: CreateHoldInput) -> ExternResult<Record> {
+    let policy = get_booking_policy_for_resource(input.resource_hash.clone())?;
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    check_availability(
+        &input.resource_hash,
+        &input.start_time,
+        &input.end_time,
+        &policy,
+        None,
+        &[my_pub_key],
+    )?;
+
+    let expires_at = Timestamp::from_micros(
+        sys_time()?.as_micros() + seconds_to_micros(policy.max_hold_seconds)?,
+    );
+    let hold_hash = create_entry(&EntryTypes::Hold(Hold {
+        resource_hash: input.resource_hash.clone(),
+        start_time: input.start_time,
+        end_time: input.end_time,
+        expires_at,
+    }))?;
+    create_link(
+        input.resource_hash,
+        hold_hash.clone(),
+        LinkTypes::ResourceToHolds,
+        (),
+    )?;
+
+    let record = get(hold_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly created Hold"))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn release_hold(hold_hash: ActionHash) -> ExternResult<()> {
+    let record = get(hold_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Hold was not found"))
+    ))?;
+    let hold = Hold::try_from(record)?;
+
+    let links = get_links(hold.resource_hash, LinkTypes::ResourceToHolds, None)?;
+    for link in links {
+        if ActionHash::from(link.target.clone()).eq(&hold_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(hold_hash)?;
+
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_active_holds_for_resource(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let now = sys_time()?;
+    let links = get_links(resource_hash, LinkTypes::ResourceToHolds, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .filter(|record| match Hold::try_from(record.clone()) {
+            Ok(hold) => hold.expires_at > now,
+            Err(_) => false,
+        })
+        .collect();
+    Ok(records)
+}
+
+pub fn release_my_holds(
+    resource_hash: ActionHash,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+
+    for record in get_active_holds_for_resource(resource_hash)? {
+        let hold = Hold::try_from(record.clone())?;
+        if record.action().author().eq(&my_pub_key)
+            && time_ranges_overlap(start_time, end_time, &hold.start_time, &hold.end_time)
+        {
+            release_hold(record.action_address().clone())?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use crate::{
+    booking::get_latest_booking, booking_invitation::get_accepted_invitation_response_hash,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateInvoiceInput {
+    pub customer: AgentPubKey,
+    pub booking_hashes: Vec<ActionHash>,
+    pub tax_rate_basis_points: u32,
+}
+
+#[hdk_extern]
+pub fn create_invoice(input: CreateInvoiceInput) -> ExternResult<Record> {
+    let mut currency: Option<String> = None;
+    let mut line_items = Vec::new();
+    for booking_hash in input.booking_hashes {
+        let record = get_latest_booking(booking_hash.clone())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest(String::from("Booking not found"))
+        ))?;
+        let booking = Booking::try_from(record.clone())?;
+        let price = booking.price.ok_or(wasm_error!(WasmErrorInner::Guest(format!(
+            "Booking \"{}\" doesn't have a price",
+            booking.title
+        ))))?;
+        if currency.get_or_insert(price.currency.clone()).ne(&price.currency) {
+            return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                "All the invoiced bookings must have the same currency"
+            ))));
+        }
+        // Invitees prove their participation with the acceptance of their invitation
+        let invitation_response_hash = if booking.bookers.contains(&input.customer) {
+            None
+        } else {
+            Some(
+                get_accepted_invitation_response_hash(booking_hash, &input.customer)?.ok_or(
+                    wasm_error!(WasmErrorInner::Guest(format!(
+                        "The customer doesn't participate in booking \"{}\"",
+                        booking.title
+                    ))),
+                )?,
+            )
+        };
+        line_items.push(InvoiceLineItem {
+            booking_hash: record.action_address().clone(),
+            description: booking.title,
+            amount: price.amount,
+            invitation_response_hash,
+        });
+    }
+    let currency = currency.ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "Invoices must have at least one booking"
+    ))))?;
+
+    let previous_invoices = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::Invoice.try_into()?)
+            .action_type(ActionType::Create),
+    )?;
+    let (subtotal, tax, total) = invoice_totals(&line_items, input.tax_rate_basis_points).ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "The totals of the invoice overflow"
+        ))),
+    )?;
+    let invoice = Invoice {
+        number: previous_invoices.len() as u32 + 1,
+        customer: input.customer,
+        currency,
+        line_items,
+        tax_rate_basis_points: input.tax_rate_basis_points,
+        subtotal,
+        tax,
+        total,
+        status: InvoiceStatus::Issued,
+    };
+
+    let invoice_hash = create_entry(&EntryTypes::Invoice(invoice.clone()))?;
+    create_link(
+        agent_info()?.agent_latest_pubkey,
+        invoice_hash.clone(),
+        LinkTypes::AgentToInvoices,
+        (),
+    )?;
+    create_link(
+        invoice.customer,
+        invoice_hash.clone(),
+        LinkTypes::AgentToInvoices,
+        (),
+    )?;
+
+    let record = get(invoice_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly created Invoice"))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_invoice(original_invoice_hash: ActionHash) -> ExternResult<Option<Record>> {
+    get_latest_invoice(original_invoice_hash)
+}
+
+fn get_latest_invoice(invoice_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let Some(details) = get_details(invoice_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    let record_details = match details {
+        Details::Entry(_) => Err(wasm_error!(WasmErrorInner::Guest(
+            "Malformed details".into()
+        ))),
+        Details::Record(record_details) => Ok(record_details),
+    }?;
+    match record_details.updates.last() {
+        Some(update) => get_latest_invoice(update.action_address().clone()),
+        None => Ok(Some(record_details.record)),
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateInvoiceStatusInput {
+    pub original_invoice_hash: ActionHash,
+    pub status: InvoiceStatus,
+}
+
+#[hdk_extern]
+pub fn update_invoice_status(input: UpdateInvoiceStatusInput) -> ExternResult<Record> {
+    let record = get_latest_invoice(input.original_invoice_hash)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Invoice not found"))
+    ))?;
+    let mut invoice = Invoice::try_from(record.clone())?;
+    invoice.status = input.status;
+
+    let updated_invoice_hash = update_entry(record.action_address().clone(), &invoice)?;
+    let record = get(updated_invoice_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly updated Invoice"))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_invoices_for_agent(agent: AgentPubKey) -> ExternResult<Vec<Record>> {
+    let links = get_links(agent, LinkTypes::AgentToInvoices, None)?;
+
+    let mut invoices = Vec::new();
+    for link in links {
+        if let Some(record) = get_latest_invoice(ActionHash::from(link.target))? {
+            invoices.push(record);
+        }
+    }
+    Ok(invoices)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum InvoiceFormat {
+    Text,
+    Json,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RenderInvoiceInput {
+    pub invoice_hash: ActionHash,
+    pub format: InvoiceFormat,
+}
+
+#[hdk_extern]
+pub fn render_invoice(input: RenderInvoiceInput) -> ExternResult<String> {
+    let record = get_latest_invoice(input.invoice_hash)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Invoice not found"))
+    ))?;
+    let issuer = record.action().author().clone();
+    let issued_at = record.action().timestamp();
+    let invoice = Invoice::try_from(record)?;
+
+    match input.format {
+        InvoiceFormat::Json => {
+            let json = serde_json::json!({
+                "number": invoice.number,
+                "issuer": issuer.to_string(),
+                "customer": invoice.customer.to_string(),
+                "issued_at": issued_at.as_micros(),
+                "currency": invoice.currency,
+                "line_items": invoice.line_items.iter().map(|item| serde_json::json!({
+                    "booking_hash": item.booking_hash.to_string(),
+                    "description": item.description,
+                    "amount": item.amount,
+                })).collect::<Vec<_>>(),
+                "tax_rate_basis_points": invoice.tax_rate_basis_points,
+                "subtotal": invoice.subtotal,
+                "tax": invoice.tax,
+                "total": invoice.total,
+                "status": format!("{:?}", invoice.status),
+            });
+            serde_json::to_string_pretty(&json)
+                .map_err(|e| wasm_error!(WasmErrorInner::Guest(e.to_string())))
+        }
+        InvoiceFormat::Text => {
+            let mut text = format!(
+                "Invoice #{}\nIssuer: {}\nCustomer: {}\nStatus: {:?}\n\n",
+                invoice.number, issuer, invoice.customer, invoice.status
+            );
+            for item in invoice.line_items.iter() {
+                text.push_str(&format!(
+                    "{}\t{}\n",
+                    item.description,
+                    format_amount(item.amount, &invoice.currency)
+                ));
+            }
+            text.push_str(&format!(
+                "\nSubtotal\t{}\nTax ({:.2}%)\t{}\nTotal\t{}\n",
+                format_amount(invoice.subtotal, &invoice.currency),
+                invoice.tax_rate_basis_points as f64 / 100.0,
+                format_amount(invoice.tax, &invoice.currency),
+                format_amount(invoice.total, &invoice.currency),
+            ));
+            Ok(text)
+        }
+    }
+}
+
+// Amounts are stored in minor units, whose number of decimals depends on the currency
+fn format_amount(amount: u64, currency: &str) -> String {
+    let exponent = currency_exponent(currency);
+    if exponent == 0 {
+        return format!("{} {}", amount, currency);
+    }
+    let factor = 10u64.pow(exponent);
+    format!(
+        "{}.{:0width$} {}",
+        amount / factor,
+        amount % factor,
+        currency,
+        width = exponent as usize
+    )
+}
+
+// ISO 4217 minor unit exponents, for the currencies that don't use cents
+fn currency_exponent(currency: &str) -> u32 {
+    match currency.to_uppercase().as_str() {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "CLF" | "UYW" => 4,
+        _ => 2,
+    }
+}
@@ -3,13 +3,51 @@ pub mod booker_to_bookings;
 pub mod my_booking_requests;
 pub mod my_resources;
 pub mod all_resources;
+pub mod allocation;
+pub mod attribute_schema;
+pub mod automatic_acceptance;
+pub mod availability;
 pub mod booking;
+pub mod booking_bundle;
+pub mod booking_cancellation;
+pub mod booking_invitation;
+pub mod booking_policy;
 pub mod booking_request;
+pub mod condition_report;
+pub mod credit_transfer;
+pub mod holds;
+pub mod invoice;
+pub mod location;
+pub mod maintenance;
+pub mod notifications;
+pub mod payment_acknowledgement;
+pub mod pricing;
+pub mod quota;
+pub mod reschedule_proposal;
 pub mod resource;
+pub mod resource_hierarchy;
+pub mod usage_event;
+pub mod usage_report;
+pub mod waitlist;
 use hdk::prelude::*;
 use bookie_integrity::*;
+use notifications::Notification;
+use std::collections::BTreeSet;
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+    let zome_name = zome_info()?.name;
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_name.clone(), FunctionName::from("process_booking_request")));
+    functions.insert((zome_name.clone(), FunctionName::from("schedule_maintenance")));
+    functions.insert((zome_name.clone(), FunctionName::from("release_bundle_bookings")));
+    functions.insert((zome_name.clone(), FunctionName::from("charge_bundle_bookings")));
+    functions.insert((zome_name, FunctionName::from("recv_remote_signal")));
+    create_cap_grant(CapGrantEntry {
+        tag: String::from("remote_calls"),
+        access: CapAccess::Unrestricted,
+        functions,
+    })?;
+    schedule("schedule_my_resources_maintenance")?;
     Ok(InitCallbackResult::Pass)
 }
 #[derive(Serialize, Deserialize, Debug)]
@@ -24,6 +62,7 @@ pub enum Signal {
         original_app_entry: EntryTypes,
     },
     EntryDeleted { action: SignedActionHashed, original_app_entry: EntryTypes },
+    Notification { notification: Notification },
 }
 #[hdk_extern(infallible)]
 pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
@@ -0,0 +1,128 @@
+use crate::{all_resources::get_all_resources, resource::get_resource};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+pub fn add_resource_location(
+    resource_hash: ActionHash,
+    resource_version_hash: ActionHash,
+    location: &Location,
+) -> ExternResult<()> {
+    let geohash = geohash_encode(
+        location.latitude,
+        location.longitude,
+        LOCATION_INDEX_PRECISION,
+    );
+    for precision in 1..=LOCATION_INDEX_PRECISION {
+        create_link(
+            location_path(&geohash[..precision]).path_entry_hash()?,
+            resource_hash.clone(),
+            LinkTypes::LocationToResources,
+            location_link_tag(&geohash, &resource_version_hash),
+        )?;
+    }
+    Ok(())
+}
+
+pub fn remove_resource_location(resource_hash: ActionHash, location: &Location) -> ExternResult<()> {
+    let geohash = geohash_encode(
+        location.latitude,
+        location.longitude,
+        LOCATION_INDEX_PRECISION,
+    );
+    for precision in 1..=LOCATION_INDEX_PRECISION {
+        let links = get_links(
+            location_path(&geohash[..precision]).path_entry_hash()?,
+            LinkTypes::LocationToResources,
+            None,
+        )?;
+        for link in links {
+            if ActionHash::from(link.target.clone()).eq(&resource_hash) {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetResourcesNearInput {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_meters: f64,
+}
+
+// Looks up the geohash cell that contains the point and its eight neighbours, using
+// the smallest cells that are still bigger than the radius
+#[hdk_extern]
+pub fn get_resources_near(input: GetResourcesNearInput) -> ExternResult<Vec<Record>> {
+    let meters_per_degree = 111_320.0;
+    let precision = (1..=LOCATION_INDEX_PRECISION).rev().find(|precision| {
+        let (height, width) = geohash_cell_size(*precision);
+        let width_meters = width * meters_per_degree * input.latitude.to_radians().cos();
+        height * meters_per_degree >= input.radius_meters && width_meters >= input.radius_meters
+    });
+
+    let resource_hashes: Vec<ActionHash> = match precision {
+        Some(precision) => {
+            let (height, width) = geohash_cell_size(precision);
+            let mut geohashes: Vec<String> = Vec::new();
+            for latitude_offset in [-1.0, 0.0, 1.0] {
+                for longitude_offset in [-1.0, 0.0, 1.0] {
+                    let latitude = (input.latitude + latitude_offset * height).clamp(-90.0, 90.0);
+                    let mut longitude = input.longitude + longitude_offset * width;
+                    if longitude < -180.0 {
+                        longitude += 360.0;
+                    } else if longitude > 180.0 {
+                        longitude -= 360.0;
+                    }
+                    let geohash = geohash_encode(latitude, longitude, precision);
+                    if !geohashes.contains(&geohash) {
+                        geohashes.push(geohash);
+                    }
+                }
+            }
+
+            let mut resource_hashes: Vec<ActionHash> = Vec::new();
+            for geohash in geohashes {
+                let links = get_links(
+                    location_path(&geohash).path_entry_hash()?,
+                    LinkTypes::LocationToResources,
+                    None,
+                )?;
+                for link in links {
+                    let resource_hash = ActionHash::from(link.target);
+                    if !resource_hashes.contains(&resource_hash) {
+                        resource_hashes.push(resource_hash);
+                    }
+                }
+            }
+            resource_hashes
+        }
+        // The radius is bigger than the largest cells
+        None => get_all_resources(())?
+            .into_iter()
+            .map(|record| record.action_address().clone())
+            .collect(),
+    };
+
+    let mut resources = Vec::new();
+    for resource_hash in resource_hashes {
+        let Some(record) = get_resource(resource_hash)? else {
+            continue;
+        };
+        let Some(location) = Resource::try_from(record.clone())?.location else {
+            continue;
+        };
+        let distance = distance_meters(
+            input.latitude,
+            input.longitude,
+            location.latitude,
+            location.longitude,
+        );
+        if distance <= input.radius_meters {
+            resources.push((distance, record));
+        }
+    }
+    resources.sort_by(|(distance_a, _), (distance_b, _)| distance_a.total_cmp(distance_b));
+    Ok(resources.into_iter().map(|(_, record)| record).collect())
+}
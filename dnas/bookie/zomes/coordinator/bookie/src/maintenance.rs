@@ -0,0 +1,294 @@
+use crate::{
+    availability::get_current_bookings_for_resource, booking_invitation::get_booking_participants,
+    my_resources::get_my_resources,
+    resource::get_resource_owner, usage_event::get_booking_usage,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[hdk_extern]
+pub fn create_maintenance_task(maintenance_task: MaintenanceTask) -> ExternResult<Record> {
+    let maintenance_task_hash =
+        create_entry(&EntryTypes::MaintenanceTask(maintenance_task.clone()))?;
+    create_link(
+        maintenance_task.resource_hash.clone(),
+        maintenance_task_hash.clone(),
+        LinkTypes::ResourceToMaintenanceTasks,
+        (),
+    )?;
+    let record = get(maintenance_task_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created MaintenanceTask"
+        ))
+    ))?;
+
+    schedule_maintenance(maintenance_task.resource_hash)?;
+
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn delete_maintenance_task(maintenance_task_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record = get(maintenance_task_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Maintenance Task not found"))
+    ))?;
+    let maintenance_task = MaintenanceTask::try_from(record)?;
+
+    let links = get_links(
+        maintenance_task.resource_hash,
+        LinkTypes::ResourceToMaintenanceTasks,
+        None,
+    )?;
+    for link in links {
+        if ActionHash::from(link.target.clone()).eq(&maintenance_task_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(maintenance_task_hash)
+}
+
+#[hdk_extern]
+pub fn get_maintenance_tasks_for_resource(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(resource_hash, LinkTypes::ResourceToMaintenanceTasks, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(records)
+}
+
+#[hdk_extern]
+pub fn create_maintenance_window(maintenance_window: MaintenanceWindow) -> ExternResult<Record> {
+    let maintenance_window_hash =
+        create_entry(&EntryTypes::MaintenanceWindow(maintenance_window.clone()))?;
+    create_link(
+        maintenance_window.resource_hash,
+        maintenance_window_hash.clone(),
+        LinkTypes::ResourceToMaintenanceWindows,
+        (),
+    )?;
+    let record = get(maintenance_window_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the newly created MaintenanceWindow"
+        ))),
+    )?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn delete_maintenance_window(maintenance_window_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record = get(maintenance_window_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Maintenance Window not found"))
+    ))?;
+    let maintenance_window = MaintenanceWindow::try_from(record)?;
+
+    let links = get_links(
+        maintenance_window.resource_hash,
+        LinkTypes::ResourceToMaintenanceWindows,
+        None,
+    )?;
+    for link in links {
+        if ActionHash::from(link.target.clone()).eq(&maintenance_window_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(maintenance_window_hash)
+}
+
+pub fn get_maintenance_windows_for_resource(
+    resource_hash: ActionHash,
+) -> ExternResult<Vec<(Record, MaintenanceWindow)>> {
+    let links = get_links(resource_hash, LinkTypes::ResourceToMaintenanceWindows, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let mut windows: Vec<(Record, MaintenanceWindow)> = vec![];
+    for record in HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+    {
+        let maintenance_window = MaintenanceWindow::try_from(record.clone())?;
+        windows.push((record, maintenance_window));
+    }
+    windows.sort_by_key(|(_, window)| window.start_time);
+    Ok(windows)
+}
+
+#[hdk_extern]
+pub fn get_upcoming_maintenance(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let now = sys_time()?;
+    let records = get_maintenance_windows_for_resource(resource_hash)?
+        .into_iter()
+        .filter(|(_, window)| window.end_time > now)
+        .map(|(record, _)| record)
+        .collect();
+    Ok(records)
+}
+
+// Creates the maintenance windows that are due for the tasks of the resource. Only the
+// owner can create them, so its bookers ask the owner to do it after using the resource
+#[hdk_extern]
+pub fn schedule_maintenance(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    if get_resource_owner(resource_hash.clone())?.ne(&my_pub_key) {
+        return Ok(vec![]);
+    }
+    let caller = call_info()?.provenance;
+    if caller.ne(&my_pub_key) {
+        let mut is_participant = false;
+        for (booking_hash, _) in get_current_bookings_for_resource(resource_hash.clone())? {
+            if get_booking_participants(booking_hash)?.contains(&caller) {
+                is_participant = true;
+                break;
+            }
+        }
+        if !is_participant {
+            return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Only the participants of the resource's bookings can ask for its maintenance to be scheduled"
+            ))));
+        }
+    }
+
+    let now = sys_time()?;
+    let windows = get_maintenance_windows_for_resource(resource_hash.clone())?;
+    let distances = get_usage_distances(resource_hash.clone())?;
+
+    let mut scheduled: Vec<Record> = vec![];
+    for task_record in get_maintenance_tasks_for_resource(resource_hash.clone())? {
+        let maintenance_task_hash = task_record.action_address().clone();
+        let maintenance_task = MaintenanceTask::try_from(task_record.clone())?;
+
+        let task_windows: Vec<&MaintenanceWindow> = windows
+            .iter()
+            .map(|(_, window)| window)
+            .filter(|window| window.maintenance_task_hash == Some(maintenance_task_hash.clone()))
+            .collect();
+        if task_windows.iter().any(|window| window.end_time > now) {
+            continue;
+        }
+        let last_window = task_windows.last();
+        let since = last_window
+            .map(|window| window.end_time)
+            .unwrap_or(task_record.action().timestamp());
+
+        let start_time = match maintenance_task.trigger {
+            MaintenanceTrigger::Interval { interval_seconds } => {
+                let base = last_window
+                    .map(|window| window.start_time)
+                    .unwrap_or(task_record.action().timestamp());
+                let due = base.as_micros().saturating_add(seconds_to_micros(interval_seconds)?);
+                Some(Timestamp::from_micros(due.max(now.as_micros())))
+            }
+            MaintenanceTrigger::Usage { odometer_interval } => {
+                let distance = distances
+                    .iter()
+                    .filter(|(timestamp, _)| *timestamp > since)
+                    .fold(0u64, |total, (_, distance)| total.saturating_add(*distance));
+                if distance >= odometer_interval {
+                    Some(now)
+                } else {
+                    None
+                }
+            }
+            MaintenanceTrigger::AfterEachUse => {
+                if get_check_out_times(resource_hash.clone())?
+                    .into_iter()
+                    .any(|timestamp| timestamp > since)
+                {
+                    Some(now)
+                } else {
+                    None
+                }
+            }
+        };
+
+        if let Some(start_time) = start_time {
+            let end_time = Timestamp::from_micros(
+                start_time
+                    .as_micros()
+                    .saturating_add(seconds_to_micros(maintenance_task.duration_seconds)?),
+            );
+            scheduled.push(create_maintenance_window(MaintenanceWindow {
+                resource_hash: resource_hash.clone(),
+                maintenance_task_hash: Some(maintenance_task_hash),
+                start_time,
+                end_time,
+            })?);
+        }
+    }
+
+    Ok(scheduled)
+}
+
+// Runs every hour while the conductor of the owner is up, so that the next window of an
+// interval task is created once the current one is over
+#[hdk_extern(infallible)]
+pub fn schedule_my_resources_maintenance(_: Option<Schedule>) -> Option<Schedule> {
+    if let Err(err) = schedule_maintenance_for_my_resources() {
+        error!("Error scheduling maintenance: {:?}", err);
+    }
+    Some(Schedule::Persisted("0 0 * * * * *".into()))
+}
+
+fn schedule_maintenance_for_my_resources() -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    for record in get_my_resources(my_pub_key)? {
+        schedule_maintenance(record.action_address().clone())?;
+    }
+    Ok(())
+}
+
+pub fn request_maintenance_scheduling(resource_hash: ActionHash) -> ExternResult<()> {
+    let resource_owner = get_resource_owner(resource_hash.clone())?;
+    if resource_owner.eq(&agent_info()?.agent_latest_pubkey) {
+        schedule_maintenance(resource_hash)?;
+        return Ok(());
+    }
+    // If the owner is offline, the maintenance will be scheduled the next time anyone
+    // uses the resource
+    let _ = call_remote(
+        resource_owner,
+        zome_info()?.name,
+        FunctionName::from("schedule_maintenance"),
+        None,
+        resource_hash,
+    );
+    Ok(())
+}
+
+// Distance covered in each booking, from its check-in to its check-out reading. Readings of
+// different bookings aren't compared, so a wrong reading only affects its own booking
+fn get_usage_distances(resource_hash: ActionHash) -> ExternResult<Vec<(Timestamp, u64)>> {
+    let mut distances: Vec<(Timestamp, u64)> = vec![];
+    for (booking_hash, _) in get_current_bookings_for_resource(resource_hash)? {
+        let usage = get_booking_usage(booking_hash)?;
+        let (Some(check_in), Some(check_out)) = (usage.check_in, usage.check_out) else {
+            continue;
+        };
+        let timestamp = check_out.action().timestamp();
+        let check_in = UsageEvent::try_from(check_in)?;
+        let check_out = UsageEvent::try_from(check_out)?;
+        if let (Some(start), Some(end)) = (check_in.odometer, check_out.odometer) {
+            distances.push((timestamp, end.saturating_sub(start)));
+        }
+    }
+    distances.sort_by_key(|(timestamp, _)| *timestamp);
+    Ok(distances)
+}
+
+fn get_check_out_times(resource_hash: ActionHash) -> ExternResult<Vec<Timestamp>> {
+    let mut check_out_times: Vec<Timestamp> = vec![];
+    for (booking_hash, _) in get_current_bookings_for_resource(resource_hash)? {
+        if let Some(record) = get_booking_usage(booking_hash)?.check_out {
+            check_out_times.push(record.action().timestamp());
+        }
+    }
+    Ok(check_out_times)
+}
@@ -0,0 +1,64 @@
+use crate::Signal;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum Notification {
+    WaitlistPromoted { booking_request_hash: ActionHash },
+    BookingCancelled {
+        booking_hash: ActionHash,
+        cancellation_hash: ActionHash,
+    },
+    RescheduleProposed {
+        booking_hash: ActionHash,
+        reschedule_proposal_hash: ActionHash,
+    },
+    RescheduleAccepted {
+        booking_hash: ActionHash,
+        reschedule_proposal_hash: ActionHash,
+    },
+    ConditionReported {
+        resource_hash: ActionHash,
+        condition_report_hash: ActionHash,
+    },
+    PaymentAcknowledged {
+        booking_hash: ActionHash,
+        payment_acknowledgement_hash: ActionHash,
+    },
+    BookingInvitationReceived {
+        booking_hash: ActionHash,
+        booking_invitation_hash: ActionHash,
+    },
+    BookingInvitationAnswered {
+        booking_hash: ActionHash,
+        booking_invitation_hash: ActionHash,
+        accepted: bool,
+    },
+    BookingBundleConfirmed { bundle_hash: ActionHash },
+    BookingBundleRejected {
+        bundle_hash: ActionHash,
+        resource_hash: ActionHash,
+        // Resources whose owners couldn't be reached to release their bookings, the
+        // requester can release them by cancelling the bundle
+        unreleased_resource_hashes: Vec<ActionHash>,
+    },
+}
+
+pub fn notify(agents: Vec<AgentPubKey>, notification: Notification) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    let agents: Vec<AgentPubKey> = agents
+        .into_iter()
+        .filter(|agent| agent.ne(&my_pub_key))
+        .collect();
+
+    if agents.is_empty() {
+        return Ok(());
+    }
+
+    send_remote_signal(notification, agents)
+}
+
+#[hdk_extern]
+pub fn recv_remote_signal(notification: Notification) -> ExternResult<()> {
+    emit_signal(Signal::Notification { notification })
+}
@@ -0,0 +1,147 @@
+use crate::{
+    booking::get_latest_booking,
+    booking_invitation::get_booking_participants,
+    notifications::{notify, Notification},
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[hdk_extern]
+pub fn acknowledge_payment(payment_acknowledgement: PaymentAcknowledgement) -> ExternResult<Record> {
+    if payment_acknowledgement.kind == PaymentKind::Refund {
+        let payment = get_booking_payment(payment_acknowledgement.booking_hash.clone())?;
+        let refunded_amount = payment
+            .refunded_amount
+            .checked_add(payment_acknowledgement.amount);
+        if !matches!(refunded_amount, Some(refunded_amount) if refunded_amount <= payment.paid_amount)
+        {
+            return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Can't refund more than what was paid"
+            ))));
+        }
+    }
+    let payment_acknowledgement_hash = create_entry(&EntryTypes::PaymentAcknowledgement(
+        payment_acknowledgement.clone(),
+    ))?;
+    create_link(
+        payment_acknowledgement.booking_hash.clone(),
+        payment_acknowledgement_hash.clone(),
+        LinkTypes::BookingToPaymentAcknowledgements,
+        (),
+    )?;
+
+    if get_latest_booking(payment_acknowledgement.booking_hash.clone())?.is_some() {
+        notify(
+            get_booking_participants(payment_acknowledgement.booking_hash.clone())?,
+            Notification::PaymentAcknowledged {
+                booking_hash: payment_acknowledgement.booking_hash,
+                payment_acknowledgement_hash: payment_acknowledgement_hash.clone(),
+            },
+        )?;
+    }
+
+    let record = get(payment_acknowledgement_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the newly created PaymentAcknowledgement"
+        ))),
+    )?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_payment_acknowledgements_for_booking(
+    booking_hash: ActionHash,
+) -> ExternResult<Vec<Record>> {
+    let links = get_links(booking_hash, LinkTypes::BookingToPaymentAcknowledgements, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(records)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum BookingPaymentStatus {
+    Unpaid,
+    DepositPaid,
+    FullyPaid,
+    Refunded,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BookingPayment {
+    pub status: BookingPaymentStatus,
+    pub paid_amount: u64,
+    pub refunded_amount: u64,
+    pub deposit_amount: Option<u64>,
+    // Bookings of resources that require a deposit are tentative until it's acknowledged
+    pub tentative: bool,
+}
+
+#[hdk_extern]
+pub fn get_booking_payment(booking_hash: ActionHash) -> ExternResult<BookingPayment> {
+    let booking_record = get_latest_booking(booking_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking not found"))
+    ))?;
+    let booking = Booking::try_from(booking_record)?;
+    // The deposit is the one of the resource version the booking was made against
+    let resource_version_hash = booking
+        .resource_version_hash
+        .clone()
+        .unwrap_or(booking.resource_hash.clone());
+    let resource_record = get(resource_version_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Resource not found"))
+    ))?;
+    let resource = Resource::try_from(resource_record)?;
+    let deposit_amount = resource.pricing.and_then(|pricing| pricing.deposit_amount);
+
+    let mut paid_amount: u64 = 0;
+    let mut refunded_amount: u64 = 0;
+    let mut fully_paid = false;
+    for record in get_payment_acknowledgements_for_booking(booking_hash)? {
+        let payment_acknowledgement = PaymentAcknowledgement::try_from(record)?;
+        match payment_acknowledgement.kind {
+            PaymentKind::Deposit => {
+                paid_amount = paid_amount.saturating_add(payment_acknowledgement.amount)
+            }
+            PaymentKind::Payment => {
+                paid_amount = paid_amount.saturating_add(payment_acknowledgement.amount);
+                fully_paid = true;
+            }
+            PaymentKind::Refund => {
+                refunded_amount = refunded_amount.saturating_add(payment_acknowledgement.amount)
+            }
+        }
+    }
+    if let Some(price) = &booking.price {
+        fully_paid = paid_amount >= price.amount;
+    }
+
+    let status = if refunded_amount > 0 && refunded_amount >= paid_amount {
+        BookingPaymentStatus::Refunded
+    } else if fully_paid {
+        BookingPaymentStatus::FullyPaid
+    } else if paid_amount > 0 {
+        BookingPaymentStatus::DepositPaid
+    } else {
+        BookingPaymentStatus::Unpaid
+    };
+    let tentative = match deposit_amount {
+        Some(deposit_amount) => paid_amount.saturating_sub(refunded_amount) < deposit_amount,
+        None => false,
+    };
+
+    Ok(BookingPayment {
+        status,
+        paid_amount,
+        refunded_amount,
+        deposit_amount,
+        tentative,
+    })
+}
@@ -0,0 +1,60 @@
+use crate::resource::get_resource;
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuoteBookingInput {
+    pub resource_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub quantity: Option<u32>,
+}
+
+#[hdk_extern]
+pub fn quote_booking(input: QuoteBookingInput) -> ExternResult<Option<BookingPrice>> {
+    price_booking(
+        input.resource_hash,
+        &input.start_time,
+        &input.end_time,
+        input.quantity.unwrap_or(1),
+    )
+}
+
+// Prices are always computed with the latest version of the resource
+pub fn price_booking(
+    resource_hash: ActionHash,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+    quantity: u32,
+) -> ExternResult<Option<BookingPrice>> {
+    let record = get_resource(resource_hash)?.ok_or(wasm_error!(WasmErrorInner::Guest(
+        String::from("Resource not found")
+    )))?;
+    price_resource_version(record, start_time, end_time, quantity)
+}
+
+pub fn price_resource_version(
+    resource_record: Record,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+    quantity: u32,
+) -> ExternResult<Option<BookingPrice>> {
+    let resource_version_hash = resource_record.action_address().clone();
+    let resource = Resource::try_from(resource_record)?;
+
+    let Some(pricing_model) = resource.pricing else {
+        return Ok(None);
+    };
+
+    let amount = compute_price(&pricing_model, start_time, end_time, quantity).ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "The time range of the booking is out of range"
+        ))),
+    )?;
+    Ok(Some(BookingPrice {
+        amount,
+        currency: pricing_model.currency,
+        quantity,
+        resource_version_hash,
+    }))
+}
@@ -0,0 +1,152 @@
+use crate::{booker_to_bookings::get_current_bookings_for_booker, resource::get_resource};
+use bookie_integrity::*;
+use hdk::prelude::*;
+use std::collections::BTreeMap;
+
+fn resource_category(
+    resource_hash: &ActionHash,
+    categories: &mut BTreeMap<ActionHash, Option<String>>,
+) -> ExternResult<Option<String>> {
+    if let Some(category) = categories.get(resource_hash) {
+        return Ok(category.clone());
+    }
+    let category = match get_resource(resource_hash.clone())? {
+        Some(record) => Resource::try_from(record)?.category,
+        None => None,
+    };
+    categories.insert(resource_hash.clone(), category.clone());
+    Ok(category)
+}
+
+fn quota_bookings_for_booker(
+    booker: AgentPubKey,
+    ignored_booking_hash: Option<&ActionHash>,
+    categories: &mut BTreeMap<ActionHash, Option<String>>,
+) -> ExternResult<Vec<QuotaBooking>> {
+    let mut bookings = Vec::new();
+    for (booking_hash, booking) in get_current_bookings_for_booker(booker)? {
+        if Some(&booking_hash) == ignored_booking_hash {
+            continue;
+        }
+        bookings.push(QuotaBooking {
+            category: resource_category(&booking.resource_hash, categories)?,
+            resource_hash: booking.resource_hash,
+            start_time: booking.start_time,
+            end_time: booking.end_time,
+        });
+    }
+    Ok(bookings)
+}
+
+pub fn check_booking_quotas(
+    bookers: &[AgentPubKey],
+    resource_hash: &ActionHash,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+    ignored_booking_hash: Option<&ActionHash>,
+) -> ExternResult<()> {
+    let quotas = booking_policy()?.quotas;
+    if quotas.is_empty() {
+        return Ok(());
+    }
+    let mut categories = BTreeMap::new();
+    let booking = QuotaBooking {
+        resource_hash: resource_hash.clone(),
+        category: resource_category(resource_hash, &mut categories)?,
+        start_time: *start_time,
+        end_time: *end_time,
+    };
+    for booker in bookers {
+        let other_bookings =
+            quota_bookings_for_booker(booker.clone(), ignored_booking_hash, &mut categories)?;
+        check_quotas(&quotas, &booking, &other_bookings)
+            .map_err(|error| wasm_error!(WasmErrorInner::Guest(error)))?;
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuotaUsage {
+    pub quota: BookingQuota,
+    // Only set for quotas that apply to each resource separately
+    pub resource_hash: Option<ActionHash>,
+    pub period_start: Timestamp,
+    pub period_end: Timestamp,
+    pub booked_seconds: u64,
+}
+
+#[hdk_extern]
+pub fn get_my_quota_usage(_: ()) -> ExternResult<Vec<QuotaUsage>> {
+    let quotas = booking_policy()?.quotas;
+    let my_pub_key = agent_info()?.agent_latest_pubkey;
+    let now = sys_time()?;
+    let mut categories = BTreeMap::new();
+    let bookings = quota_bookings_for_booker(my_pub_key, None, &mut categories)?;
+
+    let mut usage = Vec::new();
+    for quota in quotas {
+        let (period_start, period_end) = quota_period_bounds(&quota.period, &now);
+        match &quota.scope {
+            QuotaScope::EachResource => {
+                let mut resource_hashes: Vec<ActionHash> = bookings
+                    .iter()
+                    .filter(|booking| {
+                        time_ranges_overlap(
+                            &booking.start_time,
+                            &booking.end_time,
+                            &period_start,
+                            &period_end,
+                        )
+                    })
+                    .map(|booking| booking.resource_hash.clone())
+                    .collect();
+                resource_hashes.sort();
+                resource_hashes.dedup();
+                for resource_hash in resource_hashes {
+                    let booked_seconds = booked_seconds_in_period(
+                        bookings
+                            .iter()
+                            .filter(|booking| booking.resource_hash.eq(&resource_hash)),
+                        &period_start,
+                        &period_end,
+                    );
+                    usage.push(QuotaUsage {
+                        quota: quota.clone(),
+                        resource_hash: Some(resource_hash),
+                        period_start,
+                        period_end,
+                        booked_seconds,
+                    });
+                }
+            }
+            QuotaScope::Category { category } => {
+                let booked_seconds = booked_seconds_in_period(
+                    bookings
+                        .iter()
+                        .filter(|booking| booking.category.as_ref() == Some(category)),
+                    &period_start,
+                    &period_end,
+                );
+                usage.push(QuotaUsage {
+                    quota: quota.clone(),
+                    resource_hash: None,
+                    period_start,
+                    period_end,
+                    booked_seconds,
+                });
+            }
+            QuotaScope::AllResources => {
+                let booked_seconds =
+                    booked_seconds_in_period(bookings.iter(), &period_start, &period_end);
+                usage.push(QuotaUsage {
+                    quota: quota.clone(),
+                    resource_hash: None,
+                    period_start,
+                    period_end,
+                    booked_seconds,
+                });
+            }
+        }
+    }
+    Ok(usage)
+}
@@ -0,0 +1,161 @@
+use crate::{
+    availability::check_availability,
+    booking::{apply_latest_resource_version, get_latest_booking},
+    booking_invitation::get_booking_participants,
+    booking_policy::get_booking_policy_for_resource,
+    notifications::{notify, Notification},
+    quota::check_booking_quotas,
+    resource::get_resource_owner,
+    waitlist::promote_from_waitlist,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProposeRescheduleInput {
+    pub booking_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub counter_to: Option<ActionHash>,
+}
+
+#[hdk_extern]
+pub fn propose_reschedule(input: ProposeRescheduleInput) -> ExternResult<Record> {
+    let booking_record = get_latest_booking(input.booking_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking not found"))
+    ))?;
+    let booking = Booking::try_from(booking_record)?;
+
+    let policy = get_booking_policy_for_resource(booking.resource_hash.clone())?;
+    check_availability(
+        &booking.resource_hash,
+        &input.start_time,
+        &input.end_time,
+        &policy,
+        Some(&input.booking_hash),
+        &booking.bookers,
+    )?;
+
+    let reschedule_proposal_hash = create_entry(&EntryTypes::RescheduleProposal(RescheduleProposal {
+        booking_hash: input.booking_hash.clone(),
+        start_time: input.start_time,
+        end_time: input.end_time,
+        counter_to: input.counter_to,
+    }))?;
+    create_link(
+        input.booking_hash.clone(),
+        reschedule_proposal_hash.clone(),
+        LinkTypes::BookingToRescheduleProposals,
+        (),
+    )?;
+
+    notify(
+        get_participants(input.booking_hash.clone(), &booking)?,
+        Notification::RescheduleProposed {
+            booking_hash: input.booking_hash,
+            reschedule_proposal_hash: reschedule_proposal_hash.clone(),
+        },
+    )?;
+
+    let record = get(reschedule_proposal_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created RescheduleProposal"
+        ))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn accept_reschedule_proposal(reschedule_proposal_hash: ActionHash) -> ExternResult<Record> {
+    let proposal_record = get(reschedule_proposal_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Reschedule Proposal not found"
+        ))),
+    )?;
+    let proposal = RescheduleProposal::try_from(proposal_record)?;
+
+    let booking_record = get_latest_booking(proposal.booking_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking not found"))
+    ))?;
+    let previous_booking_hash = booking_record.action_address().clone();
+    let mut booking = Booking::try_from(booking_record)?;
+
+    if booking.reschedule_proposal_hash == Some(reschedule_proposal_hash.clone()) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "This proposal has already been accepted"
+        ))));
+    }
+    let proposals = get_reschedule_proposals_for_booking(proposal.booking_hash.clone())?;
+    if proposals.last().map(|record| record.action_address()) != Some(&reschedule_proposal_hash) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the latest proposal of the negotiation can be accepted"
+        ))));
+    }
+
+    let policy = get_booking_policy_for_resource(booking.resource_hash.clone())?;
+    check_availability(
+        &booking.resource_hash,
+        &proposal.start_time,
+        &proposal.end_time,
+        &policy,
+        Some(&proposal.booking_hash),
+        &booking.bookers,
+    )?;
+    check_booking_quotas(
+        &booking.bookers,
+        &booking.resource_hash,
+        &proposal.start_time,
+        &proposal.end_time,
+        Some(&proposal.booking_hash),
+    )?;
+
+    let previous_start_time = booking.start_time;
+    let previous_end_time = booking.end_time;
+    booking.start_time = proposal.start_time;
+    booking.end_time = proposal.end_time;
+    booking.reschedule_proposal_hash = Some(reschedule_proposal_hash.clone());
+    apply_latest_resource_version(&mut booking)?;
+    let updated_booking_hash = update_entry(previous_booking_hash, &booking)?;
+
+    promote_from_waitlist(
+        booking.resource_hash.clone(),
+        &previous_start_time,
+        &previous_end_time,
+        &updated_booking_hash,
+    )?;
+
+    notify(
+        get_participants(proposal.booking_hash.clone(), &booking)?,
+        Notification::RescheduleAccepted {
+            booking_hash: proposal.booking_hash,
+            reschedule_proposal_hash,
+        },
+    )?;
+
+    let record = get(updated_booking_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly updated Booking"))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_reschedule_proposals_for_booking(booking_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let mut links = get_links(booking_hash, LinkTypes::BookingToRescheduleProposals, None)?;
+    links.sort_by_key(|link| link.timestamp);
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(records)
+}
+
+fn get_participants(booking_hash: ActionHash, booking: &Booking) -> ExternResult<Vec<AgentPubKey>> {
+    let mut participants = get_booking_participants(booking_hash)?;
+    participants.push(get_resource_owner(booking.resource_hash.clone())?);
+    Ok(participants)
+}
@@ -1,5 +1,6 @@
 use hdk::prelude::*;
 use bookie_integrity::*;
+use crate::location::{add_resource_location, remove_resource_location};
 #[hdk_extern]
 pub fn create_resource(resource: Resource) -> ExternResult<Record> {
     let resource_hash = create_entry(&EntryTypes::Resource(resource.clone()))?;
@@ -18,6 +19,9 @@ pub fn create_resource(resource: Resource) -> ExternResult<Record> {
     )?;
     let my_agent_pub_key = agent_info()?.agent_latest_pubkey;
     create_link(my_agent_pub_key, resource_hash.clone(), LinkTypes::MyResources, ())?;
+    if let Some(location) = &resource.location {
+        add_resource_location(resource_hash.clone(), resource_hash, location)?;
+    }
     Ok(record)
 }
 #[hdk_extern]
@@ -44,6 +48,13 @@ pub struct UpdateResourceInput {
 }
 #[hdk_extern]
 pub fn update_resource(input: UpdateResourceInput) -> ExternResult<Record> {
+    let previous_record = get(input.previous_resource_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Resource not found"))
+            ),
+        )?;
+    let previous_location = Resource::try_from(previous_record)?.location;
     let updated_resource_hash = update_entry(
         input.previous_resource_hash.clone(),
         &input.updated_resource,
@@ -54,6 +65,18 @@ pub fn update_resource(input: UpdateResourceInput) -> ExternResult<Record> {
         LinkTypes::ResourceUpdates,
         (),
     )?;
+    if previous_location.ne(&input.updated_resource.location) {
+        if let Some(location) = &previous_location {
+            remove_resource_location(input.original_resource_hash.clone(), location)?;
+        }
+        if let Some(location) = &input.updated_resource.location {
+            add_resource_location(
+                input.original_resource_hash.clone(),
+                updated_resource_hash.clone(),
+                location,
+            )?;
+        }
+    }
     let record = get(updated_resource_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
@@ -66,3 +89,12 @@ pub fn update_resource(input: UpdateResourceInput) -> ExternResult<Record> {
 pub fn delete_resource(original_resource_hash: ActionHash) -> ExternResult<ActionHash> {
     delete_entry(original_resource_hash)
 }
+pub fn get_resource_owner(original_resource_hash: ActionHash) -> ExternResult<AgentPubKey> {
+    let record = get(original_resource_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Resource not found"))
+            ),
+        )?;
+    Ok(record.action().author().clone())
+}
@@ -0,0 +1,129 @@
+use crate::resource::get_resource;
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChildResourceInput {
+    pub parent_resource_hash: ActionHash,
+    pub child_resource_hash: ActionHash,
+}
+
+#[hdk_extern]
+pub fn add_child_resource(input: ChildResourceInput) -> ExternResult<()> {
+    if get_parent_resource_hash(input.child_resource_hash.clone())?.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The resource is already contained in another resource"
+        ))));
+    }
+    // Cycles can't be detected in the integrity zome, as they depend on other links
+    if input.child_resource_hash.eq(&input.parent_resource_hash)
+        || get_ancestor_resource_hashes(input.parent_resource_hash.clone())?
+            .contains(&input.child_resource_hash)
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "A resource can't be contained in one of its own children"
+        ))));
+    }
+
+    create_link(
+        input.parent_resource_hash.clone(),
+        input.child_resource_hash.clone(),
+        LinkTypes::ParentToChildResources,
+        (),
+    )?;
+    create_link(
+        input.child_resource_hash,
+        input.parent_resource_hash,
+        LinkTypes::ChildToParentResources,
+        (),
+    )?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn remove_child_resource(input: ChildResourceInput) -> ExternResult<()> {
+    let links = get_links(
+        input.parent_resource_hash.clone(),
+        LinkTypes::ParentToChildResources,
+        None,
+    )?;
+    for link in links {
+        if ActionHash::from(link.target.clone()).eq(&input.child_resource_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    let links = get_links(
+        input.child_resource_hash,
+        LinkTypes::ChildToParentResources,
+        None,
+    )?;
+    for link in links {
+        if ActionHash::from(link.target.clone()).eq(&input.parent_resource_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+fn get_child_resource_hashes(resource_hash: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let links = get_links(resource_hash, LinkTypes::ParentToChildResources, None)?;
+    Ok(links
+        .into_iter()
+        .map(|link| ActionHash::from(link.target))
+        .collect())
+}
+
+fn get_parent_resource_hash(resource_hash: ActionHash) -> ExternResult<Option<ActionHash>> {
+    let links = get_links(resource_hash, LinkTypes::ChildToParentResources, None)?;
+    Ok(links
+        .into_iter()
+        .min_by_key(|link| link.timestamp)
+        .map(|link| ActionHash::from(link.target)))
+}
+
+#[hdk_extern]
+pub fn get_child_resources(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let mut resources = Vec::new();
+    for child_resource_hash in get_child_resource_hashes(resource_hash)? {
+        if let Some(record) = get_resource(child_resource_hash)? {
+            resources.push(record);
+        }
+    }
+    Ok(resources)
+}
+
+#[hdk_extern]
+pub fn get_parent_resource(resource_hash: ActionHash) -> ExternResult<Option<Record>> {
+    match get_parent_resource_hash(resource_hash)? {
+        Some(parent_resource_hash) => get_resource(parent_resource_hash),
+        None => Ok(None),
+    }
+}
+
+// From the direct parent up to the root of the hierarchy
+pub fn get_ancestor_resource_hashes(resource_hash: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let mut ancestors: Vec<ActionHash> = Vec::new();
+    let mut current = resource_hash.clone();
+    while let Some(parent_resource_hash) = get_parent_resource_hash(current)? {
+        if parent_resource_hash.eq(&resource_hash) || ancestors.contains(&parent_resource_hash) {
+            break;
+        }
+        ancestors.push(parent_resource_hash.clone());
+        current = parent_resource_hash;
+    }
+    Ok(ancestors)
+}
+
+pub fn get_descendant_resource_hashes(resource_hash: ActionHash) -> ExternResult<Vec<ActionHash>> {
+    let mut descendants: Vec<ActionHash> = Vec::new();
+    let mut pending = vec![resource_hash.clone()];
+    while let Some(current) = pending.pop() {
+        for child_resource_hash in get_child_resource_hashes(current)? {
+            if child_resource_hash.ne(&resource_hash) && !descendants.contains(&child_resource_hash) {
+                descendants.push(child_resource_hash.clone());
+                pending.push(child_resource_hash);
+            }
+        }
+    }
+    Ok(descendants)
+}
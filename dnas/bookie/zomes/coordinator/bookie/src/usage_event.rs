@@ -0,0 +1,153 @@
+use crate::{
+    availability::get_current_bookings_for_resource, booking::get_latest_booking,
+    maintenance::request_maintenance_scheduling, payment_acknowledgement::get_booking_payment,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UsageEventInput {
+    pub booking_hash: ActionHash,
+    pub odometer: Option<u64>,
+    pub condition_note: Option<String>,
+    pub photo_hash: Option<EntryHash>,
+}
+
+#[hdk_extern]
+pub fn check_in(input: UsageEventInput) -> ExternResult<Record> {
+    let usage = get_booking_usage(input.booking_hash.clone())?;
+    if usage.check_in.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The booking has already been checked in"
+        ))));
+    }
+    if get_booking_payment(input.booking_hash.clone())?.tentative {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The booking is tentative until its deposit is acknowledged"
+        ))));
+    }
+    create_usage_event(input, UsageEventType::CheckIn, None)
+}
+
+#[hdk_extern]
+pub fn check_out(input: UsageEventInput) -> ExternResult<Record> {
+    let usage = get_booking_usage(input.booking_hash.clone())?;
+    let Some(check_in) = usage.check_in else {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The booking can't be checked out before it's checked in"
+        ))));
+    };
+    if usage.check_out.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The booking has already been checked out"
+        ))));
+    }
+    let booking_record = get_latest_booking(input.booking_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking not found"))
+    ))?;
+    let booking = Booking::try_from(booking_record)?;
+
+    let record = create_usage_event(
+        input,
+        UsageEventType::CheckOut,
+        Some(check_in.action_address().clone()),
+    )?;
+    request_maintenance_scheduling(booking.resource_hash)?;
+    Ok(record)
+}
+
+fn create_usage_event(
+    input: UsageEventInput,
+    event_type: UsageEventType,
+    check_in_hash: Option<ActionHash>,
+) -> ExternResult<Record> {
+    let usage_event_hash = create_entry(&EntryTypes::UsageEvent(UsageEvent {
+        booking_hash: input.booking_hash.clone(),
+        event_type,
+        check_in_hash,
+        odometer: input.odometer,
+        condition_note: input.condition_note,
+        photo_hash: input.photo_hash,
+    }))?;
+    create_link(
+        input.booking_hash,
+        usage_event_hash.clone(),
+        LinkTypes::BookingToUsageEvents,
+        (),
+    )?;
+
+    let record = get(usage_event_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly created UsageEvent"))
+    ))?;
+    Ok(record)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BookingUsage {
+    pub check_in: Option<Record>,
+    pub check_out: Option<Record>,
+    pub late_return: bool,
+}
+
+#[hdk_extern]
+pub fn get_booking_usage(booking_hash: ActionHash) -> ExternResult<BookingUsage> {
+    let booking_record = get_latest_booking(booking_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Booking not found"))
+    ))?;
+    let booking = Booking::try_from(booking_record)?;
+
+    let mut links = get_links(booking_hash, LinkTypes::BookingToUsageEvents, None)?;
+    links.sort_by_key(|link| link.timestamp);
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+
+    let mut check_in: Option<Record> = None;
+    let mut check_out: Option<Record> = None;
+    for record in records {
+        let usage_event = UsageEvent::try_from(record.clone())?;
+        match usage_event.event_type {
+            UsageEventType::CheckIn if check_in.is_none() => check_in = Some(record),
+            UsageEventType::CheckOut if check_out.is_none() => check_out = Some(record),
+            _ => {}
+        }
+    }
+
+    let returned_at = match &check_out {
+        Some(record) => record.action().timestamp(),
+        None => sys_time()?,
+    };
+    let late_return = check_in.is_some() && returned_at > booking.end_time;
+
+    Ok(BookingUsage {
+        check_in,
+        check_out,
+        late_return,
+    })
+}
+
+#[hdk_extern]
+pub fn get_overdue_bookings(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let now = sys_time()?;
+    let mut overdue_bookings: Vec<Record> = vec![];
+
+    for (booking_hash, booking) in get_current_bookings_for_resource(resource_hash)? {
+        if booking.end_time >= now {
+            continue;
+        }
+        let usage = get_booking_usage(booking_hash.clone())?;
+        if usage.check_in.is_some() && usage.check_out.is_none() {
+            if let Some(record) = get_latest_booking(booking_hash)? {
+                overdue_bookings.push(record);
+            }
+        }
+    }
+
+    Ok(overdue_bookings)
+}
@@ -0,0 +1,174 @@
+use crate::{
+    availability::get_current_bookings_for_resource,
+    booker_to_bookings::get_current_bookings_for_booker, resource::get_resource,
+    usage_event::get_booking_usage,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UsageReportEntry {
+    pub resource_hash: ActionHash,
+    pub resource_name: String,
+    pub booker: AgentPubKey,
+    pub bookings_count: u32,
+    pub booked_seconds: u64,
+    pub used_seconds: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UsageReport {
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub entries: Vec<UsageReportEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetResourceUsageReportInput {
+    pub resource_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+}
+
+#[hdk_extern]
+pub fn get_resource_usage_report(input: GetResourceUsageReportInput) -> ExternResult<UsageReport> {
+    let bookings = get_current_bookings_for_resource(input.resource_hash)?;
+    build_usage_report(bookings, input.start_time, input.end_time, None)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetBookerUsageReportInput {
+    pub booker: AgentPubKey,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+}
+
+#[hdk_extern]
+pub fn get_booker_usage_report(input: GetBookerUsageReportInput) -> ExternResult<UsageReport> {
+    let bookings = get_current_bookings_for_booker(input.booker.clone())?;
+    build_usage_report(bookings, input.start_time, input.end_time, Some(input.booker))
+}
+
+#[hdk_extern]
+pub fn render_usage_report_csv(report: UsageReport) -> ExternResult<String> {
+    let mut csv = String::from("resource,resource_hash,booker,bookings,booked_hours,used_hours\n");
+    for entry in report.entries {
+        csv.push_str(&format!(
+            "{},{},{},{},{:.2},{}\n",
+            escape_csv_field(&entry.resource_name),
+            entry.resource_hash,
+            entry.booker,
+            entry.bookings_count,
+            entry.booked_seconds as f64 / 3600.0,
+            entry
+                .used_seconds
+                .map(|seconds| format!("{:.2}", seconds as f64 / 3600.0))
+                .unwrap_or_default(),
+        ));
+    }
+    Ok(csv)
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// Bookings shared by several bookers are split evenly between them, so that the report
+// can be used directly to split the costs of the resource. Shares are summed in microseconds
+// and only converted to seconds at the end, so that splitting doesn't lose whole seconds
+fn build_usage_report(
+    bookings: Vec<(ActionHash, Booking)>,
+    start_time: Timestamp,
+    end_time: Timestamp,
+    only_booker: Option<AgentPubKey>,
+) -> ExternResult<UsageReport> {
+    let mut entries: BTreeMap<(ActionHash, AgentPubKey), (UsageReportEntry, u64, Option<u64>)> =
+        BTreeMap::new();
+    let mut resource_names: BTreeMap<ActionHash, String> = BTreeMap::new();
+
+    for (booking_hash, booking) in bookings {
+        let booked_micros =
+            overlap_micros(&booking.start_time, &booking.end_time, &start_time, &end_time);
+        if booked_micros == 0 || booking.bookers.is_empty() {
+            continue;
+        }
+
+        let usage = get_booking_usage(booking_hash)?;
+        let used_micros = match (&usage.check_in, &usage.check_out) {
+            (Some(check_in), Some(check_out)) => Some(overlap_micros(
+                &check_in.action().timestamp(),
+                &check_out.action().timestamp(),
+                &start_time,
+                &end_time,
+            )),
+            _ => None,
+        };
+
+        if !resource_names.contains_key(&booking.resource_hash) {
+            let name = match get_resource(booking.resource_hash.clone())? {
+                Some(record) => Resource::try_from(record)?.name,
+                None => String::new(),
+            };
+            resource_names.insert(booking.resource_hash.clone(), name);
+        }
+
+        let share = booking.bookers.len() as u64;
+        for booker in booking.bookers.iter() {
+            if let Some(only_booker) = &only_booker {
+                if booker.ne(only_booker) {
+                    continue;
+                }
+            }
+            let (entry, booked_share_micros, used_share_micros) = entries
+                .entry((booking.resource_hash.clone(), booker.clone()))
+                .or_insert_with(|| {
+                    (
+                        UsageReportEntry {
+                            resource_hash: booking.resource_hash.clone(),
+                            resource_name: resource_names[&booking.resource_hash].clone(),
+                            booker: booker.clone(),
+                            bookings_count: 0,
+                            booked_seconds: 0,
+                            used_seconds: None,
+                        },
+                        0,
+                        None,
+                    )
+                });
+            entry.bookings_count += 1;
+            *booked_share_micros += booked_micros / share;
+            if let Some(used_micros) = used_micros {
+                *used_share_micros = Some(used_share_micros.unwrap_or(0) + used_micros / share);
+            }
+        }
+    }
+
+    Ok(UsageReport {
+        start_time,
+        end_time,
+        entries: entries
+            .into_values()
+            .map(|(entry, booked_share_micros, used_share_micros)| UsageReportEntry {
+                booked_seconds: booked_share_micros / 1_000_000,
+                used_seconds: used_share_micros.map(|micros| micros / 1_000_000),
+                ..entry
+            })
+            .collect(),
+    })
+}
+
+fn overlap_micros(
+    start_a: &Timestamp,
+    end_a: &Timestamp,
+    start_b: &Timestamp,
+    end_b: &Timestamp,
+) -> u64 {
+    let start = start_a.as_micros().max(start_b.as_micros());
+    let end = end_a.as_micros().min(end_b.as_micros());
+    (end - start).max(0) as u64
+}
@@ -0,0 +1,173 @@
+use crate::{
+    availability::check_availability,
+    booking_policy::get_booking_policy_for_resource,
+    holds::release_my_holds,
+    notifications::{notify, Notification},
+    resource::get_resource_owner,
+};
+use bookie_integrity::*;
+use hdk::prelude::*;
+
+#[hdk_extern]
+pub fn join_waitlist(booking_request: BookingRequest) -> ExternResult<Record> {
+    let my_agent_pub_key = agent_info()?.agent_latest_pubkey;
+    let policy = get_booking_policy_for_resource(booking_request.resource_hash.clone())?;
+    if check_availability(
+        &booking_request.resource_hash,
+        &booking_request.start_time,
+        &booking_request.end_time,
+        &policy,
+        None,
+        &[my_agent_pub_key.clone()],
+    )
+    .is_ok()
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The slot is available, request a booking instead"
+        ))));
+    }
+    let booking_request_hash = create_entry(&EntryTypes::BookingRequest(booking_request.clone()))?;
+    create_link(
+        booking_request.resource_hash.clone(),
+        booking_request_hash.clone(),
+        LinkTypes::ResourceToWaitlist,
+        (),
+    )?;
+    create_link(
+        my_agent_pub_key,
+        booking_request_hash.clone(),
+        LinkTypes::MyBookingRequests,
+        (),
+    )?;
+    release_my_holds(
+        booking_request.resource_hash.clone(),
+        &booking_request.start_time,
+        &booking_request.end_time,
+    )?;
+    let record = get(booking_request_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created BookingRequest"
+        ))
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_waitlist_for_resource(resource_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let mut links = get_links(resource_hash, LinkTypes::ResourceToWaitlist, None)?;
+    links.sort_by_key(|link| link.timestamp);
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .filter_map(|r| r)
+        .collect();
+    Ok(records)
+}
+
+pub fn remove_booking_request_from_waitlist(
+    original_booking_request_hash: ActionHash,
+) -> ExternResult<()> {
+    let record =
+        get(original_booking_request_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest(String::from("Booking Request was not found"))
+        ))?;
+
+    let booking_request = BookingRequest::try_from(record)?;
+
+    let links = get_links(
+        booking_request.resource_hash.clone(),
+        LinkTypes::ResourceToWaitlist,
+        None,
+    )?;
+
+    for link in links {
+        if ActionHash::from(link.target.clone()).eq(&original_booking_request_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Promotes the waitlisted requests that fit in the freed slot, in the order they joined the
+// waitlist. Promoted requests are pending requests, so the ones overlapping an earlier
+// promoted request stay in the waitlist
+pub fn promote_from_waitlist(
+    resource_hash: ActionHash,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+    freed_booking_hash: &ActionHash,
+) -> ExternResult<Vec<ActionHash>> {
+    let policy = get_booking_policy_for_resource(resource_hash.clone())?;
+    let owner = get_resource_owner(resource_hash.clone())?;
+    let mut promoted: Vec<(ActionHash, BookingRequest)> = vec![];
+    let mut links = get_links(resource_hash.clone(), LinkTypes::ResourceToWaitlist, None)?;
+    links.sort_by_key(|link| link.timestamp);
+
+    for link in links {
+        let booking_request_hash = ActionHash::from(link.target.clone());
+        let Some(record) = get(booking_request_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        let booking_request = BookingRequest::try_from(record.clone())?;
+
+        if booking_request.start_time <= sys_time()? {
+            continue;
+        }
+        if !time_ranges_overlap(
+            start_time,
+            end_time,
+            &booking_request.start_time,
+            &booking_request.end_time,
+        ) {
+            continue;
+        }
+        if promoted.iter().any(|(_, promoted_request)| {
+            time_ranges_overlap(
+                &promoted_request.start_time,
+                &promoted_request.end_time,
+                &booking_request.start_time,
+                &booking_request.end_time,
+            )
+        }) {
+            continue;
+        }
+        if check_availability(
+            &resource_hash,
+            &booking_request.start_time,
+            &booking_request.end_time,
+            &policy,
+            Some(freed_booking_hash),
+            &[record.action().author().clone()],
+        )
+        .is_err()
+        {
+            continue;
+        }
+
+        delete_link(link.create_link_hash)?;
+        create_link(
+            resource_hash.clone(),
+            booking_request_hash.clone(),
+            LinkTypes::ResourceToBookingRequests,
+            (),
+        )?;
+        notify(
+            vec![record.action().author().clone(), owner.clone()],
+            Notification::WaitlistPromoted {
+                booking_request_hash: booking_request_hash.clone(),
+            },
+        )?;
+
+        promoted.push((booking_request_hash, booking_request));
+    }
+
+    Ok(promoted
+        .into_iter()
+        .map(|(booking_request_hash, _)| booking_request_hash)
+        .collect())
+}
@@ -0,0 +1,190 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::allocation::{AllocateSlotInput, AllocateSlotOutput};
+
+mod common;
+use common::{create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn contested_slots_are_allocated_by_priority() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+    let carol_zome = carol.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.booking_policy = Some(ResourceBookingPolicy {
+        allocation_rule: Some(AllocationRule::Priority {
+            roles: vec![PriorityRole {
+                name: "Staff".to_string(),
+                agents: vec![carol.agent_pubkey().clone()],
+            }],
+        }),
+        ..Default::default()
+    });
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let start_time = Timestamp::from_micros(4102453334548000);
+    let end_time = Timestamp::from_micros(4102456934548000);
+    let booking_request = BookingRequest {
+        resource_hash: resource_hash.clone(),
+        title: "Concert".to_string(),
+        comment: "".to_string(),
+        start_time,
+        end_time,
+        bundle_hash: None,
+    };
+
+    // Bob requests the slot before Carol
+    let bob_request: Record = conductors[1]
+        .call(&bob_zome, "create_booking_request", booking_request.clone())
+        .await;
+    let carol_request: Record = conductors[2]
+        .call(&carol_zome, "create_booking_request", booking_request)
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let input = AllocateSlotInput {
+        resource_hash: resource_hash.clone(),
+        start_time,
+        end_time,
+        deadline: Timestamp::now(),
+    };
+
+    // Only the owner can allocate the slot
+    let result: ConductorApiResult<AllocateSlotOutput> = conductors[1]
+        .call_fallible(&bob_zome, "allocate_slot", input)
+        .await;
+    assert!(result.is_err());
+
+    let output: AllocateSlotOutput = conductors[0]
+        .call(&alice_zome, "allocate_slot", AllocateSlotInput {
+            resource_hash: resource_hash.clone(),
+            start_time,
+            end_time,
+            deadline: Timestamp::now(),
+        })
+        .await;
+    assert_eq!(output.bookings.len(), 1);
+    let booking: Booking = output.bookings[0].entry().to_app_option().unwrap().unwrap();
+    assert_eq!(booking.bookers, vec![carol.agent_pubkey().clone()]);
+    assert_eq!(
+        output.rejected_booking_requests,
+        vec![bob_request.signed_action.hashed.hash.clone()]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn winners_over_their_concurrency_limit_lose_the_slot() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+    let properties = BookingPolicy {
+        max_concurrent_bookings_per_agent: Some(1),
+        ..Default::default()
+    };
+    let dna = dna.update_modifiers(
+        DnaModifiersOpt::none().with_properties(SerializedBytes::try_from(properties).unwrap()),
+    );
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+    let carol_zome = carol.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.booking_policy = Some(ResourceBookingPolicy {
+        allocation_rule: Some(AllocationRule::Priority {
+            roles: vec![PriorityRole {
+                name: "Staff".to_string(),
+                agents: vec![bobbo.agent_pubkey().clone()],
+            }],
+        }),
+        ..Default::default()
+    });
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    // Bob already has an upcoming booking
+    let _bob_booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", Booking {
+            title: "Rehearsal".to_string(),
+            start_time: Timestamp::from_micros(4102446134548000),
+            end_time: Timestamp::from_micros(4102449734548000),
+            bookers: vec![bobbo.agent_pubkey().clone()],
+            booking_request_hash: None,
+            reschedule_proposal_hash: None,
+            price: None,
+            resource_hash: resource_hash.clone(),
+            resource_version_hash: None,
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let start_time = Timestamp::from_micros(4102453334548000);
+    let end_time = Timestamp::from_micros(4102456934548000);
+    let booking_request = BookingRequest {
+        resource_hash: resource_hash.clone(),
+        title: "Concert".to_string(),
+        comment: "".to_string(),
+        start_time,
+        end_time,
+        bundle_hash: None,
+    };
+
+    let bob_request: Record = conductors[1]
+        .call(&bob_zome, "create_booking_request", booking_request.clone())
+        .await;
+    let _carol_request: Record = conductors[2]
+        .call(&carol_zome, "create_booking_request", booking_request)
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    // Bob has the priority, but he can't have another booking
+    let output: AllocateSlotOutput = conductors[0]
+        .call(&alice_zome, "allocate_slot", AllocateSlotInput {
+            resource_hash: resource_hash.clone(),
+            start_time,
+            end_time,
+            deadline: Timestamp::now(),
+        })
+        .await;
+    assert_eq!(output.bookings.len(), 1);
+    let booking: Booking = output.bookings[0].entry().to_app_option().unwrap().unwrap();
+    assert_eq!(booking.bookers, vec![carol.agent_pubkey().clone()]);
+    assert_eq!(
+        output.rejected_booking_requests,
+        vec![bob_request.signed_action.hashed.hash.clone()]
+    );
+}
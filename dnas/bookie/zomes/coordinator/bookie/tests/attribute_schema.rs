@@ -0,0 +1,206 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::prelude::DnaModifiersOpt;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+use std::collections::BTreeMap;
+
+use bookie_integrity::*;
+use bookie::attribute_schema::{AttributeCondition, AttributeFilter, GetResourcesByAttributesInput};
+
+mod common;
+use common::{create_resource, sample_resource_1};
+
+fn room_attributes(seats: f64, projector: bool) -> BTreeMap<String, AttributeValue> {
+    let mut attributes = BTreeMap::new();
+    attributes.insert("seats".to_string(), AttributeValue::Number { value: seats });
+    attributes.insert("projector".to_string(), AttributeValue::Bool { value: projector });
+    attributes.insert("floor".to_string(), AttributeValue::String { value: "ground".to_string() });
+    attributes
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resources_are_validated_and_filtered_by_attributes() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+
+    // Alice is the authority of the attribute schemas
+    let alice_pub_key = SweetAgents::one(conductors[0].keystore()).await;
+    let properties = BookingPolicy {
+        attribute_schema_authority: Some(alice_pub_key.clone()),
+        ..Default::default()
+    };
+    let dna = dna.update_modifiers(
+        DnaModifiersOpt::none().with_properties(SerializedBytes::try_from(properties).unwrap()),
+    );
+    let (alice,) = conductors[0]
+        .setup_app_for_agent("bookie", alice_pub_key, &[dna.clone()])
+        .await
+        .unwrap()
+        .into_tuple();
+    let (bobbo,) = conductors[1]
+        .setup_app("bookie", &[dna])
+        .await
+        .unwrap()
+        .into_tuple();
+    conductors.exchange_peer_info().await;
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let rooms_schema = AttributeSchema {
+        category: "rooms".to_string(),
+        attributes: vec![],
+    };
+
+    // Only the authority can define schemas
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_attribute_schema", rooms_schema.clone())
+        .await;
+    assert!(result.is_err());
+
+    let schema: Record = conductors[0]
+        .call(&alice_zome, "create_attribute_schema", AttributeSchema {
+            category: "rooms".to_string(),
+            attributes: vec![
+                AttributeDefinition {
+                    name: "seats".to_string(),
+                    attribute_type: AttributeType::Number,
+                    required: true,
+                },
+                AttributeDefinition {
+                    name: "projector".to_string(),
+                    attribute_type: AttributeType::Bool,
+                    required: false,
+                },
+                AttributeDefinition {
+                    name: "floor".to_string(),
+                    attribute_type: AttributeType::Enum {
+                        options: vec!["ground".to_string(), "first".to_string()],
+                    },
+                    required: false,
+                },
+            ],
+        })
+        .await;
+    let schema_hash = schema.signed_action.hashed.hash.clone();
+
+    // A category has a single schema
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_attribute_schema", rooms_schema)
+        .await;
+    assert!(result.is_err());
+
+    let mut big_room = sample_resource_1(&conductors[0], &alice_zome).await;
+    big_room.category = Some("rooms".to_string());
+    big_room.attribute_schema_hash = Some(schema_hash.clone());
+    big_room.attributes = room_attributes(40.0, true);
+    let big_room_record = create_resource(&conductors[0], &alice_zome, big_room.clone()).await;
+
+    let mut small_room = big_room.clone();
+    small_room.attributes = room_attributes(6.0, false);
+    let small_room_record = create_resource(&conductors[0], &alice_zome, small_room.clone()).await;
+
+    // Values of the wrong type are invalid
+    let mut invalid_room = big_room.clone();
+    invalid_room
+        .attributes
+        .insert("seats".to_string(), AttributeValue::String { value: "many".to_string() });
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", invalid_room)
+        .await;
+    assert!(result.is_err());
+
+    // Enum values must be one of the options
+    let mut invalid_room = big_room.clone();
+    invalid_room
+        .attributes
+        .insert("floor".to_string(), AttributeValue::String { value: "roof".to_string() });
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", invalid_room)
+        .await;
+    assert!(result.is_err());
+
+    // Required attributes can't be missing
+    let mut invalid_room = big_room.clone();
+    invalid_room.attributes.remove("seats");
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", invalid_room)
+        .await;
+    assert!(result.is_err());
+
+    // Undefined attributes are invalid
+    let mut invalid_room = big_room.clone();
+    invalid_room
+        .attributes
+        .insert("voltage".to_string(), AttributeValue::Number { value: 230.0 });
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", invalid_room)
+        .await;
+    assert!(result.is_err());
+
+    // Resources of a category with a schema must reference it
+    let mut invalid_room = big_room.clone();
+    invalid_room.attribute_schema_hash = None;
+    invalid_room.attributes = BTreeMap::new();
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", invalid_room)
+        .await;
+    assert!(result.is_err());
+
+    // The schema must belong to the category of the resource
+    let mut invalid_room = big_room.clone();
+    invalid_room.category = Some("cars".to_string());
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", invalid_room)
+        .await;
+    assert!(result.is_err());
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let found_schema: Option<Record> = conductors[1]
+        .call(&bob_zome, "get_attribute_schema_for_category", "rooms".to_string())
+        .await;
+    assert_eq!(found_schema, Some(schema));
+
+    let resources: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_resources_by_attributes", GetResourcesByAttributesInput {
+            category: Some("rooms".to_string()),
+            filters: vec![AttributeFilter {
+                name: "seats".to_string(),
+                condition: AttributeCondition::AtLeast { value: 10.0 },
+            }],
+        })
+        .await;
+    assert_eq!(resources, vec![big_room_record.clone()]);
+
+    let resources: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_resources_by_attributes", GetResourcesByAttributesInput {
+            category: None,
+            filters: vec![AttributeFilter {
+                name: "projector".to_string(),
+                condition: AttributeCondition::Equals {
+                    value: AttributeValue::Bool { value: false },
+                },
+            }],
+        })
+        .await;
+    assert_eq!(resources, vec![small_room_record]);
+
+    let resources: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_resources_by_attributes", GetResourcesByAttributesInput {
+            category: Some("rooms".to_string()),
+            filters: vec![],
+        })
+        .await;
+    assert_eq!(resources.len(), 2);
+}
@@ -0,0 +1,82 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use std::collections::BTreeMap;
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+
+use bookie::automatic_acceptance::RequestBookingOutput;
+
+mod common;
+use common::{create_resource, sample_booking_request_1, sample_resource_1};
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Profile {
+    nickname: String,
+    fields: BTreeMap<String, String>,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn owner_accepts_booking_request_automatically() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+    
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.booking_policy = Some(ResourceBookingPolicy {
+        auto_accept_requests: Some(true),
+        ..Default::default()
+    });
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    // Bob becomes a member by creating his profile
+    let _profile: Record = conductors[1]
+        .call(&bobbo.zome("profiles"), "create_profile", Profile {
+            nickname: "bob".to_string(),
+            fields: BTreeMap::new(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let mut booking_request = sample_booking_request_1(&conductors[0], &alice_zome).await;
+    booking_request.resource_hash = resource_hash.clone();
+
+    // Alice is online, so Bob's request is accepted right away
+    let output: RequestBookingOutput = conductors[1]
+        .call(&bob_zome, "request_booking", booking_request.clone())
+        .await;
+
+    let RequestBookingOutput::Accepted { booking } = output else {
+        panic!("The booking request should have been accepted automatically");
+    };
+    let booking: Booking = booking.entry().to_app_option().unwrap().unwrap();
+    assert_eq!(booking.bookers, vec![bobbo.agent_pubkey().clone()]);
+    assert_eq!(booking.resource_hash, resource_hash);
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // The slot is taken now, so the second request stays pending
+    let output: RequestBookingOutput = conductors[1]
+        .call(&bob_zome, "request_booking", booking_request)
+        .await;
+
+    assert!(matches!(output, RequestBookingOutput::Pending { .. }));
+}
@@ -8,7 +8,8 @@ use holochain::{conductor::config::ConductorConfig, sweettest::*};
 
 use bookie_integrity::*;
 
-use bookie::booking::UpdateBookingInput;
+use bookie::booking::CreateInstantBookingInput;
+use bookie::booking_cancellation::CancelBookingInput;
 
 mod common;
 use common::{create_booking, sample_booking_1, sample_booking_2};
@@ -75,7 +76,7 @@ async fn create_and_read_booking() {
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn create_and_update_booking() {
+async fn create_and_delete_booking() {
     // Use prebuilt dna file
     let dna_path = std::env::current_dir()
         .unwrap()
@@ -96,56 +97,28 @@ async fn create_and_update_booking() {
     
     // Alice creates a Booking
     let record: Record = create_booking(&conductors[0], &alice_zome, sample_1.clone()).await;
-    let original_action_hash = record.signed_action.hashed.hash.clone();
-        
-    consistency_10s([&alice, &bobbo]).await;
-    
-    let sample_2 = sample_booking_2(&conductors[0], &alice_zome).await;
-    let input = UpdateBookingInput {
-      previous_booking_hash: original_action_hash.clone(),
-      updated_booking: sample_2.clone(),
-    };
+    let original_action_hash = record.signed_action.hashed.hash;
     
-    // Alice updates the Booking
-    let update_record: Record = conductors[0]
-        .call(&alice_zome, "update_booking", input)
+    // Alice cancels the Booking, which deletes it
+    let _cancellation: Record = conductors[0]
+        .call(&alice_zome, "cancel_booking", CancelBookingInput {
+            booking_hash: original_action_hash.clone(),
+            booking_version_hash: None,
+            reason: "Not needed anymore".to_string(),
+        })
         .await;
-        
-    let entry: Booking = update_record.entry().to_app_option().unwrap().unwrap();
-    assert_eq!(sample_2, entry);
-    
+
     consistency_10s([&alice, &bobbo]).await;
-    
+
     let get_record: Option<Record> = conductors[1]
         .call(&bob_zome, "get_booking", original_action_hash.clone())
         .await;
-  
-    assert_eq!(update_record, get_record.unwrap());
-    
-    let input = UpdateBookingInput {
-      previous_booking_hash: update_record.signed_action.hashed.hash.clone(),
-      updated_booking: sample_1.clone(),
-    };
-    
-    // Alice updates the Booking again
-    let update_record: Record = conductors[0]
-        .call(&alice_zome, "update_booking", input)
-        .await;
         
-    let entry: Booking = update_record.entry().to_app_option().unwrap().unwrap();
-    assert_eq!(sample_1, entry);
-    
-    consistency_10s([&alice, &bobbo]).await;
-    
-    let get_record: Option<Record> = conductors[1]
-        .call(&bob_zome, "get_booking", original_action_hash.clone())
-        .await;
-  
-    assert_eq!(update_record, get_record.unwrap());
+    assert!(get_record.is_none());
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn create_and_delete_booking() {
+async fn instant_booking_without_owner_approval() {
     // Use prebuilt dna file
     let dna_path = std::env::current_dir()
         .unwrap()
@@ -162,22 +135,49 @@ async fn create_and_delete_booking() {
     let alice_zome = alice.zome("bookie");
     let bob_zome = bobbo.zome("bookie");
     
-    let sample_1 = sample_booking_1(&conductors[0], &alice_zome).await;
-    
-    // Alice creates a Booking
-    let record: Record = create_booking(&conductors[0], &alice_zome, sample_1.clone()).await;
-    let original_action_hash = record.signed_action.hashed.hash;
-    
-    // Alice deletes the Booking
-    let _delete_action_hash: ActionHash = conductors[0]
-        .call(&alice_zome, "delete_booking", original_action_hash.clone())
+    let mut instant_resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    instant_resource.booking_policy = Some(ResourceBookingPolicy {
+        requires_approval: Some(false),
+        ..Default::default()
+    });
+    let instant_resource_hash = create_resource(&conductors[0], &alice_zome, instant_resource).await.signed_action.hashed.hash;
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_2(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Bob books the instant-bookable resource directly
+    let record: Record = conductors[1]
+        .call(&bob_zome, "create_instant_booking", CreateInstantBookingInput {
+            resource_hash: instant_resource_hash.clone(),
+            title: "Meeting".to_string(),
+            start_time: Timestamp::from_micros(4102453334548000),
+            end_time: Timestamp::from_micros(4102456934548000),
+        })
         .await;
+    let booking: Booking = record.entry().to_app_option().unwrap().unwrap();
+    assert_eq!(booking.bookers, vec![bobbo.agent_pubkey().clone()]);
 
     consistency_10s([&alice, &bobbo]).await;
 
-    let get_record: Option<Record> = conductors[1]
-        .call(&bob_zome, "get_booking", original_action_hash.clone())
+    // The same slot can't be booked again
+    let result: Result<Record, _> = conductors[1]
+        .call_fallible(&bob_zome, "create_instant_booking", CreateInstantBookingInput {
+            resource_hash: instant_resource_hash.clone(),
+            title: "Meeting".to_string(),
+            start_time: Timestamp::from_micros(4102453334548000),
+            end_time: Timestamp::from_micros(4102456934548000),
+        })
         .await;
-        
-    assert!(get_record.is_none());
+    assert!(result.is_err());
+
+    // Resources that require approval can't be booked directly
+    let result: Result<Record, _> = conductors[1]
+        .call_fallible(&bob_zome, "create_instant_booking", CreateInstantBookingInput {
+            resource_hash,
+            title: "Meeting".to_string(),
+            start_time: Timestamp::from_micros(4102453334548000),
+            end_time: Timestamp::from_micros(4102456934548000),
+        })
+        .await;
+    assert!(result.is_err());
 }
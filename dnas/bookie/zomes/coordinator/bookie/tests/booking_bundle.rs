@@ -0,0 +1,210 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::booking_bundle::{BookingBundleStatus, CancelBookingBundleInput, RequestBookingBundleOutput};
+
+mod common;
+use common::{create_resource, sample_resource_1, sample_resource_2};
+
+fn accept(booking_request: &Record) -> Booking {
+    let request = BookingRequest::try_from(booking_request.clone()).unwrap();
+    Booking {
+        title: request.title,
+        start_time: request.start_time,
+        end_time: request.end_time,
+        bookers: vec![booking_request.action().author().clone()],
+        booking_request_hash: Some(booking_request.signed_action.hashed.hash.clone()),
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: request.resource_hash,
+        resource_version_hash: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bundles_are_confirmed_once_every_owner_accepts() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+    let carol_zome = carol.zome("bookie");
+
+    let room = sample_resource_1(&conductors[0], &alice_zome).await;
+    let room_hash = create_resource(&conductors[0], &alice_zome, room).await.signed_action.hashed.hash;
+    let projector = sample_resource_2(&conductors[1], &bob_zome).await;
+    let projector_hash = create_resource(&conductors[1], &bob_zome, projector).await.signed_action.hashed.hash;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let output: RequestBookingBundleOutput = conductors[2]
+        .call(&carol_zome, "request_booking_bundle", BookingBundle {
+            title: "Workshop".to_string(),
+            comment: "".to_string(),
+            start_time: Timestamp::from_micros(4102453334548000),
+            end_time: Timestamp::from_micros(4102456934548000),
+            resource_hashes: vec![room_hash.clone(), projector_hash.clone()],
+        })
+        .await;
+    let bundle_hash = output.booking_bundle.signed_action.hashed.hash.clone();
+    assert_eq!(output.booking_requests.len(), 2);
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let _room_booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", accept(&output.booking_requests[0]))
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let status: BookingBundleStatus = conductors[2]
+        .call(&carol_zome, "get_booking_bundle_status", bundle_hash.clone())
+        .await;
+    assert_eq!(status, BookingBundleStatus::Pending { accepted: 1, total: 2 });
+
+    // Bookings aren't charged until every owner has accepted
+    let result: ConductorApiResult<Vec<ActionHash>> = conductors[0]
+        .call_fallible(&alice_zome, "charge_bundle_bookings", bundle_hash.clone())
+        .await;
+    assert!(result.is_err());
+
+    let _projector_booking: Record = conductors[1]
+        .call(&bob_zome, "create_booking", accept(&output.booking_requests[1]))
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let status: BookingBundleStatus = conductors[2]
+        .call(&carol_zome, "get_booking_bundle_status", bundle_hash.clone())
+        .await;
+    assert_eq!(status, BookingBundleStatus::Confirmed);
+
+    // Only the requester can cancel the bundle
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "cancel_booking_bundle", CancelBookingBundleInput {
+            bundle_hash: bundle_hash.clone(),
+            reason: "".to_string(),
+        })
+        .await;
+    assert!(result.is_err());
+
+    let _: () = conductors[2]
+        .call(&carol_zome, "cancel_booking_bundle", CancelBookingBundleInput {
+            bundle_hash: bundle_hash.clone(),
+            reason: "The workshop was postponed".to_string(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let bookings: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_bookings_for_bundle", bundle_hash.clone())
+        .await;
+    assert_eq!(bookings.len(), 0);
+
+    let status: BookingBundleStatus = conductors[0]
+        .call(&alice_zome, "get_booking_bundle_status", bundle_hash.clone())
+        .await;
+    assert_eq!(status, BookingBundleStatus::Cancelled);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rejecting_one_request_releases_the_whole_bundle() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+    let carol_zome = carol.zome("bookie");
+
+    let room = sample_resource_1(&conductors[0], &alice_zome).await;
+    let room_hash = create_resource(&conductors[0], &alice_zome, room).await.signed_action.hashed.hash;
+    let projector = sample_resource_2(&conductors[1], &bob_zome).await;
+    let projector_hash = create_resource(&conductors[1], &bob_zome, projector).await.signed_action.hashed.hash;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let output: RequestBookingBundleOutput = conductors[2]
+        .call(&carol_zome, "request_booking_bundle", BookingBundle {
+            title: "Workshop".to_string(),
+            comment: "".to_string(),
+            start_time: Timestamp::from_micros(4102453334548000),
+            end_time: Timestamp::from_micros(4102456934548000),
+            resource_hashes: vec![room_hash.clone(), projector_hash.clone()],
+        })
+        .await;
+    let bundle_hash = output.booking_bundle.signed_action.hashed.hash.clone();
+
+    // Requests that don't match the bundle are invalid
+    let result: ConductorApiResult<Record> = conductors[2]
+        .call_fallible(&carol_zome, "create_booking_request", BookingRequest {
+            resource_hash: room_hash.clone(),
+            title: "Workshop".to_string(),
+            comment: "".to_string(),
+            start_time: Timestamp::from_micros(4102460534548000),
+            end_time: Timestamp::from_micros(4102464134548000),
+            bundle_hash: Some(bundle_hash.clone()),
+        })
+        .await;
+    assert!(result.is_err());
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let _room_booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", accept(&output.booking_requests[0]))
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let _: () = conductors[1]
+        .call(
+            &bob_zome,
+            "reject_booking_request",
+            output.booking_requests[1].signed_action.hashed.hash.clone(),
+        )
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let status: BookingBundleStatus = conductors[2]
+        .call(&carol_zome, "get_booking_bundle_status", bundle_hash.clone())
+        .await;
+    assert_eq!(
+        status,
+        BookingBundleStatus::Rejected {
+            resource_hashes: vec![projector_hash.clone()]
+        }
+    );
+
+    // The booking of the room was released by its owner
+    let bookings: Vec<Record> = conductors[2]
+        .call(&carol_zome, "get_bookings_for_bundle", bundle_hash.clone())
+        .await;
+    assert_eq!(bookings.len(), 0);
+}
@@ -0,0 +1,190 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::booking_cancellation::CancelBookingInput;
+use bookie::reschedule_proposal::ProposeRescheduleInput;
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn late_cancellations_follow_the_cancellation_policy() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.booking_policy = Some(ResourceBookingPolicy {
+        cancellation_policy: Some(CancellationPolicy {
+            notice_seconds: 24 * 60 * 60,
+            late_cancellation: LateCancellation::Forbidden,
+        }),
+        ..Default::default()
+    });
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    let now = Timestamp::now();
+    let early_booking = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Next month".to_string(),
+        start_time: Timestamp::from_micros(now.as_micros() + 30 * 24 * 3_600_000_000),
+        end_time: Timestamp::from_micros(now.as_micros() + 30 * 24 * 3_600_000_000 + 3_600_000_000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let late_booking = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "In an hour".to_string(),
+        start_time: Timestamp::from_micros(now.as_micros() + 3_600_000_000),
+        end_time: Timestamp::from_micros(now.as_micros() + 2 * 3_600_000_000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Bob can cancel well in advance
+    let cancellation_record: Record = conductors[1]
+        .call(&bob_zome, "cancel_booking", CancelBookingInput {
+            booking_hash: early_booking.signed_action.hashed.hash.clone(),
+            booking_version_hash: None,
+            reason: "Plans changed".to_string(),
+        })
+        .await;
+    let cancellation = BookingCancellation::try_from(cancellation_record.clone()).unwrap();
+    assert!(!cancellation.fee_applies);
+    assert_eq!(cancellation.booking_version_hash, early_booking.signed_action.hashed.hash);
+
+    // But not less than a day before the start
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "cancel_booking", CancelBookingInput {
+            booking_hash: late_booking.signed_action.hashed.hash.clone(),
+            booking_version_hash: None,
+            reason: "Too late".to_string(),
+        })
+        .await;
+    assert!(result.is_err());
+
+    // The owner can always cancel
+    let _owner_cancellation: Record = conductors[0]
+        .call(&alice_zome, "cancel_booking", CancelBookingInput {
+            booking_hash: late_booking.signed_action.hashed.hash.clone(),
+            booking_version_hash: None,
+            reason: "Maintenance".to_string(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let cancellation_for_booking: Option<Record> = conductors[0]
+        .call(&alice_zome, "get_cancellation_for_booking", early_booking.signed_action.hashed.hash.clone())
+        .await;
+    assert_eq!(cancellation_for_booking, Some(cancellation_record));
+
+    let booking: Option<Record> = conductors[0]
+        .call(&alice_zome, "get_booking", early_booking.signed_action.hashed.hash.clone())
+        .await;
+    assert!(booking.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cancellations_citing_a_rescheduled_version_are_rejected() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.booking_policy = Some(ResourceBookingPolicy {
+        cancellation_policy: Some(CancellationPolicy {
+            notice_seconds: 24 * 60 * 60,
+            late_cancellation: LateCancellation::Forbidden,
+        }),
+        ..Default::default()
+    });
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    let now = Timestamp::now();
+    let booking = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Next month".to_string(),
+        start_time: Timestamp::from_micros(now.as_micros() + 30 * 24 * 3_600_000_000),
+        end_time: Timestamp::from_micros(now.as_micros() + 30 * 24 * 3_600_000_000 + 3_600_000_000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking_hash = booking.signed_action.hashed.hash.clone();
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Alice moves the booking to the next hour, and Bob accepts
+    let proposal: Record = conductors[0]
+        .call(&alice_zome, "propose_reschedule", ProposeRescheduleInput {
+            booking_hash: booking_hash.clone(),
+            start_time: Timestamp::from_micros(now.as_micros() + 3_600_000_000),
+            end_time: Timestamp::from_micros(now.as_micros() + 2 * 3_600_000_000),
+            counter_to: None,
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let _rescheduled_booking: Record = conductors[1]
+        .call(&bob_zome, "accept_reschedule_proposal", proposal.signed_action.hashed.hash.clone())
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Citing the version from before the reschedule doesn't avoid the late cancellation rule
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "cancel_booking", CancelBookingInput {
+            booking_hash: booking_hash.clone(),
+            booking_version_hash: Some(booking_hash.clone()),
+            reason: "Too late".to_string(),
+        })
+        .await;
+    assert!(result.is_err());
+
+    let latest_booking: Option<Record> = conductors[0]
+        .call(&alice_zome, "get_booking", booking_hash.clone())
+        .await;
+    assert!(latest_booking.is_some());
+}
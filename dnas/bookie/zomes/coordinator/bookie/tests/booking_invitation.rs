@@ -0,0 +1,189 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::booking_invitation::{InviteToBookingInput, RespondToBookingInvitationInput};
+use bookie::usage_event::UsageEventInput;
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invitees_join_bookings_up_to_the_capacity() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+    let carol_zome = carol.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.capacity = Some(2);
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Tennis match".to_string(),
+        start_time: Timestamp::from_micros(4102453334548000),
+        end_time: Timestamp::from_micros(4102456934548000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking_hash = booking_record.signed_action.hashed.hash.clone();
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    // Only the primary booker can invite
+    let result: ConductorApiResult<Record> = conductors[2]
+        .call_fallible(&carol_zome, "invite_to_booking", InviteToBookingInput {
+            booking_hash: booking_hash.clone(),
+            invitee: carol.agent_pubkey().clone(),
+        })
+        .await;
+    assert!(result.is_err());
+
+    let invitation: Record = conductors[1]
+        .call(&bob_zome, "invite_to_booking", InviteToBookingInput {
+            booking_hash: booking_hash.clone(),
+            invitee: carol.agent_pubkey().clone(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let pending: Vec<Record> = conductors[2]
+        .call(&carol_zome, "get_my_pending_booking_invitations", ())
+        .await;
+    assert_eq!(pending, vec![invitation.clone()]);
+
+    let _response: Record = conductors[2]
+        .call(&carol_zome, "respond_to_booking_invitation", RespondToBookingInvitationInput {
+            booking_invitation_hash: invitation.signed_action.hashed.hash.clone(),
+            accept: true,
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let participants: Vec<AgentPubKey> = conductors[0]
+        .call(&alice_zome, "get_booking_participants", booking_hash.clone())
+        .await;
+    assert_eq!(participants, vec![bobbo.agent_pubkey().clone(), carol.agent_pubkey().clone()]);
+
+    let carol_bookings: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_bookings_for_booker", carol.agent_pubkey().clone())
+        .await;
+    assert_eq!(carol_bookings, vec![booking_record]);
+
+    // Accepted invitees participate in the booking like its bookers
+    let _check_in: Record = conductors[2]
+        .call(&carol_zome, "check_in", UsageEventInput {
+            booking_hash: booking_hash.clone(),
+            odometer: None,
+            condition_note: None,
+            photo_hash: None,
+        })
+        .await;
+
+    // The booking is now full
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "invite_to_booking", InviteToBookingInput {
+            booking_hash: booking_hash.clone(),
+            invitee: alice.agent_pubkey().clone(),
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn withdrawn_invitations_cannot_be_answered_and_still_count() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(3, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,), (carol,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+    let carol_zome = carol.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.capacity = Some(3);
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Padel match".to_string(),
+        start_time: Timestamp::from_micros(4102453334548000),
+        end_time: Timestamp::from_micros(4102456934548000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking_hash = booking_record.signed_action.hashed.hash.clone();
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let invitation: Record = conductors[1]
+        .call(&bob_zome, "invite_to_booking", InviteToBookingInput {
+            booking_hash: booking_hash.clone(),
+            invitee: carol.agent_pubkey().clone(),
+        })
+        .await;
+    let invitation_hash = invitation.signed_action.hashed.hash.clone();
+    let _: () = conductors[1]
+        .call(&bob_zome, "withdraw_booking_invitation", invitation_hash.clone())
+        .await;
+
+    consistency_10s([&alice, &bobbo, &carol]).await;
+
+    let result: ConductorApiResult<Record> = conductors[2]
+        .call_fallible(&carol_zome, "respond_to_booking_invitation", RespondToBookingInvitationInput {
+            booking_invitation_hash: invitation_hash,
+            accept: true,
+        })
+        .await;
+    assert!(result.is_err());
+
+    // The withdrawn invitation keeps its seat, so only one more invitation fits
+    let _invitation: Record = conductors[1]
+        .call(&bob_zome, "invite_to_booking", InviteToBookingInput {
+            booking_hash: booking_hash.clone(),
+            invitee: alice.agent_pubkey().clone(),
+        })
+        .await;
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "invite_to_booking", InviteToBookingInput {
+            booking_hash: booking_hash.clone(),
+            invitee: carol.agent_pubkey().clone(),
+        })
+        .await;
+    assert!(result.is_err());
+}
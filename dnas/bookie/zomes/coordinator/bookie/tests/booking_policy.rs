@@ -0,0 +1,272 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::prelude::DnaModifiersOpt;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+
+use bookie::booking_cancellation::CancelBookingInput;
+use bookie::resource::UpdateResourceInput;
+
+mod common;
+use common::{create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_default_booking_policy() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    
+    let policy: BookingPolicy = conductors[0]
+        .call(&alice_zome, "get_booking_policy", ())
+        .await;
+
+    assert_eq!(policy, BookingPolicy::default());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resource_booking_policy_overrides_the_community_defaults() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    
+    let mut sample = sample_resource_1(&conductors[0], &alice_zome).await;
+    sample.booking_policy = Some(ResourceBookingPolicy {
+        slot_granularity_seconds: Some(30 * 60),
+        max_booking_duration_seconds: Some(3 * 24 * 60 * 60),
+        requires_approval: Some(false),
+        ..Default::default()
+    });
+    
+    // Alice creates a Resource with its own booking policy
+    let record: Record = create_resource(&conductors[0], &alice_zome, sample.clone()).await;
+
+    let policy: BookingPolicy = conductors[0]
+        .call(&alice_zome, "get_booking_policy_for_resource", record.signed_action.hashed.hash.clone())
+        .await;
+
+    assert_eq!(policy.slot_granularity_seconds, Some(30 * 60));
+    assert_eq!(policy.max_booking_duration_seconds, Some(3 * 24 * 60 * 60));
+    assert!(!policy.requests_require_approval);
+    assert_eq!(policy.min_notice_seconds, None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bookings_follow_the_policy_of_their_resource_version() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+
+    let sample = sample_resource_1(&conductors[0], &alice_zome).await;
+    let record: Record = create_resource(&conductors[0], &alice_zome, sample.clone()).await;
+    let resource_hash = record.signed_action.hashed.hash.clone();
+
+    // Alice restricts the resource to hourly slots
+    let mut updated_resource = sample.clone();
+    updated_resource.booking_policy = Some(ResourceBookingPolicy {
+        slot_granularity_seconds: Some(60 * 60),
+        ..Default::default()
+    });
+    let update_record: Record = conductors[0]
+        .call(&alice_zome, "update_resource", UpdateResourceInput {
+            original_resource_hash: resource_hash.clone(),
+            previous_resource_hash: resource_hash.clone(),
+            updated_resource,
+        })
+        .await;
+
+    let hour = 3_600_000_000;
+    let start = 4102444800000000 + 10 * hour;
+    let booking = |start_time: i64| Booking {
+        title: "Meeting".to_string(),
+        start_time: Timestamp::from_micros(start_time),
+        end_time: Timestamp::from_micros(start_time + hour),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    };
+
+    // The slots of the latest version apply
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_booking", booking(start + hour / 2))
+        .await;
+    assert!(result.is_err());
+
+    let booking_record: Record = conductors[0]
+        .call(&alice_zome, "create_booking", booking(start))
+        .await;
+    let booking = Booking::try_from(booking_record).unwrap();
+    assert_eq!(
+        booking.resource_version_hash,
+        Some(update_record.signed_action.hashed.hash.clone())
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bookings_violating_the_dna_properties_are_rejected() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+    let properties = BookingPolicy {
+        max_booking_duration_seconds: Some(2 * 60 * 60),
+        max_concurrent_bookings_per_agent: Some(1),
+        ..Default::default()
+    };
+    let dna = dna.update_modifiers(
+        DnaModifiersOpt::none().with_properties(SerializedBytes::try_from(properties.clone()).unwrap()),
+    );
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+
+    let policy: BookingPolicy = conductors[0]
+        .call(&alice_zome, "get_booking_policy", ())
+        .await;
+    assert_eq!(policy, properties);
+
+    let sample = sample_resource_1(&conductors[0], &alice_zome).await;
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample).await.signed_action.hashed.hash;
+
+    let hour = 3_600_000_000;
+    let start = 4102444800000000 + 10 * hour;
+    let booking = |start_time: i64, hours: i64| Booking {
+        title: "Meeting".to_string(),
+        start_time: Timestamp::from_micros(start_time),
+        end_time: Timestamp::from_micros(start_time + hours * hour),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    };
+
+    // Longer than the maximum duration
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_booking", booking(start, 3))
+        .await;
+    assert!(result.is_err());
+
+    let _booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", booking(start, 2))
+        .await;
+
+    // Bob already has an upcoming booking
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_booking", booking(start + 24 * hour, 1))
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bookings_cancelled_by_the_booker_free_their_concurrency_slot() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+    let properties = BookingPolicy {
+        max_concurrent_bookings_per_agent: Some(1),
+        ..Default::default()
+    };
+    let dna = dna.update_modifiers(
+        DnaModifiersOpt::none().with_properties(SerializedBytes::try_from(properties).unwrap()),
+    );
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let sample = sample_resource_1(&conductors[0], &alice_zome).await;
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample).await.signed_action.hashed.hash;
+
+    let hour = 3_600_000_000;
+    let start = 4102444800000000 + 10 * hour;
+    let booking = |start_time: i64| Booking {
+        title: "Meeting".to_string(),
+        start_time: Timestamp::from_micros(start_time),
+        end_time: Timestamp::from_micros(start_time + hour),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    };
+
+    let first_booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", booking(start))
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Bob cancels the booking himself, so the delete is in his chain
+    let _cancellation: Record = conductors[1]
+        .call(&bob_zome, "cancel_booking", CancelBookingInput {
+            booking_hash: first_booking.signed_action.hashed.hash.clone(),
+            booking_version_hash: None,
+            reason: "Plans changed".to_string(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Alice can book him again
+    let _second_booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", booking(start + 24 * hour))
+        .await;
+}
@@ -180,3 +180,57 @@ async fn create_and_delete_booking_request() {
         
     assert!(get_record.is_none());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn past_booking_requests_expire() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let now = Timestamp::now();
+    let mut expired_request = sample_booking_request_1(&conductors[1], &bob_zome).await;
+    expired_request.resource_hash = resource_hash.clone();
+    expired_request.start_time = Timestamp::from_micros(now.as_micros() - 7_200_000_000);
+    expired_request.end_time = Timestamp::from_micros(now.as_micros() - 3_600_000_000);
+
+    let mut pending_request = sample_booking_request_1(&conductors[1], &bob_zome).await;
+    pending_request.resource_hash = resource_hash.clone();
+
+    // Bob creates a request for a slot that has already started, and another one in the future
+    let expired_record = create_booking_request(&conductors[1], &bob_zome, expired_request).await;
+    let pending_record = create_booking_request(&conductors[1], &bob_zome, pending_request).await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let pending_requests: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_booking_requests_for_resource", resource_hash.clone())
+        .await;
+    assert_eq!(pending_requests, vec![pending_record.clone()]);
+
+    // Only the owner can clear the expired requests
+    let result: ConductorApiResult<Vec<ActionHash>> = conductors[1]
+        .call_fallible(&bob_zome, "clear_expired_booking_requests", resource_hash.clone())
+        .await;
+    assert!(result.is_err());
+
+    let cleared: Vec<ActionHash> = conductors[0]
+        .call(&alice_zome, "clear_expired_booking_requests", resource_hash.clone())
+        .await;
+    assert_eq!(cleared, vec![expired_record.signed_action.hashed.hash.clone()]);
+}
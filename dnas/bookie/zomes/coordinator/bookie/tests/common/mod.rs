@@ -2,6 +2,7 @@ use hdk::prelude::*;
 use holochain::sweettest::*;
 
 use bookie_integrity::*;
+use std::collections::BTreeMap;
 
 
 
@@ -9,7 +10,16 @@ pub async fn sample_resource_1(conductor: &SweetConductor, zome: &SweetZome) ->
     Resource {
 	  name: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
 	  description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
-	  image_hash: ::fixt::fixt!(EntryHash),
+	  image_hash: None,
+	  booking_policy: None,
+	  pricing: None,
+	  category: None,
+	  capacity: None,
+	  location: None,
+	  attribute_schema_hash: None,
+	  attributes: BTreeMap::new(),
+	  gallery: vec![],
+	  documents: vec![],
     }
 }
 
@@ -17,7 +27,16 @@ pub async fn sample_resource_2(conductor: &SweetConductor, zome: &SweetZome) ->
     Resource {
 	  name: "Lorem ipsum 2".to_string(),
 	  description: "Lorem ipsum 2".to_string(),
-	  image_hash: ::fixt::fixt!(EntryHash),
+	  image_hash: None,
+	  booking_policy: None,
+	  pricing: None,
+	  category: None,
+	  capacity: None,
+	  location: None,
+	  attribute_schema_hash: None,
+	  attributes: BTreeMap::new(),
+	  gallery: vec![],
+	  documents: vec![],
     }
 }
 
@@ -35,8 +54,9 @@ pub async fn sample_booking_request_1(conductor: &SweetConductor, zome: &SweetZo
           resource_hash: create_resource(conductor, zome, sample_resource_1(conductor, zome).await).await.signed_action.hashed.hash,
 	  title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
 	  comment: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
-	  start_time: 1674053334548000,
-	  end_time: 1674053334548000,
+	  start_time: Timestamp::from_micros(4102453334548000),
+	  end_time: Timestamp::from_micros(4102453334548000),
+	  bundle_hash: None,
     }
 }
 
@@ -45,8 +65,9 @@ pub async fn sample_booking_request_2(conductor: &SweetConductor, zome: &SweetZo
           resource_hash: create_resource(conductor, zome, sample_resource_2(conductor, zome).await).await.signed_action.hashed.hash,
 	  title: "Lorem ipsum 2".to_string(),
 	  comment: "Lorem ipsum 2".to_string(),
-	  start_time: 1674059334548000,
-	  end_time: 1674059334548000,
+	  start_time: Timestamp::from_micros(4102459334548000),
+	  end_time: Timestamp::from_micros(4102459334548000),
+	  bundle_hash: None,
     }
 }
 
@@ -62,20 +83,28 @@ pub async fn create_booking_request(conductor: &SweetConductor, zome: &SweetZome
 pub async fn sample_booking_1(conductor: &SweetConductor, zome: &SweetZome) -> Booking {
     Booking {
 	  title: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
-	  start_time: 1674053334548000,
-	  end_time: 1674053334548000,
-          booking_request_hash: create_booking_request(conductor, zome, sample_booking_request_1(conductor, zome).await).await.signed_action.hashed.hash,
+	  start_time: Timestamp::from_micros(4102453334548000),
+	  end_time: Timestamp::from_micros(4102453334548000),
+	  bookers: vec![zome.cell_id().agent_pubkey().clone()],
+          booking_request_hash: Some(create_booking_request(conductor, zome, sample_booking_request_1(conductor, zome).await).await.signed_action.hashed.hash),
+          reschedule_proposal_hash: None,
+          price: None,
           resource_hash: create_resource(conductor, zome, sample_resource_1(conductor, zome).await).await.signed_action.hashed.hash,
+          resource_version_hash: None,
     }
 }
 
 pub async fn sample_booking_2(conductor: &SweetConductor, zome: &SweetZome) -> Booking {
     Booking {
 	  title: "Lorem ipsum 2".to_string(),
-	  start_time: 1674059334548000,
-	  end_time: 1674059334548000,
-          booking_request_hash: create_booking_request(conductor, zome, sample_booking_request_2(conductor, zome).await).await.signed_action.hashed.hash,
+	  start_time: Timestamp::from_micros(4102459334548000),
+	  end_time: Timestamp::from_micros(4102459334548000),
+	  bookers: vec![zome.cell_id().agent_pubkey().clone()],
+          booking_request_hash: Some(create_booking_request(conductor, zome, sample_booking_request_2(conductor, zome).await).await.signed_action.hashed.hash),
+          reschedule_proposal_hash: None,
+          price: None,
           resource_hash: create_resource(conductor, zome, sample_resource_2(conductor, zome).await).await.signed_action.hashed.hash,
+          resource_version_hash: None,
     }
 }
 
@@ -0,0 +1,105 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::condition_report::ResourceStatus;
+
+mod common;
+use common::{create_resource, sample_booking_1, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn out_of_service_resources_cannot_be_booked() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Bob hasn't booked the resource, so he can't take it out of service
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_condition_report", ConditionReport {
+            resource_hash: resource_hash.clone(),
+            booking_hash: None,
+            severity: ConditionReportSeverity::OutOfService,
+            description: "The chain is broken".to_string(),
+            resolved: false,
+        })
+        .await;
+    assert!(result.is_err());
+
+    // Reports can't be filed already resolved
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_condition_report", ConditionReport {
+            resource_hash: resource_hash.clone(),
+            booking_hash: None,
+            severity: ConditionReportSeverity::Minor,
+            description: "The bell is rusty".to_string(),
+            resolved: true,
+        })
+        .await;
+    assert!(result.is_err());
+
+    // Alice reports that the resource is broken
+    let report: Record = conductors[0]
+        .call(&alice_zome, "create_condition_report", ConditionReport {
+            resource_hash: resource_hash.clone(),
+            booking_hash: None,
+            severity: ConditionReportSeverity::OutOfService,
+            description: "The chain is broken".to_string(),
+            resolved: false,
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let status: ResourceStatus = conductors[0]
+        .call(&alice_zome, "get_resource_status", resource_hash.clone())
+        .await;
+    assert_eq!(status, ResourceStatus::OutOfService);
+
+    let mut booking = sample_booking_1(&conductors[0], &alice_zome).await;
+    booking.resource_hash = resource_hash.clone();
+
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_booking", booking.clone())
+        .await;
+    assert!(result.is_err());
+
+    // Only the owner can resolve the report
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "resolve_condition_report", report.signed_action.hashed.hash.clone())
+        .await;
+    assert!(result.is_err());
+
+    let _resolved: Record = conductors[0]
+        .call(&alice_zome, "resolve_condition_report", report.signed_action.hashed.hash.clone())
+        .await;
+
+    let status: ResourceStatus = conductors[0]
+        .call(&alice_zome, "get_resource_status", resource_hash.clone())
+        .await;
+    assert_eq!(status, ResourceStatus::Ok);
+
+    let _booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", booking)
+        .await;
+}
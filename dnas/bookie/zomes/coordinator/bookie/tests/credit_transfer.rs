@@ -0,0 +1,103 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::prelude::DnaModifiersOpt;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+use std::collections::BTreeMap;
+
+use bookie_integrity::*;
+
+use bookie::booking_cancellation::CancelBookingInput;
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accepted_bookings_transfer_time_credits_to_the_owner() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+    let properties = BookingPolicy {
+        credit_ledger: Some(CreditLedgerSettings {
+            unit: CreditUnit::Time,
+            external_zome: None,
+        }),
+        ..Default::default()
+    };
+    let dna = dna.update_modifiers(
+        DnaModifiersOpt::none().with_properties(SerializedBytes::try_from(properties).unwrap()),
+    );
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    // Alice accepts a two hour booking for Bob
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Two hours".to_string(),
+        start_time: Timestamp::from_micros(4102453334548000),
+        end_time: Timestamp::from_micros(4102453334548000 + 2 * 3_600_000_000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking_hash = booking_record.signed_action.hashed.hash.clone();
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let transfers: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_credit_transfers_for_booking", booking_hash.clone())
+        .await;
+    assert_eq!(transfers.len(), 1);
+    let transfer: CreditTransfer = transfers[0].entry().to_app_option().unwrap().unwrap();
+    assert_eq!(transfer.from, bobbo.agent_pubkey().clone());
+    assert_eq!(transfer.to, alice.agent_pubkey().clone());
+    assert_eq!(transfer.amount, 2 * 3600);
+
+    let bob_balance: BTreeMap<String, i64> = conductors[1]
+        .call(&bob_zome, "get_credit_balance", bobbo.agent_pubkey().clone())
+        .await;
+    assert_eq!(bob_balance.get("seconds"), Some(&-7200));
+    let alice_balance: BTreeMap<String, i64> = conductors[1]
+        .call(&bob_zome, "get_credit_balance", alice.agent_pubkey().clone())
+        .await;
+    assert_eq!(alice_balance.get("seconds"), Some(&7200));
+
+    // Alice cancels the booking herself, so Bob gets his credits back
+    let _cancellation: Record = conductors[0]
+        .call(&alice_zome, "cancel_booking", CancelBookingInput {
+            booking_hash: booking_hash.clone(),
+            booking_version_hash: None,
+            reason: "The resource is broken".to_string(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let transfers: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_credit_transfers_for_booking", booking_hash.clone())
+        .await;
+    assert_eq!(transfers.len(), 2);
+
+    let bob_balance: BTreeMap<String, i64> = conductors[1]
+        .call(&bob_zome, "get_credit_balance", bobbo.agent_pubkey().clone())
+        .await;
+    assert_eq!(bob_balance.get("seconds"), Some(&0));
+}
@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::holds::CreateHoldInput;
+
+mod common;
+use common::{create_resource, sample_booking_request_1, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hold_blocks_others_until_released() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    let booking_request = sample_booking_request_1(&conductors[0], &alice_zome).await;
+    let start_time = booking_request.start_time;
+    let end_time = Timestamp::from_micros(start_time.as_micros() + 3_600_000_000);
+
+    // Bob holds the slot while he decides
+    let hold_record: Record = conductors[1]
+        .call(&bob_zome, "create_hold", CreateHoldInput {
+            resource_hash: resource_hash.clone(),
+            start_time,
+            end_time,
+        })
+        .await;
+    let hold = Hold::try_from(hold_record.clone()).unwrap();
+    assert_eq!(
+        hold.expires_at.as_micros() - hold_record.action().timestamp().as_micros() <= 900_000_000,
+        true
+    );
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let holds: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_active_holds_for_resource", resource_hash.clone())
+        .await;
+    assert_eq!(holds, vec![hold_record.clone()]);
+
+    let booking = Booking {
+        title: "Alice's booking".to_string(),
+        start_time,
+        end_time,
+        bookers: vec![alice.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    };
+
+    // Alice can't book the held slot
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_booking", booking.clone())
+        .await;
+    assert!(result.is_err());
+
+    // Bob releases the hold
+    let _: () = conductors[1]
+        .call(&bob_zome, "release_hold", hold_record.signed_action.hashed.hash.clone())
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let holds: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_active_holds_for_resource", resource_hash.clone())
+        .await;
+    assert!(holds.is_empty());
+
+    let _booking_record: Record = conductors[0]
+        .call(&alice_zome, "create_booking", booking)
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn holds_must_cover_a_time_range() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    let booking_request = sample_booking_request_1(&conductors[0], &alice_zome).await;
+
+    // Bob can't hold an empty slot
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_hold", CreateHoldInput {
+            resource_hash: resource_hash.clone(),
+            start_time: booking_request.start_time,
+            end_time: booking_request.start_time,
+        })
+        .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,186 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::invoice::{CreateInvoiceInput, InvoiceFormat, RenderInvoiceInput, UpdateInvoiceStatusInput};
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invoices_are_numbered_sequentially_and_can_be_paid() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.pricing = Some(PricingModel {
+        currency: "EUR".to_string(),
+        flat_fee: 0,
+        unit: PricingUnit::Hour,
+        amount_per_unit: 1000,
+        tiers: vec![],
+        peak_rates: vec![],
+        utc_offset_seconds: 0,
+        deposit_amount: None,
+    });
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    let hour = 3_600_000_000;
+    let mut booking_hashes = vec![];
+    for i in 0..2 {
+        let record = create_booking(&conductors[0], &alice_zome, Booking {
+            title: format!("Meeting {}", i),
+            start_time: Timestamp::from_micros(4102453334548000 + 2 * i * hour),
+            end_time: Timestamp::from_micros(4102453334548000 + (2 * i + 1) * hour),
+            bookers: vec![bobbo.agent_pubkey().clone()],
+            booking_request_hash: None,
+            reschedule_proposal_hash: None,
+            price: None,
+            resource_hash: resource_hash.clone(),
+            resource_version_hash: None,
+        }).await;
+        booking_hashes.push(record.signed_action.hashed.hash);
+    }
+
+    let first_invoice: Record = conductors[0]
+        .call(&alice_zome, "create_invoice", CreateInvoiceInput {
+            customer: bobbo.agent_pubkey().clone(),
+            booking_hashes: booking_hashes.clone(),
+            tax_rate_basis_points: 2100,
+        })
+        .await;
+    let invoice = Invoice::try_from(first_invoice.clone()).unwrap();
+    assert_eq!(invoice.number, 1);
+    assert_eq!(invoice.subtotal, 2000);
+    assert_eq!(invoice.tax, 420);
+    assert_eq!(invoice.total, 2420);
+
+    let second_invoice: Record = conductors[0]
+        .call(&alice_zome, "create_invoice", CreateInvoiceInput {
+            customer: bobbo.agent_pubkey().clone(),
+            booking_hashes: vec![booking_hashes[0].clone()],
+            tax_rate_basis_points: 0,
+        })
+        .await;
+    assert_eq!(Invoice::try_from(second_invoice).unwrap().number, 2);
+
+    // Bob can't invoice bookings of Alice's resource
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_invoice", CreateInvoiceInput {
+            customer: bobbo.agent_pubkey().clone(),
+            booking_hashes: booking_hashes.clone(),
+            tax_rate_basis_points: 0,
+        })
+        .await;
+    assert!(result.is_err());
+
+    let text: String = conductors[0]
+        .call(&alice_zome, "render_invoice", RenderInvoiceInput {
+            invoice_hash: first_invoice.signed_action.hashed.hash.clone(),
+            format: InvoiceFormat::Text,
+        })
+        .await;
+    assert!(text.contains("Invoice #1"));
+    assert!(text.contains("24.20 EUR"));
+
+    let _paid: Record = conductors[0]
+        .call(&alice_zome, "update_invoice_status", UpdateInvoiceStatusInput {
+            original_invoice_hash: first_invoice.signed_action.hashed.hash.clone(),
+            status: InvoiceStatus::Paid,
+        })
+        .await;
+
+    // Paid invoices can't be voided
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "update_invoice_status", UpdateInvoiceStatusInput {
+            original_invoice_hash: first_invoice.signed_action.hashed.hash.clone(),
+            status: InvoiceStatus::Void,
+        })
+        .await;
+    assert!(result.is_err());
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let invoices: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_invoices_for_agent", bobbo.agent_pubkey().clone())
+        .await;
+    assert_eq!(invoices.len(), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn invoices_use_the_decimals_of_their_currency() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+
+    // Yen have no minor unit
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.pricing = Some(PricingModel {
+        currency: "JPY".to_string(),
+        flat_fee: 0,
+        unit: PricingUnit::Hour,
+        amount_per_unit: 1500,
+        tiers: vec![],
+        peak_rates: vec![],
+        utc_offset_seconds: 0,
+        deposit_amount: None,
+    });
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    let record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Meeting".to_string(),
+        start_time: Timestamp::from_micros(4102453334548000),
+        end_time: Timestamp::from_micros(4102453334548000 + 3_600_000_000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+
+    let invoice: Record = conductors[0]
+        .call(&alice_zome, "create_invoice", CreateInvoiceInput {
+            customer: bobbo.agent_pubkey().clone(),
+            booking_hashes: vec![record.signed_action.hashed.hash],
+            tax_rate_basis_points: 0,
+        })
+        .await;
+
+    let text: String = conductors[0]
+        .call(&alice_zome, "render_invoice", RenderInvoiceInput {
+            invoice_hash: invoice.signed_action.hashed.hash.clone(),
+            format: InvoiceFormat::Text,
+        })
+        .await;
+    assert!(text.contains("Total\t1500 JPY"));
+}
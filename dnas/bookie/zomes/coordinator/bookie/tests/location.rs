@@ -0,0 +1,134 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::location::GetResourcesNearInput;
+use bookie::resource::UpdateResourceInput;
+
+mod common;
+use common::{create_resource, sample_resource_1};
+
+fn hashes(records: &[Record]) -> Vec<ActionHash> {
+    records
+        .iter()
+        .map(|record| record.signed_action.hashed.hash.clone())
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resources_are_found_by_distance() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let locations = [
+        ("Plaça de Catalunya", 41.3874, 2.1700),
+        ("Passeig de Gràcia", 41.3935, 2.1640),
+        ("Puerta del Sol", 40.4169, -3.7035),
+    ];
+    let mut resources = Vec::new();
+    for (address, latitude, longitude) in locations {
+        let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+        resource.location = Some(Location {
+            address: address.to_string(),
+            latitude,
+            longitude,
+        });
+        resources.push(create_resource(&conductors[0], &alice_zome, resource).await);
+    }
+    let resource_without_location = sample_resource_1(&conductors[0], &alice_zome).await;
+    create_resource(&conductors[0], &alice_zome, resource_without_location).await;
+
+    // Coordinates out of range are invalid
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.location = Some(Location {
+        address: "Nowhere".to_string(),
+        latitude: 91.0,
+        longitude: 0.0,
+    });
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", resource)
+        .await;
+    assert!(result.is_err());
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let near: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_resources_near", GetResourcesNearInput {
+            latitude: 41.3874,
+            longitude: 2.1700,
+            radius_meters: 1000.0,
+        })
+        .await;
+    assert_eq!(hashes(&near), hashes(&resources[0..2]));
+
+    let near: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_resources_near", GetResourcesNearInput {
+            latitude: 41.3874,
+            longitude: 2.1700,
+            radius_meters: 100.0,
+        })
+        .await;
+    assert_eq!(hashes(&near), hashes(&resources[0..1]));
+
+    let near: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_resources_near", GetResourcesNearInput {
+            latitude: 41.3874,
+            longitude: 2.1700,
+            radius_meters: 600_000.0,
+        })
+        .await;
+    assert_eq!(hashes(&near), hashes(&resources));
+
+    // Moving a resource updates the index
+    let mut moved_resource: Resource = resources[1].entry().to_app_option().unwrap().unwrap();
+    moved_resource.location = Some(Location {
+        address: "Gran Vía".to_string(),
+        latitude: 40.4200,
+        longitude: -3.7050,
+    });
+    let _: Record = conductors[0]
+        .call(&alice_zome, "update_resource", UpdateResourceInput {
+            original_resource_hash: resources[1].signed_action.hashed.hash.clone(),
+            previous_resource_hash: resources[1].signed_action.hashed.hash.clone(),
+            updated_resource: moved_resource,
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let near: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_resources_near", GetResourcesNearInput {
+            latitude: 41.3874,
+            longitude: 2.1700,
+            radius_meters: 1000.0,
+        })
+        .await;
+    assert_eq!(hashes(&near), hashes(&resources[0..1]));
+
+    let near: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_resources_near", GetResourcesNearInput {
+            latitude: 40.4169,
+            longitude: -3.7035,
+            radius_meters: 1000.0,
+        })
+        .await;
+    assert_eq!(near.len(), 2);
+}
@@ -0,0 +1,109 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::usage_event::UsageEventInput;
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn maintenance_windows_block_bookings() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    // The sauna is serviced every six months and cleaned after each use
+    let _service: Record = conductors[0]
+        .call(&alice_zome, "create_maintenance_task", MaintenanceTask {
+            resource_hash: resource_hash.clone(),
+            title: "Service".to_string(),
+            trigger: MaintenanceTrigger::Interval { interval_seconds: 182 * 24 * 60 * 60 },
+            duration_seconds: 24 * 60 * 60,
+        })
+        .await;
+    let _cleaning: Record = conductors[0]
+        .call(&alice_zome, "create_maintenance_task", MaintenanceTask {
+            resource_hash: resource_hash.clone(),
+            title: "Cleaning".to_string(),
+            trigger: MaintenanceTrigger::AfterEachUse,
+            duration_seconds: 60 * 60,
+        })
+        .await;
+
+    let upcoming_maintenance: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_upcoming_maintenance", resource_hash.clone())
+        .await;
+    assert_eq!(upcoming_maintenance.len(), 1);
+
+    let now = Timestamp::now();
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Sauna".to_string(),
+        start_time: Timestamp::from_micros(now.as_micros() - 3_600_000_000),
+        end_time: now,
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let input = UsageEventInput {
+        booking_hash: booking_record.signed_action.hashed.hash.clone(),
+        odometer: None,
+        condition_note: None,
+        photo_hash: None,
+    };
+    let _check_in: Record = conductors[1]
+        .call(&bob_zome, "check_in", input.clone())
+        .await;
+    // Checking out asks Alice to schedule the cleaning
+    let _check_out: Record = conductors[1]
+        .call(&bob_zome, "check_out", input)
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let upcoming_maintenance: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_upcoming_maintenance", resource_hash.clone())
+        .await;
+    assert_eq!(upcoming_maintenance.len(), 2);
+
+    let now = Timestamp::now();
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_booking", Booking {
+            title: "Sauna again".to_string(),
+            start_time: now,
+            end_time: Timestamp::from_micros(now.as_micros() + 1_800_000_000),
+            bookers: vec![bobbo.agent_pubkey().clone()],
+            booking_request_hash: None,
+            reschedule_proposal_hash: None,
+            price: None,
+            resource_hash: resource_hash.clone(),
+            resource_version_hash: None,
+        })
+        .await;
+    assert!(result.is_err());
+}
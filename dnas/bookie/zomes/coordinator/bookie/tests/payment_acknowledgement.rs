@@ -0,0 +1,199 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::payment_acknowledgement::{BookingPayment, BookingPaymentStatus};
+use bookie::resource::UpdateResourceInput;
+use bookie::usage_event::UsageEventInput;
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bookings_stay_tentative_until_the_deposit_is_acknowledged() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.pricing = Some(PricingModel {
+        currency: "EUR".to_string(),
+        flat_fee: 0,
+        unit: PricingUnit::Hour,
+        amount_per_unit: 1000,
+        tiers: vec![],
+        peak_rates: vec![],
+        utc_offset_seconds: 0,
+        deposit_amount: Some(500),
+    });
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Car rental".to_string(),
+        start_time: Timestamp::from_micros(4102453334548000),
+        end_time: Timestamp::from_micros(4102453334548000 + 2 * 3_600_000_000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking_hash = booking_record.signed_action.hashed.hash.clone();
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let payment: BookingPayment = conductors[1]
+        .call(&bob_zome, "get_booking_payment", booking_hash.clone())
+        .await;
+    assert_eq!(payment.status, BookingPaymentStatus::Unpaid);
+    assert!(payment.tentative);
+
+    let check_in_input = UsageEventInput {
+        booking_hash: booking_hash.clone(),
+        odometer: None,
+        condition_note: None,
+        photo_hash: None,
+    };
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "check_in", check_in_input.clone())
+        .await;
+    assert!(result.is_err());
+
+    let deposit = PaymentAcknowledgement {
+        booking_hash: booking_hash.clone(),
+        kind: PaymentKind::Deposit,
+        amount: 500,
+        currency: "EUR".to_string(),
+        note: "Cash".to_string(),
+    };
+
+    // Only the owner can acknowledge payments
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "acknowledge_payment", deposit.clone())
+        .await;
+    assert!(result.is_err());
+
+    let _deposit: Record = conductors[0]
+        .call(&alice_zome, "acknowledge_payment", deposit)
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let payment: BookingPayment = conductors[1]
+        .call(&bob_zome, "get_booking_payment", booking_hash.clone())
+        .await;
+    assert_eq!(payment.status, BookingPaymentStatus::DepositPaid);
+    assert!(!payment.tentative);
+
+    let _check_in: Record = conductors[1]
+        .call(&bob_zome, "check_in", check_in_input)
+        .await;
+
+    let _payment: Record = conductors[0]
+        .call(&alice_zome, "acknowledge_payment", PaymentAcknowledgement {
+            booking_hash: booking_hash.clone(),
+            kind: PaymentKind::Payment,
+            amount: 1500,
+            currency: "EUR".to_string(),
+            note: "Card".to_string(),
+        })
+        .await;
+
+    let payment: BookingPayment = conductors[0]
+        .call(&alice_zome, "get_booking_payment", booking_hash.clone())
+        .await;
+    assert_eq!(payment.status, BookingPaymentStatus::FullyPaid);
+
+    // Refunds can't exceed what was paid
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "acknowledge_payment", PaymentAcknowledgement {
+            booking_hash: booking_hash.clone(),
+            kind: PaymentKind::Refund,
+            amount: 2500,
+            currency: "EUR".to_string(),
+            note: "Card".to_string(),
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn deposits_are_the_ones_of_the_booked_resource_version() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource.clone()).await.signed_action.hashed.hash;
+
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Car rental".to_string(),
+        start_time: Timestamp::from_micros(4102453334548000),
+        end_time: Timestamp::from_micros(4102453334548000 + 2 * 3_600_000_000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking_hash = booking_record.signed_action.hashed.hash.clone();
+
+    // Alice starts requiring a deposit after the booking was made
+    let mut updated_resource = resource;
+    updated_resource.pricing = Some(PricingModel {
+        currency: "EUR".to_string(),
+        flat_fee: 0,
+        unit: PricingUnit::Hour,
+        amount_per_unit: 1000,
+        tiers: vec![],
+        peak_rates: vec![],
+        utc_offset_seconds: 0,
+        deposit_amount: Some(500),
+    });
+    let _update: Record = conductors[0]
+        .call(&alice_zome, "update_resource", UpdateResourceInput {
+            original_resource_hash: resource_hash.clone(),
+            previous_resource_hash: resource_hash.clone(),
+            updated_resource,
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let payment: BookingPayment = conductors[1]
+        .call(&bob_zome, "get_booking_payment", booking_hash.clone())
+        .await;
+    assert_eq!(payment.deposit_amount, None);
+    assert!(!payment.tentative);
+}
@@ -0,0 +1,174 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::pricing::QuoteBookingInput;
+use bookie::resource::UpdateResourceInput;
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+fn sample_pricing_model() -> PricingModel {
+    PricingModel {
+        currency: "EUR".to_string(),
+        flat_fee: 500,
+        unit: PricingUnit::Hour,
+        amount_per_unit: 1000,
+        tiers: vec![PriceTier {
+            from_unit: 2,
+            amount_per_unit: 800,
+        }],
+        peak_rates: vec![PeakRate {
+            weekdays: vec![5, 6],
+            start_hour: 0,
+            end_hour: 24,
+            surcharge_per_hour: 200,
+        }],
+        utc_offset_seconds: 0,
+        deposit_amount: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn quotes_follow_the_pricing_model_and_prices_are_frozen() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.pricing = Some(sample_pricing_model());
+    let resource_record = create_resource(&conductors[0], &alice_zome, resource.clone()).await;
+    let resource_hash = resource_record.signed_action.hashed.hash.clone();
+
+    // 2100-01-01 was a Friday
+    let friday = 4102444800000000 + 10 * 3_600_000_000;
+    let saturday = friday + 24 * 3_600_000_000;
+    let hour = 3_600_000_000;
+
+    let quote: Option<BookingPrice> = conductors[0]
+        .call(&alice_zome, "quote_booking", QuoteBookingInput {
+            resource_hash: resource_hash.clone(),
+            start_time: Timestamp::from_micros(friday),
+            end_time: Timestamp::from_micros(friday + 3 * hour),
+            quantity: None,
+        })
+        .await;
+    assert_eq!(quote.unwrap().amount, 500 + 1000 + 1000 + 800);
+
+    let quote: Option<BookingPrice> = conductors[0]
+        .call(&alice_zome, "quote_booking", QuoteBookingInput {
+            resource_hash: resource_hash.clone(),
+            start_time: Timestamp::from_micros(saturday),
+            end_time: Timestamp::from_micros(saturday + 3 * hour),
+            quantity: Some(2),
+        })
+        .await;
+    assert_eq!(quote.unwrap().amount, 500 + 2 * (1000 + 1000 + 800 + 3 * 200));
+
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Priced booking".to_string(),
+        start_time: Timestamp::from_micros(friday),
+        end_time: Timestamp::from_micros(friday + 3 * hour),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking = Booking::try_from(booking_record.clone()).unwrap();
+    assert_eq!(booking.price.unwrap().amount, 3300);
+
+    // Raising the price doesn't change the existing booking
+    let mut updated_resource = resource.clone();
+    updated_resource.pricing = Some(PricingModel {
+        amount_per_unit: 2000,
+        ..sample_pricing_model()
+    });
+    let _update: Record = conductors[0]
+        .call(&alice_zome, "update_resource", UpdateResourceInput {
+            original_resource_hash: resource_hash.clone(),
+            previous_resource_hash: resource_hash.clone(),
+            updated_resource,
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let booking: Option<Record> = conductors[1]
+        .call(&bob_zome, "get_booking", booking_record.signed_action.hashed.hash.clone())
+        .await;
+    let booking = Booking::try_from(booking.unwrap()).unwrap();
+    assert_eq!(booking.price.unwrap().amount, 3300);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pricing_models_need_a_real_utc_offset_and_ascending_tiers() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+
+    let sample = sample_resource_1(&conductors[0], &alice_zome).await;
+    let invalid_pricing_models = [
+        PricingModel {
+            utc_offset_seconds: 15 * 60 * 60,
+            ..sample_pricing_model()
+        },
+        PricingModel {
+            tiers: vec![PriceTier {
+                from_unit: 0,
+                amount_per_unit: 800,
+            }],
+            ..sample_pricing_model()
+        },
+        PricingModel {
+            tiers: vec![
+                PriceTier {
+                    from_unit: 4,
+                    amount_per_unit: 600,
+                },
+                PriceTier {
+                    from_unit: 2,
+                    amount_per_unit: 800,
+                },
+            ],
+            ..sample_pricing_model()
+        },
+    ];
+    for pricing_model in invalid_pricing_models {
+        let mut resource = sample.clone();
+        resource.pricing = Some(pricing_model);
+        let result: ConductorApiResult<Record> = conductors[0]
+            .call_fallible(&alice_zome, "create_resource", resource)
+            .await;
+        assert!(result.is_err());
+    }
+}
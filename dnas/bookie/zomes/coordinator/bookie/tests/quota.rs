@@ -0,0 +1,102 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::prelude::DnaModifiersOpt;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::quota::QuotaUsage;
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+fn booking_for(booker: AgentPubKey, resource_hash: ActionHash, start: i64, hours: i64) -> Booking {
+    Booking {
+        title: "Evening session".to_string(),
+        start_time: Timestamp::from_micros(start),
+        end_time: Timestamp::from_micros(start + hours * 3_600_000_000),
+        bookers: vec![booker],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash,
+        resource_version_hash: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn weekly_quotas_limit_the_booked_hours() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+    let properties = BookingPolicy {
+        quotas: vec![BookingQuota {
+            scope: QuotaScope::Category {
+                category: "studio".to_string(),
+            },
+            period: QuotaPeriod::Week,
+            max_booked_seconds: 3 * 60 * 60,
+        }],
+        ..Default::default()
+    };
+    let dna = dna.update_modifiers(
+        DnaModifiersOpt::none().with_properties(SerializedBytes::try_from(properties).unwrap()),
+    );
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+    resource.category = Some("studio".to_string());
+    let resource_hash = create_resource(&conductors[0], &alice_zome, resource).await.signed_action.hashed.hash;
+
+    // 2100-01-04 was a Monday
+    let hour = 3_600_000_000;
+    let monday = 4102444800000000 + 3 * 24 * hour + 18 * hour;
+    let wednesday = monday + 2 * 24 * hour;
+    let next_monday = monday + 7 * 24 * hour;
+    let bob = bobbo.agent_pubkey().clone();
+
+    create_booking(&conductors[0], &alice_zome, booking_for(bob.clone(), resource_hash.clone(), monday, 2)).await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Two more hours in the same week exceed the quota
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_booking", booking_for(bob.clone(), resource_hash.clone(), wednesday, 2))
+        .await;
+    assert!(result.is_err());
+
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "create_booking_request", BookingRequest {
+            resource_hash: resource_hash.clone(),
+            title: "Evening session".to_string(),
+            comment: "".to_string(),
+            start_time: Timestamp::from_micros(wednesday),
+            end_time: Timestamp::from_micros(wednesday + 2 * hour),
+            bundle_hash: None,
+        })
+        .await;
+    assert!(result.is_err());
+
+    // The quota is renewed the next week
+    create_booking(&conductors[0], &alice_zome, booking_for(bob.clone(), resource_hash.clone(), next_monday, 2)).await;
+
+    let usage: Vec<QuotaUsage> = conductors[1]
+        .call(&bob_zome, "get_my_quota_usage", ())
+        .await;
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage[0].booked_seconds, 0);
+}
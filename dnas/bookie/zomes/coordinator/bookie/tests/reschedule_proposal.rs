@@ -0,0 +1,103 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::reschedule_proposal::ProposeRescheduleInput;
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reschedule_after_counter_proposal() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    let start = 4102453334548000;
+    let hour = 3_600_000_000;
+
+    // Alice books the resource for Bob
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Bob's booking".to_string(),
+        start_time: Timestamp::from_micros(start),
+        end_time: Timestamp::from_micros(start + hour),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking_hash = booking_record.signed_action.hashed.hash.clone();
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Bob proposes to move it two hours later
+    let proposal: Record = conductors[1]
+        .call(&bob_zome, "propose_reschedule", ProposeRescheduleInput {
+            booking_hash: booking_hash.clone(),
+            start_time: Timestamp::from_micros(start + 2 * hour),
+            end_time: Timestamp::from_micros(start + 3 * hour),
+            counter_to: None,
+        })
+        .await;
+
+    // Bob can't accept his own proposal
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "accept_reschedule_proposal", proposal.signed_action.hashed.hash.clone())
+        .await;
+    assert!(result.is_err());
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Alice counter-proposes one hour later
+    let counter_proposal: Record = conductors[0]
+        .call(&alice_zome, "propose_reschedule", ProposeRescheduleInput {
+            booking_hash: booking_hash.clone(),
+            start_time: Timestamp::from_micros(start + hour),
+            end_time: Timestamp::from_micros(start + 2 * hour),
+            counter_to: Some(proposal.signed_action.hashed.hash.clone()),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Bob accepts the counter-proposal
+    let updated_booking: Record = conductors[1]
+        .call(&bob_zome, "accept_reschedule_proposal", counter_proposal.signed_action.hashed.hash.clone())
+        .await;
+    let booking = Booking::try_from(updated_booking.clone()).unwrap();
+    assert_eq!(booking.start_time, Timestamp::from_micros(start + hour));
+    assert_eq!(booking.reschedule_proposal_hash, Some(counter_proposal.signed_action.hashed.hash.clone()));
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let latest_booking: Option<Record> = conductors[0]
+        .call(&alice_zome, "get_booking", booking_hash.clone())
+        .await;
+    assert_eq!(latest_booking, Some(updated_booking));
+
+    let proposals: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_reschedule_proposals_for_booking", booking_hash.clone())
+        .await;
+    assert_eq!(proposals, vec![proposal, counter_proposal]);
+}
@@ -120,13 +120,24 @@ async fn create_and_update_resource() {
         .await;
   
     assert_eq!(update_record, get_record.unwrap());
-    
+
+    // Only Alice can update her Resource
+    let input = UpdateResourceInput {
+      original_resource_hash: original_action_hash.clone(),
+      previous_resource_hash: update_record.signed_action.hashed.hash.clone(),
+      updated_resource: sample_2.clone(),
+    };
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "update_resource", input)
+        .await;
+    assert!(result.is_err());
+
     let input = UpdateResourceInput {
       original_resource_hash: original_action_hash.clone(),
       previous_resource_hash: update_record.signed_action.hashed.hash.clone(),
       updated_resource: sample_1.clone(),
     };
-    
+
     // Alice updates the Resource again
     let update_record: Record = conductors[0]
         .call(&alice_zome, "update_resource", input)
@@ -181,3 +192,90 @@ async fn create_and_delete_resource() {
         
     assert!(get_record.is_none());
 }
+
+// Mirror the entries of the file_storage zome
+#[derive(Serialize, Deserialize, Debug)]
+struct FileChunk(SerializedBytes);
+
+#[derive(Serialize, Deserialize, Debug)]
+struct FileMetadata {
+    name: String,
+    last_modified: Timestamp,
+    size: usize,
+    file_type: String,
+    chunks_hashes: Vec<EntryHash>,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resource_files_must_be_stored_files() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (_bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let alice_file_storage_zome = alice.zome("file_storage");
+
+    let mut chunk_hashes: Vec<EntryHash> = Vec::new();
+    let mut file_hashes: Vec<EntryHash> = Vec::new();
+    for (index, content) in [vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]].into_iter().enumerate() {
+        let chunk_hash: EntryHash = conductors[0]
+            .call(
+                &alice_file_storage_zome,
+                "create_file_chunk",
+                FileChunk(SerializedBytes::from(UnsafeBytes::from(content))),
+            )
+            .await;
+        let file_hash: EntryHash = conductors[0]
+            .call(
+                &alice_file_storage_zome,
+                "create_file_metadata",
+                FileMetadata {
+                    name: format!("file-{}", index),
+                    last_modified: Timestamp::from_micros(1686050492353428),
+                    size: 3,
+                    file_type: "image/png".to_string(),
+                    chunks_hashes: vec![chunk_hash.clone()],
+                },
+            )
+            .await;
+        chunk_hashes.push(chunk_hash);
+        file_hashes.push(file_hash);
+    }
+
+    // Resources don't need an image
+    let sample = sample_resource_1(&conductors[0], &alice_zome).await;
+    create_resource(&conductors[0], &alice_zome, sample.clone()).await;
+
+    let mut resource = sample.clone();
+    resource.image_hash = Some(file_hashes[0].clone());
+    resource.gallery = vec![file_hashes[1].clone()];
+    resource.documents = vec![file_hashes[2].clone()];
+    let record: Record = create_resource(&conductors[0], &alice_zome, resource.clone()).await;
+    let entry: Resource = record.entry().to_app_option().unwrap().unwrap();
+    assert_eq!(entry, resource);
+
+    // Files that don't exist are invalid
+    let mut resource = sample.clone();
+    resource.documents = vec![::fixt::fixt!(EntryHash)];
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", resource)
+        .await;
+    assert!(result.is_err());
+
+    // Entries that aren't files are invalid too
+    let mut resource = sample.clone();
+    resource.gallery = vec![chunk_hashes[0].clone()];
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_resource", resource)
+        .await;
+    assert!(result.is_err());
+}
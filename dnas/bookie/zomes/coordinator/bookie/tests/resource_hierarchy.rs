@@ -0,0 +1,146 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::resource_hierarchy::ChildResourceInput;
+
+mod common;
+use common::{create_resource, sample_resource_1};
+
+fn sample_booking(resource_hash: ActionHash, booker: AgentPubKey, start_time: i64) -> Booking {
+    Booking {
+        title: "Meeting".to_string(),
+        start_time: Timestamp::from_micros(start_time),
+        end_time: Timestamp::from_micros(start_time + 3_600_000_000),
+        bookers: vec![booker],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash,
+        resource_version_hash: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bookings_block_the_whole_hierarchy() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let mut hashes = Vec::new();
+    for name in ["Venue", "Room", "Desk"] {
+        let mut resource = sample_resource_1(&conductors[0], &alice_zome).await;
+        resource.name = name.to_string();
+        let record = create_resource(&conductors[0], &alice_zome, resource).await;
+        hashes.push(record.signed_action.hashed.hash);
+    }
+    let (venue_hash, room_hash, desk_hash) = (hashes[0].clone(), hashes[1].clone(), hashes[2].clone());
+
+    let _: () = conductors[0]
+        .call(&alice_zome, "add_child_resource", ChildResourceInput {
+            parent_resource_hash: venue_hash.clone(),
+            child_resource_hash: room_hash.clone(),
+        })
+        .await;
+    let _: () = conductors[0]
+        .call(&alice_zome, "add_child_resource", ChildResourceInput {
+            parent_resource_hash: room_hash.clone(),
+            child_resource_hash: desk_hash.clone(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Only the owner of both resources can nest them
+    let bob_resource = sample_resource_1(&conductors[1], &bob_zome).await;
+    let bob_resource_hash = create_resource(&conductors[1], &bob_zome, bob_resource).await.signed_action.hashed.hash;
+    let result: ConductorApiResult<()> = conductors[1]
+        .call_fallible(&bob_zome, "add_child_resource", ChildResourceInput {
+            parent_resource_hash: venue_hash.clone(),
+            child_resource_hash: bob_resource_hash,
+        })
+        .await;
+    assert!(result.is_err());
+
+    // Cycles are rejected
+    let result: ConductorApiResult<()> = conductors[0]
+        .call_fallible(&alice_zome, "add_child_resource", ChildResourceInput {
+            parent_resource_hash: desk_hash.clone(),
+            child_resource_hash: venue_hash.clone(),
+        })
+        .await;
+    assert!(result.is_err());
+
+    let children: Vec<Record> = conductors[1]
+        .call(&bob_zome, "get_child_resources", venue_hash.clone())
+        .await;
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].signed_action.hashed.hash, room_hash);
+
+    let parent: Option<Record> = conductors[1]
+        .call(&bob_zome, "get_parent_resource", desk_hash.clone())
+        .await;
+    assert_eq!(parent.unwrap().signed_action.hashed.hash, room_hash);
+
+    // Booking the desk blocks the room and the venue
+    let start_time = 4102453334548000;
+    let bob = bobbo.agent_pubkey().clone();
+    let _desk_booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", sample_booking(desk_hash.clone(), bob.clone(), start_time))
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    for resource_hash in [venue_hash.clone(), room_hash.clone()] {
+        let result: ConductorApiResult<Record> = conductors[0]
+            .call_fallible(&alice_zome, "create_booking", sample_booking(resource_hash, bob.clone(), start_time))
+            .await;
+        assert!(result.is_err());
+    }
+
+    // Booking the venue blocks every room and desk
+    let later = start_time + 86_400_000_000;
+    let _venue_booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", sample_booking(venue_hash.clone(), bob.clone(), later))
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    for resource_hash in [room_hash.clone(), desk_hash.clone()] {
+        let result: ConductorApiResult<Record> = conductors[0]
+            .call_fallible(&alice_zome, "create_booking", sample_booking(resource_hash, bob.clone(), later))
+            .await;
+        assert!(result.is_err());
+    }
+
+    // Once removed from the hierarchy, the desk is independent again
+    let _: () = conductors[0]
+        .call(&alice_zome, "remove_child_resource", ChildResourceInput {
+            parent_resource_hash: room_hash.clone(),
+            child_resource_hash: desk_hash.clone(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let _desk_booking: Record = conductors[0]
+        .call(&alice_zome, "create_booking", sample_booking(desk_hash.clone(), bob.clone(), later))
+        .await;
+}
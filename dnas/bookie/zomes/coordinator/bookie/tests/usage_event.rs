@@ -0,0 +1,118 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::usage_event::{BookingUsage, UsageEventInput};
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn late_returns_are_overdue_until_checked_out() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    // Alice books the resource for Bob, the booking already ended an hour ago
+    let now = Timestamp::now();
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Bob's booking".to_string(),
+        start_time: Timestamp::from_micros(now.as_micros() - 2 * 3_600_000_000),
+        end_time: Timestamp::from_micros(now.as_micros() - 3_600_000_000),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    let booking_hash = booking_record.signed_action.hashed.hash.clone();
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let input = UsageEventInput {
+        booking_hash: booking_hash.clone(),
+        odometer: Some(12000),
+        condition_note: None,
+        photo_hash: None,
+    };
+
+    // Bob can't return what he hasn't picked up
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "check_out", input.clone())
+        .await;
+    assert!(result.is_err());
+
+    // Photos must be stored files
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "check_in", UsageEventInput {
+            photo_hash: Some(::fixt::fixt!(EntryHash)),
+            ..input.clone()
+        })
+        .await;
+    assert!(result.is_err());
+
+    let check_in: Record = conductors[1]
+        .call(&bob_zome, "check_in", input)
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let overdue_bookings: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_overdue_bookings", resource_hash.clone())
+        .await;
+    assert_eq!(overdue_bookings, vec![booking_record]);
+
+    // The odometer can't go backwards during the booking
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "check_out", UsageEventInput {
+            booking_hash: booking_hash.clone(),
+            odometer: Some(11000),
+            condition_note: None,
+            photo_hash: None,
+        })
+        .await;
+    assert!(result.is_err());
+
+    let check_out: Record = conductors[1]
+        .call(&bob_zome, "check_out", UsageEventInput {
+            booking_hash: booking_hash.clone(),
+            odometer: Some(12150),
+            condition_note: Some("Low on fuel".to_string()),
+            photo_hash: None,
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let usage: BookingUsage = conductors[0]
+        .call(&alice_zome, "get_booking_usage", booking_hash.clone())
+        .await;
+    assert_eq!(usage.check_in, Some(check_in));
+    assert_eq!(usage.check_out, Some(check_out));
+    assert!(usage.late_return);
+
+    let overdue_bookings: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_overdue_bookings", resource_hash.clone())
+        .await;
+    assert!(overdue_bookings.is_empty());
+}
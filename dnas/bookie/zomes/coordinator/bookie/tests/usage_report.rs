@@ -0,0 +1,98 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+use bookie::usage_report::{GetResourceUsageReportInput, UsageReport};
+
+mod common;
+use common::{create_booking, create_resource, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn shared_bookings_are_split_between_bookers() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    let start = 4102453334548000;
+    let hour = 3_600_000_000;
+
+    // Two hours for Alice and Bob together, and one more for Bob alone
+    create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Trip".to_string(),
+        start_time: Timestamp::from_micros(start),
+        end_time: Timestamp::from_micros(start + 2 * hour),
+        bookers: vec![alice.agent_pubkey().clone(), bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Errand".to_string(),
+        start_time: Timestamp::from_micros(start + 3 * hour),
+        end_time: Timestamp::from_micros(start + 4 * hour),
+        bookers: vec![bobbo.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+    // Two short shared bookings whose halves only add up to whole seconds together
+    for offset in [5, 6] {
+        create_booking(&conductors[0], &alice_zome, Booking {
+            title: "Quick stop".to_string(),
+            start_time: Timestamp::from_micros(start + offset * hour),
+            end_time: Timestamp::from_micros(start + offset * hour + 61_000_000),
+            bookers: vec![alice.agent_pubkey().clone(), bobbo.agent_pubkey().clone()],
+            booking_request_hash: None,
+            reschedule_proposal_hash: None,
+            price: None,
+            resource_hash: resource_hash.clone(),
+            resource_version_hash: None,
+        }).await;
+    }
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let report: UsageReport = conductors[1]
+        .call(&bob_zome, "get_resource_usage_report", GetResourceUsageReportInput {
+            resource_hash: resource_hash.clone(),
+            start_time: Timestamp::from_micros(start),
+            end_time: Timestamp::from_micros(start + 24 * hour),
+        })
+        .await;
+
+    assert_eq!(report.entries.len(), 2);
+    let alice_entry = report.entries.iter().find(|entry| entry.booker.eq(alice.agent_pubkey())).unwrap();
+    let bob_entry = report.entries.iter().find(|entry| entry.booker.eq(bobbo.agent_pubkey())).unwrap();
+    assert_eq!(alice_entry.booked_seconds, 3600 + 61);
+    assert_eq!(bob_entry.booked_seconds, 2 * 3600 + 61);
+    assert_eq!(bob_entry.bookings_count, 4);
+
+    let csv: String = conductors[1]
+        .call(&bob_zome, "render_usage_report_csv", report)
+        .await;
+    assert_eq!(csv.lines().count(), 3);
+    assert!(csv.contains(",4,2.02,"));
+}
@@ -0,0 +1,93 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use hdk::prelude::*;
+use holochain::test_utils::consistency_10s;
+use holochain::{conductor::config::ConductorConfig, sweettest::*};
+
+use bookie_integrity::*;
+
+use bookie::booking_cancellation::CancelBookingInput;
+
+mod common;
+use common::{create_booking, create_resource, sample_booking_request_1, sample_resource_1};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn waitlisted_request_is_promoted_when_the_booking_is_deleted() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/bookie.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("bookie", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+    
+    let alice_zome = alice.zome("bookie");
+    let bob_zome = bobbo.zome("bookie");
+
+    let resource_hash = create_resource(&conductors[0], &alice_zome, sample_resource_1(&conductors[0], &alice_zome).await).await.signed_action.hashed.hash;
+
+    let mut booking_request = sample_booking_request_1(&conductors[0], &alice_zome).await;
+    booking_request.resource_hash = resource_hash.clone();
+    booking_request.end_time = Timestamp::from_micros(booking_request.start_time.as_micros() + 3_600_000_000);
+
+    // Bob can't join the waitlist while the slot is available
+    let result: ConductorApiResult<Record> = conductors[1]
+        .call_fallible(&bob_zome, "join_waitlist", booking_request.clone())
+        .await;
+    assert!(result.is_err());
+
+    // Alice books the slot for herself
+    let booking_record = create_booking(&conductors[0], &alice_zome, Booking {
+        title: "Alice's booking".to_string(),
+        start_time: booking_request.start_time,
+        end_time: booking_request.end_time,
+        bookers: vec![alice.agent_pubkey().clone()],
+        booking_request_hash: None,
+        reschedule_proposal_hash: None,
+        price: None,
+        resource_hash: resource_hash.clone(),
+        resource_version_hash: None,
+    }).await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    // Bob joins the waitlist for the same slot
+    let waitlisted: Record = conductors[1]
+        .call(&bob_zome, "join_waitlist", booking_request.clone())
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let waitlist: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_waitlist_for_resource", resource_hash.clone())
+        .await;
+    assert_eq!(waitlist, vec![waitlisted.clone()]);
+
+    // Alice cancels her booking, which promotes Bob's request
+    let _cancellation: Record = conductors[0]
+        .call(&alice_zome, "cancel_booking", CancelBookingInput {
+            booking_hash: booking_record.signed_action.hashed.hash.clone(),
+            booking_version_hash: None,
+            reason: "Plans changed".to_string(),
+        })
+        .await;
+
+    consistency_10s([&alice, &bobbo]).await;
+
+    let waitlist: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_waitlist_for_resource", resource_hash.clone())
+        .await;
+    assert!(waitlist.is_empty());
+
+    let pending_requests: Vec<Record> = conductors[0]
+        .call(&alice_zome, "get_booking_requests_for_resource", resource_hash.clone())
+        .await;
+    assert_eq!(pending_requests, vec![waitlisted]);
+}
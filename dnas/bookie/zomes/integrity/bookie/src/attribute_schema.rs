@@ -0,0 +1,285 @@
+use hdi::hash_path::path::{Component, Path};
+use hdi::prelude::*;
+use std::collections::BTreeMap;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum AttributeType {
+    String,
+    Number,
+    Bool,
+    Enum { options: Vec<String> },
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AttributeDefinition {
+    pub name: String,
+    pub attribute_type: AttributeType,
+    pub required: bool,
+}
+// Values of enum attributes are stored as strings
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum AttributeValue {
+    String { value: String },
+    Number { value: f64 },
+    Bool { value: bool },
+}
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct AttributeSchema {
+    pub category: String,
+    pub attributes: Vec<AttributeDefinition>,
+}
+pub fn attribute_schema_path(category: &str) -> Path {
+    Path::from(
+        vec![Component::from("attribute_schemas"), Component::from(category.to_string())],
+    )
+}
+// Schemas are defined by the authority of the DNA properties, once per category, so the
+// schema of a category is the one created by the authority
+pub fn validate_create_attribute_schema(
+    action: EntryCreationAction,
+    attribute_schema: AttributeSchema,
+) -> ExternResult<ValidateCallbackResult> {
+    if attribute_schema.category.is_empty() {
+        return Ok(
+            ValidateCallbackResult::Invalid(String::from("The category can't be empty")),
+        );
+    }
+    let authority = crate::booking_policy()?.attribute_schema_authority;
+    if authority.as_ref().ne(&Some(action.author())) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the attribute schema authority can define attribute schemas"),
+            ),
+        );
+    }
+    if let EntryCreationAction::Create(_) = action {
+        if category_defined_in_author_chain(&action, &attribute_schema.category)? {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The category already has an attribute schema"),
+                ),
+            );
+        }
+    }
+    let mut names: Vec<&String> = Vec::new();
+    for definition in attribute_schema.attributes.iter() {
+        if definition.name.is_empty() || names.contains(&&definition.name) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Attribute names must be unique and not empty"),
+                ),
+            );
+        }
+        names.push(&definition.name);
+        if let AttributeType::Enum { options } = &definition.attribute_type {
+            if options.is_empty() {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        format!("The enum attribute {} must have options", definition.name),
+                    ),
+                );
+            }
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+fn category_defined_in_author_chain(
+    action: &EntryCreationAction,
+    category: &String,
+) -> ExternResult<bool> {
+    let schema_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::AttributeSchema,
+    )?;
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    for item in activity {
+        let Action::Create(create) = item.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        if app_entry_def.zome_index != schema_entry_def.zome_index
+            || app_entry_def.entry_index != schema_entry_def.zome_type
+        {
+            continue;
+        }
+        let record = must_get_valid_record(item.action.action_address().clone())?;
+        let Some(attribute_schema): Option<AttributeSchema> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        if attribute_schema.category.eq(category) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+pub fn validate_update_attribute_schema(
+    action: Update,
+    attribute_schema: AttributeSchema,
+    original_action: EntryCreationAction,
+    original_attribute_schema: AttributeSchema,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the author of an attribute schema can update it"),
+            ),
+        );
+    }
+    if attribute_schema.category.ne(&original_attribute_schema.category) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The category of an attribute schema can't change"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_attribute_schema(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_attribute_schema: AttributeSchema,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Attribute Schemas cannot be deleted"),
+        ),
+    )
+}
+fn attribute_matches(definition: &AttributeDefinition, value: &AttributeValue) -> bool {
+    match (&definition.attribute_type, value) {
+        (AttributeType::String, AttributeValue::String { .. }) => true,
+        (AttributeType::Number, AttributeValue::Number { value }) => value.is_finite(),
+        (AttributeType::Bool, AttributeValue::Bool { .. }) => true,
+        (AttributeType::Enum { options }, AttributeValue::String { value }) => {
+            options.contains(value)
+        }
+        _ => false,
+    }
+}
+// Resources with attributes must reference the schema of their category. Once there is a
+// schema authority, the categories of resources are the ones it has defined a schema for
+pub fn validate_resource_attributes(
+    category: &Option<String>,
+    attribute_schema_hash: &Option<ActionHash>,
+    attributes: &BTreeMap<String, AttributeValue>,
+) -> ExternResult<ValidateCallbackResult> {
+    let authority = crate::booking_policy()?.attribute_schema_authority;
+    let Some(attribute_schema_hash) = attribute_schema_hash else {
+        if !attributes.is_empty() {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Resources with attributes must reference an attribute schema"),
+                ),
+            );
+        }
+        if category.is_some() && authority.is_some() {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Resources with a category must reference its attribute schema"),
+                ),
+            );
+        }
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    let record = must_get_valid_record(attribute_schema_hash.clone())?;
+    if authority.as_ref().ne(&Some(record.action().author())) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The attribute schema must be defined by the schema authority"),
+            ),
+        );
+    }
+    let attribute_schema: AttributeSchema = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if category.as_ref().ne(&Some(&attribute_schema.category)) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The attribute schema belongs to another category"),
+            ),
+        );
+    }
+    for (name, value) in attributes.iter() {
+        let Some(definition) = attribute_schema
+            .attributes
+            .iter()
+            .find(|definition| definition.name.eq(name)) else {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!("The attribute {} is not defined in the schema", name),
+                ),
+            );
+        };
+        if !attribute_matches(definition, value) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!("The value of the attribute {} doesn't match its type", name),
+                ),
+            );
+        }
+    }
+    for definition in attribute_schema.attributes.iter() {
+        if definition.required && !attributes.contains_key(&definition.name) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!("The attribute {} is required", definition.name),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_category_to_attribute_schemas(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let attribute_schema: crate::AttributeSchema = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let path_hash = attribute_schema_path(&attribute_schema.category).path_entry_hash()?;
+    if AnyLinkableHash::from(path_hash).ne(&base_address) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Attribute schemas can only be linked from their category"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_category_to_attribute_schemas(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("CategoryToAttributeSchemas links cannot be deleted"),
+        ),
+    )
+}
@@ -1,14 +1,23 @@
 use hdi::prelude::*;
+// Invitees link the bookings they join with the hash of their acceptance. The author of the
+// link also proves which of the bookings they linked to the booker before were cancelled or
+// rescheduled since, as those changes may have been made by other agents
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, Default)]
+pub struct BookerToBookingsTag {
+    pub booking_invitation_response_hash: Option<ActionHash>,
+    pub cancellation_hashes: Vec<ActionHash>,
+    pub booking_version_hashes: Vec<ActionHash>,
+}
 pub fn validate_create_link_booker_to_bookings(
-    _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    action: CreateLink,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
     // Check the entry type for the given action hash
     let action_hash = ActionHash::from(target_address);
-    let record = must_get_valid_record(action_hash)?;
-    let _booking: crate::Booking = record
+    let record = must_get_valid_record(action_hash.clone())?;
+    let booking: crate::Booking = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -17,7 +26,128 @@ pub fn validate_create_link_booker_to_bookings(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
-    // TODO: add the appropriate validation rules
+    let Ok(tag) = BookerToBookingsTag::try_from(
+        SerializedBytes::from(UnsafeBytes::from(tag.0)),
+    ) else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The tag of BookerToBookings links must be a BookerToBookingsTag"),
+            ),
+        );
+    };
+    if !booking
+        .bookers
+        .iter()
+        .any(|booker| AnyLinkableHash::from(booker.clone()).eq(&base_address))
+    {
+        let result = crate::validate_accepted_invitation(
+            &base_address,
+            &action_hash,
+            tag.booking_invitation_response_hash.clone(),
+        )?;
+        if !matches!(result, ValidateCallbackResult::Valid) {
+            return Ok(result);
+        }
+    }
+    let other_bookings = bookings_linked_in_author_chain(&action, &base_address, &tag)?;
+    let result = validate_concurrent_bookings(&action, &booking, &other_bookings)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    crate::validate_quotas(&booking, &other_bookings)
+}
+// Bookings that the author of the action has linked to the booker before, without the
+// cancelled ones and with the times of the latest versions proven in the tag
+pub fn bookings_linked_in_author_chain(
+    action: &CreateLink,
+    booker: &AnyLinkableHash,
+    tag: &BookerToBookingsTag,
+) -> ExternResult<Vec<crate::Booking>> {
+    let booker_to_bookings = ScopedLinkType::try_from(crate::LinkTypes::BookerToBookings)?;
+    let activity = must_get_agent_activity(
+        action.author.clone(),
+        ChainFilter::new(action.prev_action.clone()),
+    )?;
+    let mut booking_hashes: Vec<ActionHash> = Vec::new();
+    for item in activity {
+        if let Action::CreateLink(create_link) = item.action.action() {
+            if create_link.zome_index == booker_to_bookings.zome_index
+                && create_link.link_type == booker_to_bookings.zome_type
+                && create_link.base_address.eq(booker)
+                && create_link.target_address.ne(&action.target_address)
+            {
+                booking_hashes.push(ActionHash::from(create_link.target_address.clone()));
+            }
+        }
+    }
+    let mut cancelled_hashes: Vec<ActionHash> = Vec::new();
+    for cancellation_hash in tag.cancellation_hashes.iter() {
+        let record = must_get_valid_record(cancellation_hash.clone())?;
+        let Some(cancellation): Option<crate::BookingCancellation> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        cancelled_hashes.push(cancellation.booking_hash);
+    }
+    let mut latest_versions: Vec<(ActionHash, crate::Booking)> = Vec::new();
+    for booking_version_hash in tag.booking_version_hashes.iter() {
+        if let Some(version) = crate::booking_version(booking_version_hash)? {
+            latest_versions.push(version);
+        }
+    }
+    let mut bookings = Vec::new();
+    for booking_hash in booking_hashes {
+        if cancelled_hashes.contains(&booking_hash) {
+            continue;
+        }
+        if let Some((_, version)) = latest_versions
+            .iter()
+            .find(|(original_hash, _)| original_hash.eq(&booking_hash))
+        {
+            bookings.push(version.clone());
+            continue;
+        }
+        let record = must_get_valid_record(booking_hash)?;
+        let Some(booking): Option<crate::Booking> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        bookings.push(booking);
+    }
+    Ok(bookings)
+}
+// Like the quotas, only the bookings in the chain of the author can be counted here, the
+// coordinator counts all the upcoming bookings of the booker
+fn validate_concurrent_bookings(
+    action: &CreateLink,
+    booking: &crate::Booking,
+    other_bookings: &[crate::Booking],
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(max_concurrent_bookings) = crate::booking_policy()?.max_concurrent_bookings_per_agent
+    else {
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    if booking.end_time <= action.timestamp {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let upcoming_bookings = other_bookings
+        .iter()
+        .filter(|other| other.end_time > action.timestamp)
+        .count();
+    if upcoming_bookings >= max_concurrent_bookings as usize {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                format!(
+                    "The booker already has the maximum of {} upcoming bookings",
+                    max_concurrent_bookings
+                ),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_booker_to_bookings(
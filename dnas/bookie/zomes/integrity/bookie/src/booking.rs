@@ -8,9 +8,13 @@ pub struct Booking {
     pub bookers: Vec<AgentPubKey>,
     pub booking_request_hash: Option<ActionHash>,
     pub resource_hash: ActionHash,
+    // Version of the resource whose policy and pricing apply, the original one if missing
+    pub resource_version_hash: Option<ActionHash>,
+    pub reschedule_proposal_hash: Option<ActionHash>,
+    pub price: Option<crate::BookingPrice>,
 }
 pub fn validate_create_booking(
-    _action: EntryCreationAction,
+    action: EntryCreationAction,
     booking: Booking,
 ) -> ExternResult<ValidateCallbackResult> {
     if let Some(action_hash) = booking.booking_request_hash.clone() {
@@ -23,29 +27,277 @@ pub fn validate_create_booking(
                 "Dependant action must be accompanied by an entry"
             ))))?;
     }
-    let record = must_get_valid_record(booking.resource_hash.clone())?;
-    let _resource: crate::Resource = record
+    let resource_version_hash = booking
+        .resource_version_hash
+        .clone()
+        .unwrap_or(booking.resource_hash.clone());
+    let Some(record) =
+        crate::must_get_resource_version(&booking.resource_hash, &resource_version_hash)?
+    else {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Bookings must reference a version of the booked resource made by its owner",
+        )));
+    };
+    let resource: crate::Resource = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
         .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
             "Dependant action must be accompanied by an entry"
         ))))?;
+    let resource_owner = record.action().author().clone();
+    let policy = crate::booking_policy_for_resource(&resource)?;
+    let result =
+        crate::validate_booking_time_range(&action, &booking.start_time, &booking.end_time, &policy)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    let result = crate::validate_booking_price(&booking, &resource_version_hash, &resource)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    if crate::out_of_service_in_chain(&action, &booking.resource_hash)? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The resource is out of service",
+        )));
+    }
+    // Holds must be released before their range is booked, so that no agent can keep both
+    if crate::overlaps_holds_in_author_chain(
+        &action,
+        &booking.resource_hash,
+        &booking.start_time,
+        &booking.end_time,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The author still holds that time of the resource",
+        )));
+    }
+    if let Some(capacity) = resource.capacity {
+        if booking.bookers.len() > capacity as usize {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "The bookers exceed the capacity of the resource",
+            )));
+        }
+    }
+    // Bookings made without the approval of the owner are only allowed for instant-bookable
+    // resources. Only the conflicts with the bookings in the chain of the booker can be
+    // checked deterministically here, the coordinator checks all of them before committing
+    if let EntryCreationAction::Create(create) = &action {
+        if booking.reschedule_proposal_hash.is_some() {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "New bookings can't reference a reschedule proposal",
+            )));
+        }
+        if create.author != resource_owner {
+            if policy.requests_require_approval {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "Only the owner of the resource can create bookings for it",
+                )));
+            }
+            if !booking.bookers.contains(&create.author) {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "Instant bookings can only be created by one of their bookers",
+                )));
+            }
+            if overlaps_bookings_in_author_chain(&action, &booking, &policy)? {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "The resource is already booked for that time",
+                )));
+            }
+        }
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+// Whether the booking overlaps other bookings of the same resource that the author has
+// created and not cancelled
+fn overlaps_bookings_in_author_chain(
+    action: &EntryCreationAction,
+    booking: &Booking,
+    policy: &crate::BookingPolicy,
+) -> ExternResult<bool> {
+    let booking_entry_def = ScopedEntryDefIndex::try_from(crate::UnitEntryTypes::Booking)?;
+    let cancellation_entry_def =
+        ScopedEntryDefIndex::try_from(crate::UnitEntryTypes::BookingCancellation)?;
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    let mut booking_hashes: Vec<ActionHash> = Vec::new();
+    let mut cancelled_hashes: Vec<ActionHash> = Vec::new();
+    for item in activity {
+        let Action::Create(create) = item.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        if app_entry_def.zome_index == booking_entry_def.zome_index
+            && app_entry_def.entry_index == booking_entry_def.zome_type
+        {
+            booking_hashes.push(item.action.action_address().clone());
+        } else if app_entry_def.zome_index == cancellation_entry_def.zome_index
+            && app_entry_def.entry_index == cancellation_entry_def.zome_type
+        {
+            let record = must_get_valid_record(item.action.action_address().clone())?;
+            if let Some(cancellation) = record
+                .entry()
+                .to_app_option::<crate::BookingCancellation>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                cancelled_hashes.push(cancellation.booking_hash);
+            }
+        }
+    }
+    let buffer = crate::seconds_to_micros(policy.buffer_seconds.unwrap_or(0))?;
+    let start_with_buffer =
+        Timestamp::from_micros(booking.start_time.as_micros().saturating_sub(buffer));
+    let end_with_buffer =
+        Timestamp::from_micros(booking.end_time.as_micros().saturating_add(buffer));
+    for booking_hash in booking_hashes {
+        if cancelled_hashes.contains(&booking_hash) {
+            continue;
+        }
+        let record = must_get_valid_record(booking_hash)?;
+        let Some(other): Option<Booking> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        if other.resource_hash.eq(&booking.resource_hash)
+            && crate::time_ranges_overlap(
+                &start_with_buffer,
+                &end_with_buffer,
+                &other.start_time,
+                &other.end_time,
+            )
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
 pub fn validate_update_booking(
-    _action: Update,
-    _booking: Booking,
+    action: Update,
+    booking: Booking,
     _original_action: EntryCreationAction,
-    _original_booking: Booking,
+    original_booking: Booking,
 ) -> ExternResult<ValidateCallbackResult> {
+    // Bookings can only change through an accepted reschedule proposal
+    let Some(reschedule_proposal_hash) = booking.reschedule_proposal_hash.clone() else {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Bookings can only be updated by accepting a reschedule proposal",
+        )));
+    };
+    if booking.title != original_booking.title
+        || booking.bookers != original_booking.bookers
+        || booking.booking_request_hash != original_booking.booking_request_hash
+        || booking.resource_hash != original_booking.resource_hash
+        || booking.price.as_ref().map(|price| price.quantity).unwrap_or(1)
+            != original_booking.price.as_ref().map(|price| price.quantity).unwrap_or(1)
+    {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the time range of a booking can be rescheduled",
+        )));
+    }
+    let proposal_record = must_get_valid_record(reschedule_proposal_hash)?;
+    let proposal: crate::RescheduleProposal = proposal_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Dependant action must be accompanied by an entry"
+        ))))?;
+    if proposal.start_time != booking.start_time || proposal.end_time != booking.end_time {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The booking must be rescheduled to the time range of the accepted proposal",
+        )));
+    }
+    // Proposals point to the original booking, which is either the updated action itself
+    // or the booking targeted by the previously accepted proposal
+    let original_booking_hash = match original_booking.reschedule_proposal_hash {
+        Some(previous_proposal_hash) => {
+            let previous_proposal: crate::RescheduleProposal =
+                must_get_valid_record(previous_proposal_hash)?
+                    .entry()
+                    .to_app_option()
+                    .map_err(|e| wasm_error!(e))?
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                        "Dependant action must be accompanied by an entry"
+                    ))))?;
+            previous_proposal.booking_hash
+        }
+        None => action.original_action_address.clone(),
+    };
+    if proposal.booking_hash != original_booking_hash {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The accepted proposal must be for this booking",
+        )));
+    }
+    let owner = must_get_valid_record(booking.resource_hash.clone())?
+        .action()
+        .author()
+        .clone();
+    let proposer = proposal_record.action().author();
+    if action.author.ne(&owner) && !booking.bookers.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the bookers or the owner of the resource can accept a reschedule proposal",
+        )));
+    }
+    if action.author.eq(&owner) == proposer.eq(&owner) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the other side of the booking can accept a reschedule proposal",
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+// Bookings are only deleted when cancelled, right after committing their cancellation
 pub fn validate_delete_booking(
-    _action: Delete,
+    action: Delete,
     _original_action: EntryCreationAction,
-    _original_booking: Booking,
+    original_booking: Booking,
 ) -> ExternResult<ValidateCallbackResult> {
+    let owner = must_get_valid_record(original_booking.resource_hash.clone())?
+        .action()
+        .author()
+        .clone();
+    if action.author.ne(&owner) && !original_booking.bookers.contains(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Only the bookers or the owner of the resource can delete a booking",
+        )));
+    }
+    let cancellation_entry_def =
+        ScopedEntryDefIndex::try_from(crate::UnitEntryTypes::BookingCancellation)?;
+    let previous_record = must_get_valid_record(action.prev_action.clone())?;
+    let is_cancellation = match previous_record.action() {
+        Action::Create(create) => match &create.entry_type {
+            EntryType::App(app_entry_def) => {
+                app_entry_def.zome_index == cancellation_entry_def.zome_index
+                    && app_entry_def.entry_index == cancellation_entry_def.zome_type
+            }
+            _ => false,
+        },
+        _ => false,
+    };
+    if !is_cancellation {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "Bookings can only be deleted right after cancelling them",
+        )));
+    }
+    let booking_cancellation: crate::BookingCancellation = previous_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Dependant action must be accompanied by an entry"
+        ))))?;
+    // The deleted version is the cancelled one, so a cancellation citing a version that was
+    // already superseded doesn't remove the latest version of the booking
+    if booking_cancellation.booking_version_hash.ne(&action.deletes_address) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "The previous action must be the cancellation of this booking version",
+        )));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_create_link_booking_request_to_bookings(
@@ -122,3 +374,23 @@ pub fn validate_delete_link_resource_to_bookings(
         "ResourceToBookings links cannot be deleted",
     )))
 }
+// The original booking of a version, reached through its updates, and the booking at that
+// version. None if the action is not a booking
+pub fn booking_version(
+    booking_version_hash: &ActionHash,
+) -> ExternResult<Option<(ActionHash, Booking)>> {
+    let record = must_get_valid_record(booking_version_hash.clone())?;
+    let Some(booking): Option<Booking> = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))? else {
+        return Ok(None);
+    };
+    let mut action_hash = booking_version_hash.clone();
+    let mut action = record.action().clone();
+    while let Action::Update(update) = action {
+        action_hash = update.original_action_address.clone();
+        action = must_get_valid_record(action_hash.clone())?.action().clone();
+    }
+    Ok(Some((action_hash, booking)))
+}
@@ -0,0 +1,198 @@
+use hdi::prelude::*;
+// All or nothing booking of several resources for the same time range: one booking
+// request is created for each resource, referencing the bundle
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct BookingBundle {
+    pub title: String,
+    pub comment: String,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub resource_hashes: Vec<ActionHash>,
+}
+pub fn validate_create_booking_bundle(
+    _action: EntryCreationAction,
+    booking_bundle: BookingBundle,
+) -> ExternResult<ValidateCallbackResult> {
+    if booking_bundle.resource_hashes.len() < 2 {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Bundles must include at least two resources"),
+            ),
+        );
+    }
+    let mut resource_hashes = booking_bundle.resource_hashes.clone();
+    resource_hashes.sort();
+    resource_hashes.dedup();
+    if resource_hashes.len() != booking_bundle.resource_hashes.len() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Bundles can't include the same resource twice"),
+            ),
+        );
+    }
+    if booking_bundle.end_time <= booking_bundle.start_time {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The end time must be after the start time"),
+            ),
+        );
+    }
+    for resource_hash in booking_bundle.resource_hashes {
+        let record = must_get_valid_record(resource_hash)?;
+        let _resource: crate::Resource = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                ),
+            )?;
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_booking_bundle(
+    _action: Update,
+    _booking_bundle: BookingBundle,
+    _original_action: EntryCreationAction,
+    _original_booking_bundle: BookingBundle,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Booking Bundles cannot be updated")))
+}
+pub fn validate_delete_booking_bundle(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_booking_bundle: BookingBundle,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Booking Bundles cannot be deleted")))
+}
+pub fn validate_bundled_booking_request(
+    action: &EntryCreationAction,
+    booking_request: &crate::BookingRequest,
+    bundle_hash: ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(bundle_hash)?;
+    let booking_bundle: BookingBundle = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if record.action().author().ne(action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the author of a bundle can request its bookings"),
+            ),
+        );
+    }
+    if !booking_bundle.resource_hashes.contains(&booking_request.resource_hash)
+        || booking_bundle.start_time.ne(&booking_request.start_time)
+        || booking_bundle.end_time.ne(&booking_request.end_time)
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The booking request doesn't match its bundle"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_bundle_to_booking_requests(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let booking_request: crate::BookingRequest = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if booking_request.bundle_hash.map(AnyLinkableHash::from).ne(&Some(base_address)) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Booking requests can only be linked from their bundle"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_bundle_to_booking_requests(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("BundleToBookingRequests links cannot be deleted"),
+        ),
+    )
+}
+pub fn validate_create_link_bundle_to_bookings(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let Some(booking_request_hash) = booking.booking_request_hash else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only bookings of bundled requests can be linked from a bundle"),
+            ),
+        );
+    };
+    let record = must_get_valid_record(booking_request_hash)?;
+    let booking_request: crate::BookingRequest = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if booking_request.bundle_hash.map(AnyLinkableHash::from).ne(&Some(base_address)) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Bookings can only be linked from the bundle of their request"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_bundle_to_bookings(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("BundleToBookings links cannot be deleted"),
+        ),
+    )
+}
@@ -0,0 +1,208 @@
+use hdi::prelude::*;
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct BookingCancellation {
+    pub booking_hash: ActionHash,
+    // Version of the booking that was cancelled, reached from the booking through its updates
+    pub booking_version_hash: ActionHash,
+    pub reason: String,
+    pub fee_applies: bool,
+}
+pub fn validate_create_booking_cancellation(
+    action: EntryCreationAction,
+    booking_cancellation: BookingCancellation,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(booking_cancellation.booking_version_hash.clone())?;
+    // A booker could cite a version from before a reschedule, so lateness is judged against
+    // the earliest start of every version between the booking and the cited one
+    let mut earliest_start_time = booking_start_time(&record)?;
+    let mut action_hash = booking_cancellation.booking_version_hash.clone();
+    let mut version_action = record.action().clone();
+    while action_hash.ne(&booking_cancellation.booking_hash) {
+        let Action::Update(update) = version_action else {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The cancelled version must be a version of the booking"),
+                ),
+            );
+        };
+        action_hash = update.original_action_address.clone();
+        let version_record = must_get_valid_record(action_hash.clone())?;
+        earliest_start_time = earliest_start_time.min(booking_start_time(&version_record)?);
+        version_action = version_record.action().clone();
+    }
+    if superseded_in_author_chain(&action, &booking_cancellation.booking_version_hash)? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The cancelled version has already been rescheduled"),
+            ),
+        );
+    }
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let resource_record = must_get_valid_record(booking.resource_hash.clone())?;
+    let is_owner = resource_record.action().author().eq(action.author());
+    if !is_owner && !booking.bookers.contains(action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from(
+                    "Only the bookers or the owner of the resource can cancel a booking",
+                ),
+            ),
+        );
+    }
+    let policy = crate::booking_policy_for_booking(&booking)?;
+    let late = match &policy.cancellation_policy {
+        Some(cancellation_policy) if !is_owner => {
+            let Some(notice) = earliest_start_time
+                .as_micros()
+                .checked_sub(action.timestamp().as_micros()) else {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from("The start time of the booking is out of range"),
+                    ),
+                );
+            };
+            notice < crate::seconds_to_micros(cancellation_policy.notice_seconds)?
+        }
+        _ => false,
+    };
+    if late {
+        if let Some(crate::CancellationPolicy {
+            late_cancellation: crate::LateCancellation::Forbidden,
+            notice_seconds,
+        }) = policy.cancellation_policy
+        {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!(
+                        "Bookings can't be cancelled less than {} seconds before they start",
+                        notice_seconds
+                    ),
+                ),
+            );
+        }
+    }
+    if booking_cancellation.fee_applies != late {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from(
+                    "The cancellation fee must apply exactly to late cancellations",
+                ),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+// Whether the author has updated the version themselves, e.g. by accepting a reschedule
+// proposal. Updates by other agents can't be seen here, but the booking is deleted through
+// the cited version, so citing one of those doesn't remove the latest version
+fn superseded_in_author_chain(
+    action: &EntryCreationAction,
+    booking_version_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    Ok(
+        activity
+            .into_iter()
+            .any(|item| match item.action.action() {
+                Action::Update(update) => update.original_action_address.eq(booking_version_hash),
+                _ => false,
+            }),
+    )
+}
+fn booking_start_time(record: &Record) -> ExternResult<Timestamp> {
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    Ok(booking.start_time)
+}
+pub fn validate_update_booking_cancellation(
+    _action: Update,
+    _booking_cancellation: BookingCancellation,
+    _original_action: EntryCreationAction,
+    _original_booking_cancellation: BookingCancellation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Booking Cancellations cannot be updated"),
+        ),
+    )
+}
+pub fn validate_delete_booking_cancellation(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_booking_cancellation: BookingCancellation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Booking Cancellations cannot be deleted"),
+        ),
+    )
+}
+pub fn validate_create_link_booking_to_cancellations(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(base_address.clone());
+    let record = must_get_valid_record(action_hash)?;
+    let _booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let booking_cancellation: crate::BookingCancellation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(booking_cancellation.booking_hash).ne(&base_address) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The cancellation must belong to the linked booking"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_booking_to_cancellations(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("BookingToCancellations links cannot be deleted"),
+        ),
+    )
+}
@@ -0,0 +1,460 @@
+use hdi::prelude::*;
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct BookingInvitation {
+    pub booking_hash: ActionHash,
+    pub invitee: AgentPubKey,
+}
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct BookingInvitationResponse {
+    pub booking_invitation_hash: ActionHash,
+    pub accepted: bool,
+    // Latest action of the inviter seen by the invitee, whose chain up to it must not have
+    // withdrawn the invitation
+    pub inviter_activity_hash: ActionHash,
+}
+pub fn validate_create_booking_invitation(
+    action: EntryCreationAction,
+    booking_invitation: BookingInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(booking_invitation.booking_hash.clone())?;
+    if !matches!(record.action(), Action::Create(_)) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Invitations must reference the original booking"),
+            ),
+        );
+    }
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if booking.bookers.first().ne(&Some(action.author())) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the primary booker can invite other agents"),
+            ),
+        );
+    }
+    if booking.bookers.contains(&booking_invitation.invitee) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The invitee is already a booker"),
+            ),
+        );
+    }
+    let resource_version_hash = booking
+        .resource_version_hash
+        .clone()
+        .unwrap_or(booking.resource_hash.clone());
+    let resource_record = must_get_valid_record(resource_version_hash)?;
+    let resource: crate::Resource = resource_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if let Some(capacity) = resource.capacity {
+        // All the invitations to the booking are in the chain of its primary booker. Withdrawn
+        // invitations keep their place, since they could have been accepted before
+        let invitations = previous_invitations_count(&action, &booking_invitation.booking_hash)?;
+        if booking.bookers.len() + invitations >= capacity as usize {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The booking is already at the capacity of the resource"),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+fn previous_invitations_count(
+    action: &EntryCreationAction,
+    booking_hash: &ActionHash,
+) -> ExternResult<usize> {
+    let invitation_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::BookingInvitation,
+    )?;
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    let mut count = 0;
+    for item in activity {
+        let Action::Create(create) = item.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        if app_entry_def.zome_index != invitation_entry_def.zome_index
+            || app_entry_def.entry_index != invitation_entry_def.zome_type
+        {
+            continue;
+        }
+        let record = must_get_valid_record(item.action.action_address().clone())?;
+        let Some(invitation): Option<BookingInvitation> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        if invitation.booking_hash.eq(booking_hash) {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+pub fn validate_update_booking_invitation(
+    _action: Update,
+    _booking_invitation: BookingInvitation,
+    _original_action: EntryCreationAction,
+    _original_booking_invitation: BookingInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Booking Invitations cannot be updated"),
+        ),
+    )
+}
+pub fn validate_delete_booking_invitation(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_booking_invitation: BookingInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the inviter can withdraw an invitation"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_booking_invitation_response(
+    action: EntryCreationAction,
+    booking_invitation_response: BookingInvitationResponse,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(
+        booking_invitation_response.booking_invitation_hash.clone(),
+    )?;
+    let booking_invitation: crate::BookingInvitation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if booking_invitation.invitee.ne(action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the invitee can respond to an invitation"),
+            ),
+        );
+    }
+    let inviter_activity = must_get_agent_activity(
+        record.action().author().clone(),
+        ChainFilter::new(booking_invitation_response.inviter_activity_hash.clone())
+            .until(booking_invitation_response.booking_invitation_hash.clone()),
+    )?;
+    if !inviter_activity
+        .iter()
+        .any(|item| {
+            item.action.action_address().eq(&booking_invitation_response.booking_invitation_hash)
+        })
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The activity of the inviter must include the invitation"),
+            ),
+        );
+    }
+    let withdrawn = inviter_activity
+        .iter()
+        .any(|item| match item.action.action() {
+            Action::Delete(delete) => {
+                delete.deletes_address.eq(&booking_invitation_response.booking_invitation_hash)
+            }
+            _ => false,
+        });
+    if withdrawn {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The invitation has been withdrawn"),
+            ),
+        );
+    }
+    let response_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::BookingInvitationResponse,
+    )?;
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    for item in activity {
+        let Action::Create(create) = item.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        if app_entry_def.zome_index != response_entry_def.zome_index
+            || app_entry_def.entry_index != response_entry_def.zome_type
+        {
+            continue;
+        }
+        let previous_record = must_get_valid_record(item.action.action_address().clone())?;
+        let Some(previous_response): Option<BookingInvitationResponse> = previous_record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        if previous_response
+            .booking_invitation_hash
+            .eq(&booking_invitation_response.booking_invitation_hash)
+        {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The invitation has already been answered"),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_booking_invitation_response(
+    _action: Update,
+    _booking_invitation_response: BookingInvitationResponse,
+    _original_action: EntryCreationAction,
+    _original_booking_invitation_response: BookingInvitationResponse,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Booking Invitation Responses cannot be updated"),
+        ),
+    )
+}
+pub fn validate_delete_booking_invitation_response(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_booking_invitation_response: BookingInvitationResponse,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Booking Invitation Responses cannot be deleted"),
+        ),
+    )
+}
+// The tag of the `BookerToBookings` link of an invitee holds the hash of their acceptance,
+// which can only be valid if it was given once and before the invitation was withdrawn
+pub fn validate_accepted_invitation(
+    invitee: &AnyLinkableHash,
+    booking_hash: &ActionHash,
+    response_hash: Option<ActionHash>,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(response_hash) = response_hash else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Bookings can only be linked from their bookers or accepted invitees"),
+            ),
+        );
+    };
+    let record = must_get_valid_record(response_hash)?;
+    let response: crate::BookingInvitationResponse = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let invitation_record = must_get_valid_record(response.booking_invitation_hash.clone())?;
+    let invitation: crate::BookingInvitation = invitation_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if !response.accepted || invitation.booking_hash.ne(booking_hash)
+        || AnyLinkableHash::from(invitation.invitee).ne(invitee)
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The invitation to this booking was not accepted by the agent"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+// Whether the author has accepted an invitation to the booking, or to the original booking of
+// this version, in their own chain, which makes them a participant like its bookers
+pub fn accepted_invitation_in_author_chain(
+    author: &AgentPubKey,
+    prev_action: &ActionHash,
+    booking_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let Some((original_booking_hash, _)) = crate::booking_version(booking_hash)? else {
+        return Ok(false);
+    };
+    let response_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::BookingInvitationResponse,
+    )?;
+    let activity = must_get_agent_activity(
+        author.clone(),
+        ChainFilter::new(prev_action.clone()),
+    )?;
+    for item in activity {
+        let Action::Create(create) = item.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        if app_entry_def.zome_index != response_entry_def.zome_index
+            || app_entry_def.entry_index != response_entry_def.zome_type
+        {
+            continue;
+        }
+        let result = validate_accepted_invitation(
+            &AnyLinkableHash::from(author.clone()),
+            &original_booking_hash,
+            Some(item.action.action_address().clone()),
+        )?;
+        if matches!(result, ValidateCallbackResult::Valid) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+pub fn validate_create_link_booking_to_invitations(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let booking_invitation: crate::BookingInvitation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address.ne(&AnyLinkableHash::from(booking_invitation.booking_hash)) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Invitations can only be linked from their booking"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_booking_to_invitations(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the inviter can delete BookingToInvitations links"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_invitee_to_invitations(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let booking_invitation: crate::BookingInvitation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address.ne(&AnyLinkableHash::from(booking_invitation.invitee)) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Invitations can only be linked from their invitee"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_invitee_to_invitations(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_invitation_to_responses(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let response: crate::BookingInvitationResponse = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address.ne(&AnyLinkableHash::from(response.booking_invitation_hash)) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Responses can only be linked from their invitation"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_invitation_to_responses(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("InvitationToResponses links cannot be deleted"),
+        ),
+    )
+}
@@ -0,0 +1,286 @@
+use hdi::prelude::*;
+use crate::{BookingQuota, CreditLedgerSettings};
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct BookingPolicy {
+    pub max_booking_duration_seconds: Option<u64>,
+    pub min_booking_duration_seconds: Option<u64>,
+    pub slot_granularity_seconds: Option<u64>,
+    pub max_advance_booking_seconds: Option<u64>,
+    pub min_notice_seconds: Option<u64>,
+    pub buffer_seconds: Option<u64>,
+    pub requests_require_approval: bool,
+    pub auto_accept_requests: bool,
+    pub max_concurrent_bookings_per_agent: Option<u32>,
+    pub max_hold_seconds: u64,
+    pub request_response_seconds: Option<u64>,
+    pub cancellation_policy: Option<CancellationPolicy>,
+    pub credit_ledger: Option<CreditLedgerSettings>,
+    pub quotas: Vec<BookingQuota>,
+    pub allocation_rule: Option<AllocationRule>,
+    // Only agent allowed to define the attribute schemas of the categories of resources
+    pub attribute_schema_authority: Option<AgentPubKey>,
+}
+impl Default for BookingPolicy {
+    fn default() -> Self {
+        BookingPolicy {
+            max_booking_duration_seconds: None,
+            min_booking_duration_seconds: None,
+            slot_granularity_seconds: None,
+            max_advance_booking_seconds: None,
+            min_notice_seconds: None,
+            buffer_seconds: None,
+            requests_require_approval: true,
+            auto_accept_requests: false,
+            max_concurrent_bookings_per_agent: None,
+            max_hold_seconds: 15 * 60,
+            request_response_seconds: None,
+            cancellation_policy: None,
+            credit_ledger: None,
+            quotas: vec![],
+            allocation_rule: None,
+            attribute_schema_authority: None,
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ResourceBookingPolicy {
+    pub slot_granularity_seconds: Option<u64>,
+    pub min_booking_duration_seconds: Option<u64>,
+    pub max_booking_duration_seconds: Option<u64>,
+    pub min_notice_seconds: Option<u64>,
+    pub buffer_seconds: Option<u64>,
+    pub requires_approval: Option<bool>,
+    pub auto_accept_requests: Option<bool>,
+    pub request_response_seconds: Option<u64>,
+    pub cancellation_policy: Option<CancellationPolicy>,
+    pub allocation_rule: Option<AllocationRule>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CancellationPolicy {
+    pub notice_seconds: u64,
+    pub late_cancellation: LateCancellation,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum LateCancellation {
+    Forbidden,
+    AllowedWithFee,
+}
+// Roles are listed from the highest to the lowest priority
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PriorityRole {
+    pub name: String,
+    pub agents: Vec<AgentPubKey>,
+}
+// How contested slots are resolved when the owner allocates them
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum AllocationRule {
+    Priority { roles: Vec<PriorityRole> },
+    LeastRecentUsage,
+    Lottery,
+}
+impl BookingPolicy {
+    pub fn with_overrides(self, overrides: &ResourceBookingPolicy) -> BookingPolicy {
+        BookingPolicy {
+            max_booking_duration_seconds: overrides
+                .max_booking_duration_seconds
+                .or(self.max_booking_duration_seconds),
+            min_booking_duration_seconds: overrides
+                .min_booking_duration_seconds
+                .or(self.min_booking_duration_seconds),
+            slot_granularity_seconds: overrides
+                .slot_granularity_seconds
+                .or(self.slot_granularity_seconds),
+            max_advance_booking_seconds: self.max_advance_booking_seconds,
+            min_notice_seconds: overrides.min_notice_seconds.or(self.min_notice_seconds),
+            buffer_seconds: overrides.buffer_seconds.or(self.buffer_seconds),
+            requests_require_approval: overrides
+                .requires_approval
+                .unwrap_or(self.requests_require_approval),
+            auto_accept_requests: overrides
+                .auto_accept_requests
+                .unwrap_or(self.auto_accept_requests),
+            max_concurrent_bookings_per_agent: self.max_concurrent_bookings_per_agent,
+            max_hold_seconds: self.max_hold_seconds,
+            request_response_seconds: overrides
+                .request_response_seconds
+                .or(self.request_response_seconds),
+            cancellation_policy: overrides
+                .cancellation_policy
+                .clone()
+                .or(self.cancellation_policy),
+            credit_ledger: self.credit_ledger,
+            quotas: self.quotas,
+            allocation_rule: overrides.allocation_rule.clone().or(self.allocation_rule),
+            attribute_schema_authority: self.attribute_schema_authority,
+        }
+    }
+}
+pub fn booking_policy() -> ExternResult<BookingPolicy> {
+    let properties = dna_info()?.properties;
+    BookingPolicy::try_from(properties).map_err(|e| wasm_error!(e))
+}
+pub fn booking_policy_for_resource(resource: &crate::Resource) -> ExternResult<BookingPolicy> {
+    let policy = booking_policy()?;
+    match &resource.booking_policy {
+        Some(overrides) => Ok(policy.with_overrides(overrides)),
+        None => Ok(policy),
+    }
+}
+// Policy of the version of the resource the booking was made against
+pub fn booking_policy_for_booking(booking: &crate::Booking) -> ExternResult<BookingPolicy> {
+    let resource_version_hash = booking
+        .resource_version_hash
+        .clone()
+        .unwrap_or(booking.resource_hash.clone());
+    let resource: crate::Resource = must_get_valid_record(resource_version_hash)?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    booking_policy_for_resource(&resource)
+}
+pub fn validate_resource_booking_policy(
+    policy: &ResourceBookingPolicy,
+) -> ExternResult<ValidateCallbackResult> {
+    let durations = [
+        policy.slot_granularity_seconds,
+        policy.min_booking_duration_seconds,
+        policy.max_booking_duration_seconds,
+        policy.min_notice_seconds,
+        policy.buffer_seconds,
+        policy.request_response_seconds,
+        policy.cancellation_policy.as_ref().map(|cancellation| cancellation.notice_seconds),
+    ];
+    if durations
+        .into_iter()
+        .flatten()
+        .any(|seconds| crate::seconds_to_micros(seconds).is_err())
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The durations of the booking policy are out of range"),
+            ),
+        );
+    }
+    if let Some(0) = policy.slot_granularity_seconds {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The slot granularity must be greater than 0"),
+            ),
+        );
+    }
+    if let (Some(min), Some(max))
+        = (policy.min_booking_duration_seconds, policy.max_booking_duration_seconds) {
+        if min > max {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from(
+                        "The minimum booking duration can't be greater than the maximum booking duration",
+                    ),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_booking_time_range(
+    action: &EntryCreationAction,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+    policy: &BookingPolicy,
+) -> ExternResult<ValidateCallbackResult> {
+    if end_time < start_time {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The end time can't be before the start time"),
+            ),
+        );
+    }
+    let Some(duration) = end_time.as_micros().checked_sub(start_time.as_micros()) else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The duration of the booking is out of range"),
+            ),
+        );
+    };
+    if let Some(max_duration) = policy.max_booking_duration_seconds {
+        if duration > crate::seconds_to_micros(max_duration)? {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!(
+                        "Bookings can't be longer than {} seconds", max_duration
+                    ),
+                ),
+            );
+        }
+    }
+    if let Some(min_duration) = policy.min_booking_duration_seconds {
+        if duration < crate::seconds_to_micros(min_duration)? {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!(
+                        "Bookings can't be shorter than {} seconds", min_duration
+                    ),
+                ),
+            );
+        }
+    }
+    if let Some(granularity) = policy.slot_granularity_seconds {
+        let granularity = crate::seconds_to_micros(granularity)?;
+        if granularity > 0
+            && (start_time.as_micros() % granularity != 0 || duration % granularity != 0)
+        {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from(
+                        "Bookings must start and end on the slot boundaries of the resource",
+                    ),
+                ),
+            );
+        }
+    }
+    // Notice and horizon are relative to the moment of creation, so updates of
+    // already accepted bookings are not checked against them
+    if let EntryCreationAction::Create(create) = action {
+        let Some(notice) = start_time.as_micros().checked_sub(create.timestamp.as_micros())
+        else {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The start time of the booking is out of range"),
+                ),
+            );
+        };
+        if let Some(min_notice) = policy.min_notice_seconds {
+            if notice < crate::seconds_to_micros(min_notice)? {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        format!(
+                            "Bookings must be made at least {} seconds in advance",
+                            min_notice
+                        ),
+                    ),
+                );
+            }
+        }
+        if let Some(max_advance) = policy.max_advance_booking_seconds {
+            if notice > crate::seconds_to_micros(max_advance)? {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        format!(
+                            "Bookings can't be made more than {} seconds in advance",
+                            max_advance
+                        ),
+                    ),
+                );
+            }
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
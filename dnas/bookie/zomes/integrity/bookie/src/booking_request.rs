@@ -7,13 +7,14 @@ pub struct BookingRequest {
     pub comment: String,
     pub start_time: Timestamp,
     pub end_time: Timestamp,
+    pub bundle_hash: Option<ActionHash>,
 }
 pub fn validate_create_booking_request(
-    _action: EntryCreationAction,
+    action: EntryCreationAction,
     booking_request: BookingRequest,
 ) -> ExternResult<ValidateCallbackResult> {
     let record = must_get_valid_record(booking_request.resource_hash.clone())?;
-    let _resource: crate::Resource = record
+    let resource: crate::Resource = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -22,7 +23,23 @@ pub fn validate_create_booking_request(
                 WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
             ),
         )?;
-    Ok(ValidateCallbackResult::Valid)
+    if let Some(bundle_hash) = booking_request.bundle_hash.clone() {
+        let result = crate::validate_bundled_booking_request(
+            &action,
+            &booking_request,
+            bundle_hash,
+        )?;
+        if !matches!(result, ValidateCallbackResult::Valid) {
+            return Ok(result);
+        }
+    }
+    let policy = crate::booking_policy_for_resource(&resource)?;
+    crate::validate_booking_time_range(
+        &action,
+        &booking_request.start_time,
+        &booking_request.end_time,
+        &policy,
+    )
 }
 pub fn validate_update_booking_request(
     _action: Update,
@@ -0,0 +1,244 @@
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum ConditionReportSeverity {
+    Minor,
+    NeedsAttention,
+    OutOfService,
+}
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ConditionReport {
+    pub resource_hash: ActionHash,
+    pub booking_hash: Option<ActionHash>,
+    pub severity: ConditionReportSeverity,
+    pub description: String,
+    pub resolved: bool,
+}
+pub fn validate_create_condition_report(
+    action: EntryCreationAction,
+    condition_report: ConditionReport,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(condition_report.resource_hash.clone())?;
+    let _resource: crate::Resource = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let is_owner = record.action().author().eq(action.author());
+    if condition_report.resolved {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Reports can't be created already resolved"),
+            ),
+        );
+    }
+    let mut is_booker = false;
+    if let Some(booking_hash) = condition_report.booking_hash.clone() {
+        let record = must_get_valid_record(booking_hash.clone())?;
+        let booking: crate::Booking = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                ),
+            )?;
+        if booking.resource_hash.ne(&condition_report.resource_hash) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The booking of a report must be for the reported resource"),
+                ),
+            );
+        }
+        is_booker = booking.bookers.contains(action.author())
+            || crate::accepted_invitation_in_author_chain(
+                action.author(),
+                action.prev_action(),
+                &booking_hash,
+            )?;
+    }
+    if let ConditionReportSeverity::OutOfService = condition_report.severity {
+        if !is_owner && !is_booker {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from(
+                        "Only the owner of the resource or the participants of the reported booking can mark it out of service",
+                    ),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+// Whether the author of the action left an out of service report on the resource open in their
+// chain. Resolving is reserved to the owner, so this covers every report the owner has seen
+pub fn out_of_service_in_chain(
+    action: &EntryCreationAction,
+    resource_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let report_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::ConditionReport,
+    )?;
+    let mut activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    activity.sort_by_key(|item| item.action.action().action_seq());
+    let mut open_reports: Vec<ActionHash> = Vec::new();
+    for item in activity {
+        let (entry_type, updated_action_hash) = match item.action.action() {
+            Action::Create(create) => (&create.entry_type, None),
+            Action::Update(update) => {
+                (&update.entry_type, Some(update.original_action_address.clone()))
+            }
+            _ => continue,
+        };
+        let EntryType::App(app_entry_def) = entry_type else {
+            continue;
+        };
+        if app_entry_def.zome_index != report_entry_def.zome_index
+            || app_entry_def.entry_index != report_entry_def.zome_type
+        {
+            continue;
+        }
+        let record = must_get_valid_record(item.action.action_address().clone())?;
+        let Some(condition_report): Option<ConditionReport> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        if condition_report.resource_hash.ne(resource_hash) {
+            continue;
+        }
+        let report_hash = match updated_action_hash {
+            Some(updated_action_hash) => original_condition_report_hash(updated_action_hash)?,
+            None => item.action.action_address().clone(),
+        };
+        open_reports.retain(|open_report_hash| open_report_hash.ne(&report_hash));
+        if let ConditionReportSeverity::OutOfService = condition_report.severity {
+            if !condition_report.resolved {
+                open_reports.push(report_hash);
+            }
+        }
+    }
+    Ok(!open_reports.is_empty())
+}
+fn original_condition_report_hash(action_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record = must_get_valid_record(action_hash.clone())?;
+    match record.action() {
+        Action::Update(update) => {
+            original_condition_report_hash(update.original_action_address.clone())
+        }
+        _ => Ok(action_hash),
+    }
+}
+pub fn validate_update_condition_report(
+    action: Update,
+    condition_report: ConditionReport,
+    original_action: EntryCreationAction,
+    original_condition_report: ConditionReport,
+) -> ExternResult<ValidateCallbackResult> {
+    if condition_report.resource_hash.ne(&original_condition_report.resource_hash)
+        || condition_report.booking_hash.ne(&original_condition_report.booking_hash)
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The resource and booking of a report can't be changed"),
+            ),
+        );
+    }
+    let resource_record = must_get_valid_record(condition_report.resource_hash.clone())?;
+    let is_owner = resource_record.action().author().eq(&action.author);
+    if !is_owner && action.author.ne(original_action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from(
+                    "Only the reporter or the owner of the resource can update a report",
+                ),
+            ),
+        );
+    }
+    if condition_report.severity != original_condition_report.severity && !is_owner {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of the resource can change the severity of a report"),
+            ),
+        );
+    }
+    if condition_report.resolved != original_condition_report.resolved && !is_owner {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of the resource can resolve a report"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_condition_report(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_condition_report: ConditionReport,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Condition Reports cannot be deleted"),
+        ),
+    )
+}
+pub fn validate_create_link_resource_to_condition_reports(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(base_address.clone());
+    let record = must_get_valid_record(action_hash)?;
+    let _resource: crate::Resource = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let condition_report: crate::ConditionReport = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(condition_report.resource_hash).ne(&base_address) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The report must belong to the linked resource"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_resource_to_condition_reports(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("ResourceToConditionReports links cannot be deleted"),
+        ),
+    )
+}
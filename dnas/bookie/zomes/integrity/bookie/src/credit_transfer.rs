@@ -0,0 +1,370 @@
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum CreditUnit {
+    Time,
+    Price,
+}
+// When `external_zome` is set, transfers are delegated to that zome of this DNA instead of
+// the built-in ledger
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CreditLedgerSettings {
+    pub unit: CreditUnit,
+    pub external_zome: Option<String>,
+}
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct CreditTransfer {
+    pub booking_hash: ActionHash,
+    pub from: AgentPubKey,
+    pub to: AgentPubKey,
+    pub amount: u64,
+    pub unit: String,
+    // The charge this transfer gives back, when the owner cancels the booking
+    pub refund_of: Option<ActionHash>,
+}
+// Bookings are paid evenly by all their bookers, and accepted invitees pay the same share as
+// each booker: time is credited in seconds, prices in the minor unit of their currency.
+// Transfers are final, except when the owner cancels the booking and refunds them
+pub fn credit_for_booking(
+    booking: &crate::Booking,
+    unit: &CreditUnit,
+) -> Option<(u64, String)> {
+    let bookers = booking.bookers.len() as u64;
+    if bookers == 0 {
+        return None;
+    }
+    match unit {
+        CreditUnit::Time => {
+            let seconds = booking
+                .end_time
+                .as_micros()
+                .checked_sub(booking.start_time.as_micros())?
+                .max(0) as u64
+                / 1_000_000;
+            Some((seconds / bookers, String::from("seconds")))
+        }
+        CreditUnit::Price => {
+            booking
+                .price
+                .as_ref()
+                .map(|price| (price.amount / bookers, price.currency.clone()))
+        }
+    }
+}
+pub fn validate_create_credit_transfer(
+    action: EntryCreationAction,
+    credit_transfer: CreditTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(settings) = crate::booking_policy()?.credit_ledger else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("This community doesn't use credit transfers"),
+            ),
+        );
+    };
+    let record = must_get_valid_record(credit_transfer.booking_hash.clone())?;
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let resource_record = must_get_valid_record(booking.resource_hash.clone())?;
+    let owner = resource_record.action().author();
+    if let Some(refunded_transfer_hash) = credit_transfer.refund_of.clone() {
+        return validate_credit_refund(&action, &credit_transfer, refunded_transfer_hash, owner);
+    }
+    if credit_transfer.to.ne(owner) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Credit transfers must go to the owner of the resource"),
+            ),
+        );
+    }
+    // Transfers of the bookers are created together with the booking by its author, so that
+    // all of them can be found in the author's source chain. Accepted invitees pay their own
+    // share when they accept, which is proven by the acceptance in their chain
+    if booking.bookers.contains(&credit_transfer.from) {
+        if action.author().ne(record.action().author()) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from(
+                        "Only the author of the booking can create the credit transfers of its bookers",
+                    ),
+                ),
+            );
+        }
+    } else if credit_transfer.from.ne(action.author())
+        || !crate::accepted_invitation_in_author_chain(
+            action.author(),
+            action.prev_action(),
+            &credit_transfer.booking_hash,
+        )?
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from(
+                    "Credit transfers must go from a booker or an accepted invitee of the booking",
+                ),
+            ),
+        );
+    }
+    if booker_already_charged(&action, &credit_transfer)? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The booker has already been charged for this booking"),
+            ),
+        );
+    }
+    if credit_for_booking(&booking, &settings.unit)
+        != Some((credit_transfer.amount, credit_transfer.unit.clone()))
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The credit transfer doesn't match the booking"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+fn validate_credit_refund(
+    action: &EntryCreationAction,
+    refund: &CreditTransfer,
+    refunded_transfer_hash: ActionHash,
+    owner: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author().ne(owner) || refund.from.ne(owner) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of the resource can refund credits"),
+            ),
+        );
+    }
+    let record = must_get_valid_record(refunded_transfer_hash.clone())?;
+    let refunded: CreditTransfer = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if refunded.refund_of.is_some() || refunded.booking_hash.ne(&refund.booking_hash)
+        || refunded.to.ne(owner) || refunded.from.ne(&refund.to)
+        || refunded.amount != refund.amount || refunded.unit.ne(&refund.unit)
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Refunds must give back a charge of the booking in full"),
+            ),
+        );
+    }
+    let cancellation_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::BookingCancellation,
+    )?;
+    let credit_transfer_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::CreditTransfer,
+    )?;
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    let mut cancelled_by_owner = false;
+    for item in activity {
+        let Action::Create(create) = item.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        let is_cancellation = app_entry_def.zome_index == cancellation_entry_def.zome_index
+            && app_entry_def.entry_index == cancellation_entry_def.zome_type;
+        let is_credit_transfer = app_entry_def.zome_index
+            == credit_transfer_entry_def.zome_index
+            && app_entry_def.entry_index == credit_transfer_entry_def.zome_type;
+        if !is_cancellation && !is_credit_transfer {
+            continue;
+        }
+        let record = must_get_valid_record(item.action.action_address().clone())?;
+        if is_cancellation {
+            if let Some(cancellation) = record
+                .entry()
+                .to_app_option::<crate::BookingCancellation>()
+                .map_err(|e| wasm_error!(e))?
+            {
+                cancelled_by_owner |= cancellation.booking_hash.eq(&refund.booking_hash);
+            }
+        } else if let Some(previous_transfer) = record
+            .entry()
+            .to_app_option::<CreditTransfer>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            if previous_transfer.refund_of.eq(&Some(refunded_transfer_hash.clone())) {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from("The charge has already been refunded"),
+                    ),
+                );
+            }
+        }
+    }
+    // Cancellations by the bookers keep their charges
+    if !cancelled_by_owner {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Credits are only refunded when the owner cancels the booking"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+fn booker_already_charged(
+    action: &EntryCreationAction,
+    credit_transfer: &CreditTransfer,
+) -> ExternResult<bool> {
+    let credit_transfer_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::CreditTransfer,
+    )?;
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    for item in activity {
+        let Action::Create(create) = item.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        if app_entry_def.zome_index != credit_transfer_entry_def.zome_index
+            || app_entry_def.entry_index != credit_transfer_entry_def.zome_type
+        {
+            continue;
+        }
+        let record = must_get_valid_record(item.action.action_address().clone())?;
+        let Some(previous_transfer): Option<CreditTransfer> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        if previous_transfer.refund_of.is_none()
+            && previous_transfer.booking_hash.eq(&credit_transfer.booking_hash)
+            && previous_transfer.from.eq(&credit_transfer.from)
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+pub fn validate_update_credit_transfer(
+    _action: Update,
+    _credit_transfer: CreditTransfer,
+    _original_action: EntryCreationAction,
+    _original_credit_transfer: CreditTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Credit Transfers cannot be updated")))
+}
+pub fn validate_delete_credit_transfer(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_credit_transfer: CreditTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Credit Transfers cannot be deleted")))
+}
+// Targets can be transfers of an external ledger zome, so only the booking is checked here
+pub fn validate_create_link_booking_to_credit_transfers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    _target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let booking_hash = ActionHash::from(base_address);
+    let record = must_get_valid_record(booking_hash.clone())?;
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let resource_record = must_get_valid_record(booking.resource_hash.clone())?;
+    if action.author.ne(resource_record.action().author())
+        && !booking.bookers.contains(&action.author)
+        && !crate::accepted_invitation_in_author_chain(
+            &action.author,
+            &action.prev_action,
+            &booking_hash,
+        )?
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from(
+                    "Only the participants or the owner of the resource can link credit transfers",
+                ),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_booking_to_credit_transfers(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("BookingToCreditTransfers links cannot be deleted"),
+        ),
+    )
+}
+pub fn validate_create_link_agent_to_credit_transfers(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let credit_transfer: crate::CreditTransfer = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address.ne(&AnyLinkableHash::from(credit_transfer.from))
+        && base_address.ne(&AnyLinkableHash::from(credit_transfer.to))
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Credit transfers can only be linked from their participants"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_credit_transfers(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("AgentToCreditTransfers links cannot be deleted"),
+        ),
+    )
+}
@@ -0,0 +1,195 @@
+use hdi::prelude::*;
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Hold {
+    pub resource_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub expires_at: Timestamp,
+}
+pub fn validate_create_hold(
+    action: EntryCreationAction,
+    hold: Hold,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(hold.resource_hash.clone())?;
+    let resource: crate::Resource = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if hold.end_time <= hold.start_time {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The end time of a hold must be after its start time"),
+            ),
+        );
+    }
+    // Holds reserve a time range to be booked, so it must be bookable under the policy
+    let policy = crate::booking_policy_for_resource(&resource)?;
+    let result = crate::validate_booking_time_range(
+        &action,
+        &hold.start_time,
+        &hold.end_time,
+        &policy,
+    )?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    let Some(remaining) = hold.expires_at.as_micros().checked_sub(action.timestamp().as_micros())
+    else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The expiry of the hold is out of range"),
+            ),
+        );
+    };
+    if remaining <= 0 {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Holds must expire after they are created"),
+            ),
+        );
+    }
+    let max_hold_seconds = policy.max_hold_seconds;
+    if remaining > crate::seconds_to_micros(max_hold_seconds)? {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                format!("Holds can't last longer than {} seconds", max_hold_seconds),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_hold(
+    _action: Update,
+    _hold: Hold,
+    _original_action: EntryCreationAction,
+    _original_hold: Hold,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Holds cannot be updated")))
+}
+pub fn validate_delete_hold(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_hold: Hold,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the author of a hold can release it"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_resource_to_holds(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let resource_hash = ActionHash::from(base_address);
+    let record = must_get_valid_record(resource_hash.clone())?;
+    let _resource: crate::Resource = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let hold: crate::Hold = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if hold.resource_hash.ne(&resource_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Holds can only be linked from the resource they hold"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_resource_to_holds(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the author of a hold can release it"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+// Whether the author has an unexpired hold that overlaps the given time range of the
+// resource and hasn't been released. Integrity can only see the author's own chain, the
+// holds of other agents are checked by the coordinator
+pub fn overlaps_holds_in_author_chain(
+    action: &EntryCreationAction,
+    resource_hash: &ActionHash,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+) -> ExternResult<bool> {
+    let hold_entry_def = ScopedEntryDefIndex::try_from(crate::UnitEntryTypes::Hold)?;
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    let mut hold_hashes: Vec<ActionHash> = Vec::new();
+    let mut released_hashes: Vec<ActionHash> = Vec::new();
+    for item in activity {
+        match item.action.action() {
+            Action::Create(create) => {
+                let EntryType::App(app_entry_def) = &create.entry_type else {
+                    continue;
+                };
+                if app_entry_def.zome_index == hold_entry_def.zome_index
+                    && app_entry_def.entry_index == hold_entry_def.zome_type
+                {
+                    hold_hashes.push(item.action.action_address().clone());
+                }
+            }
+            Action::Delete(delete) => {
+                released_hashes.push(delete.deletes_address.clone());
+            }
+            _ => {}
+        }
+    }
+    for hold_hash in hold_hashes {
+        if released_hashes.contains(&hold_hash) {
+            continue;
+        }
+        let record = must_get_valid_record(hold_hash)?;
+        let Some(hold): Option<Hold> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        if hold.resource_hash.eq(resource_hash)
+            && hold.expires_at > *action.timestamp()
+            && crate::time_ranges_overlap(start_time, end_time, &hold.start_time, &hold.end_time)
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
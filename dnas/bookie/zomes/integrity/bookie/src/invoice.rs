@@ -0,0 +1,255 @@
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum InvoiceStatus {
+    Issued,
+    Paid,
+    Void,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InvoiceLineItem {
+    pub booking_hash: ActionHash,
+    pub description: String,
+    pub amount: u64,
+    // Acceptance of the invitation to the booking, when the customer is not one of its bookers
+    pub invitation_response_hash: Option<ActionHash>,
+}
+// Tax rates are expressed in basis points (2100 is 21%)
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Invoice {
+    pub number: u32,
+    pub customer: AgentPubKey,
+    pub currency: String,
+    pub line_items: Vec<InvoiceLineItem>,
+    pub tax_rate_basis_points: u32,
+    pub subtotal: u64,
+    pub tax: u64,
+    pub total: u64,
+    pub status: InvoiceStatus,
+}
+// Subtotal, tax and total of the line items, or None if any of them overflows
+pub fn invoice_totals(
+    line_items: &[InvoiceLineItem],
+    tax_rate_basis_points: u32,
+) -> Option<(u64, u64, u64)> {
+    let subtotal = line_items
+        .iter()
+        .try_fold(0u64, |subtotal, item| subtotal.checked_add(item.amount))?;
+    let tax = subtotal.checked_mul(tax_rate_basis_points as u64)? / 10_000;
+    Some((subtotal, tax, subtotal.checked_add(tax)?))
+}
+pub fn validate_create_invoice(
+    action: EntryCreationAction,
+    invoice: Invoice,
+) -> ExternResult<ValidateCallbackResult> {
+    if invoice.line_items.is_empty() {
+        return Ok(
+            ValidateCallbackResult::Invalid(String::from("Invoices must have at least one line item")),
+        );
+    }
+    for line_item in invoice.line_items.iter() {
+        let record = must_get_valid_record(line_item.booking_hash.clone())?;
+        let booking: crate::Booking = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                ),
+            )?;
+        let resource_record = must_get_valid_record(booking.resource_hash.clone())?;
+        if resource_record.action().author().ne(action.author()) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Only the owner of the resource can invoice its bookings"),
+                ),
+            );
+        }
+        if !booking.bookers.contains(&invoice.customer) {
+            let Some((original_booking_hash, _)) = crate::booking_version(
+                &line_item.booking_hash,
+            )? else {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from("Line items must reference a booking"),
+                    ),
+                );
+            };
+            let result = crate::validate_accepted_invitation(
+                &AnyLinkableHash::from(invoice.customer.clone()),
+                &original_booking_hash,
+                line_item.invitation_response_hash.clone(),
+            )?;
+            if !matches!(result, ValidateCallbackResult::Valid) {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from(
+                            "The customer must be a participant of every invoiced booking",
+                        ),
+                    ),
+                );
+            }
+        }
+        let Some(price) = booking.price else {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Only bookings with a price can be invoiced"),
+                ),
+            );
+        };
+        if price.amount != line_item.amount || price.currency.ne(&invoice.currency) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Line items must match the price of their booking"),
+                ),
+            );
+        }
+    }
+    let Some(totals) = invoice_totals(&invoice.line_items, invoice.tax_rate_basis_points) else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The totals of the invoice overflow"),
+            ),
+        );
+    };
+    if totals != (invoice.subtotal, invoice.tax, invoice.total) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The totals of the invoice don't add up"),
+            ),
+        );
+    }
+    if let EntryCreationAction::Create(create) = action {
+        if invoice.status != InvoiceStatus::Issued {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Invoices must be created with the Issued status"),
+                ),
+            );
+        }
+        let previous_invoices = count_previous_invoices(&create)?;
+        if invoice.number != previous_invoices + 1 {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    format!("The next invoice number of this agent is {}", previous_invoices + 1),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+// Invoice numbers are sequential per owner: the number must be the count of the
+// invoices previously created in the owner's source chain, plus one
+fn count_previous_invoices(create: &Create) -> ExternResult<u32> {
+    let invoice_entry_def = ScopedEntryDefIndex::try_from(crate::UnitEntryTypes::Invoice)?;
+    let activity = must_get_agent_activity(
+        create.author.clone(),
+        ChainFilter::new(create.prev_action.clone()),
+    )?;
+    let count = activity
+        .into_iter()
+        .filter(|activity| match activity.action.action() {
+            Action::Create(create) => match &create.entry_type {
+                EntryType::App(app_entry_def) => {
+                    app_entry_def.zome_index == invoice_entry_def.zome_index
+                        && app_entry_def.entry_index == invoice_entry_def.zome_type
+                }
+                _ => false,
+            },
+            _ => false,
+        })
+        .count();
+    Ok(count as u32)
+}
+pub fn validate_update_invoice(
+    action: Update,
+    invoice: Invoice,
+    original_action: EntryCreationAction,
+    original_invoice: Invoice,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the issuer of an invoice can update it"),
+            ),
+        );
+    }
+    let mut unchanged_invoice = invoice.clone();
+    unchanged_invoice.status = original_invoice.status.clone();
+    if unchanged_invoice.ne(&original_invoice) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the payment status of an invoice can be updated"),
+            ),
+        );
+    }
+    // Updates always point to the previous version, so transitions are checked against it
+    let previous_record = must_get_valid_record(action.original_action_address.clone())?;
+    let previous_invoice: Invoice = previous_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if previous_invoice.status != InvoiceStatus::Issued {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Paid or void invoices can't be updated"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_invoice(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_invoice: Invoice,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Invoices cannot be deleted")))
+}
+pub fn validate_create_link_agent_to_invoices(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let invoice: crate::Invoice = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address.ne(&AnyLinkableHash::from(record.action().author().clone()))
+        && base_address.ne(&AnyLinkableHash::from(invoice.customer))
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Invoices can only be linked from their issuer or customer"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_invoices(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("AgentToInvoices links cannot be deleted"),
+        ),
+    )
+}
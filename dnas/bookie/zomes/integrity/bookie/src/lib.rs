@@ -1,11 +1,49 @@
+pub mod attribute_schema;
+pub use attribute_schema::*;
 pub mod booker_to_bookings;
 pub use booker_to_bookings::*;
 pub mod booking;
 pub use booking::*;
+pub mod booking_bundle;
+pub use booking_bundle::*;
+pub mod booking_cancellation;
+pub use booking_cancellation::*;
+pub mod booking_invitation;
+pub use booking_invitation::*;
+pub mod booking_policy;
+pub use booking_policy::*;
 pub mod booking_request;
 pub use booking_request::*;
+pub mod condition_report;
+pub use condition_report::*;
+pub mod credit_transfer;
+pub use credit_transfer::*;
+pub mod hold;
+pub use hold::*;
+pub mod invoice;
+pub use invoice::*;
+pub mod location;
+pub use location::*;
+pub mod maintenance;
+pub use maintenance::*;
+pub mod payment_acknowledgement;
+pub use payment_acknowledgement::*;
+pub mod pricing;
+pub use pricing::*;
+pub mod quota;
+pub use quota::*;
+pub mod reschedule_proposal;
+pub use reschedule_proposal::*;
 pub mod resource;
 pub use resource::*;
+pub mod resource_hierarchy;
+pub use resource_hierarchy::*;
+pub mod time;
+pub use time::*;
+pub mod usage_event;
+pub use usage_event::*;
+pub mod waitlist;
+pub use waitlist::*;
 use hdi::prelude::*;
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -15,6 +53,20 @@ pub enum EntryTypes {
     Resource(Resource),
     BookingRequest(BookingRequest),
     Booking(Booking),
+    Hold(Hold),
+    BookingCancellation(BookingCancellation),
+    RescheduleProposal(RescheduleProposal),
+    UsageEvent(UsageEvent),
+    ConditionReport(ConditionReport),
+    MaintenanceTask(MaintenanceTask),
+    MaintenanceWindow(MaintenanceWindow),
+    CreditTransfer(CreditTransfer),
+    Invoice(Invoice),
+    PaymentAcknowledgement(PaymentAcknowledgement),
+    BookingInvitation(BookingInvitation),
+    BookingInvitationResponse(BookingInvitationResponse),
+    BookingBundle(BookingBundle),
+    AttributeSchema(AttributeSchema),
 }
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
@@ -27,6 +79,27 @@ pub enum LinkTypes {
     MyResources,
     MyBookingRequests,
     BookerToBookings,
+    ResourceToWaitlist,
+    ResourceToHolds,
+    BookingToCancellations,
+    BookingToRescheduleProposals,
+    BookingToUsageEvents,
+    ResourceToConditionReports,
+    ResourceToMaintenanceTasks,
+    ResourceToMaintenanceWindows,
+    BookingToCreditTransfers,
+    AgentToCreditTransfers,
+    AgentToInvoices,
+    BookingToPaymentAcknowledgements,
+    BookingToInvitations,
+    InviteeToInvitations,
+    InvitationToResponses,
+    BundleToBookingRequests,
+    BundleToBookings,
+    ParentToChildResources,
+    ChildToParentResources,
+    LocationToResources,
+    CategoryToAttributeSchemas,
 }
 #[hdk_extern]
 pub fn genesis_self_check(
@@ -65,6 +138,90 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 booking,
                             )
                         }
+                        EntryTypes::Hold(hold) => {
+                            validate_create_hold(
+                                EntryCreationAction::Create(action),
+                                hold,
+                            )
+                        }
+                        EntryTypes::BookingCancellation(booking_cancellation) => {
+                            validate_create_booking_cancellation(
+                                EntryCreationAction::Create(action),
+                                booking_cancellation,
+                            )
+                        }
+                        EntryTypes::RescheduleProposal(reschedule_proposal) => {
+                            validate_create_reschedule_proposal(
+                                EntryCreationAction::Create(action),
+                                reschedule_proposal,
+                            )
+                        }
+                        EntryTypes::UsageEvent(usage_event) => {
+                            validate_create_usage_event(
+                                EntryCreationAction::Create(action),
+                                usage_event,
+                            )
+                        }
+                        EntryTypes::ConditionReport(condition_report) => {
+                            validate_create_condition_report(
+                                EntryCreationAction::Create(action),
+                                condition_report,
+                            )
+                        }
+                        EntryTypes::MaintenanceTask(maintenance_task) => {
+                            validate_create_maintenance_task(
+                                EntryCreationAction::Create(action),
+                                maintenance_task,
+                            )
+                        }
+                        EntryTypes::MaintenanceWindow(maintenance_window) => {
+                            validate_create_maintenance_window(
+                                EntryCreationAction::Create(action),
+                                maintenance_window,
+                            )
+                        }
+                        EntryTypes::CreditTransfer(credit_transfer) => {
+                            validate_create_credit_transfer(
+                                EntryCreationAction::Create(action),
+                                credit_transfer,
+                            )
+                        }
+                        EntryTypes::Invoice(invoice) => {
+                            validate_create_invoice(
+                                EntryCreationAction::Create(action),
+                                invoice,
+                            )
+                        }
+                        EntryTypes::PaymentAcknowledgement(payment_acknowledgement) => {
+                            validate_create_payment_acknowledgement(
+                                EntryCreationAction::Create(action),
+                                payment_acknowledgement,
+                            )
+                        }
+                        EntryTypes::BookingInvitation(booking_invitation) => {
+                            validate_create_booking_invitation(
+                                EntryCreationAction::Create(action),
+                                booking_invitation,
+                            )
+                        }
+                        EntryTypes::BookingInvitationResponse(booking_invitation_response) => {
+                            validate_create_booking_invitation_response(
+                                EntryCreationAction::Create(action),
+                                booking_invitation_response,
+                            )
+                        }
+                        EntryTypes::BookingBundle(booking_bundle) => {
+                            validate_create_booking_bundle(
+                                EntryCreationAction::Create(action),
+                                booking_bundle,
+                            )
+                        }
+                        EntryTypes::AttributeSchema(attribute_schema) => {
+                            validate_create_attribute_schema(
+                                EntryCreationAction::Create(action),
+                                attribute_schema,
+                            )
+                        }
                     }
                 }
                 OpEntry::UpdateEntry { app_entry, action, .. } => {
@@ -87,6 +244,90 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 booking,
                             )
                         }
+                        EntryTypes::Hold(hold) => {
+                            validate_create_hold(
+                                EntryCreationAction::Update(action),
+                                hold,
+                            )
+                        }
+                        EntryTypes::BookingCancellation(booking_cancellation) => {
+                            validate_create_booking_cancellation(
+                                EntryCreationAction::Update(action),
+                                booking_cancellation,
+                            )
+                        }
+                        EntryTypes::RescheduleProposal(reschedule_proposal) => {
+                            validate_create_reschedule_proposal(
+                                EntryCreationAction::Update(action),
+                                reschedule_proposal,
+                            )
+                        }
+                        EntryTypes::UsageEvent(usage_event) => {
+                            validate_create_usage_event(
+                                EntryCreationAction::Update(action),
+                                usage_event,
+                            )
+                        }
+                        EntryTypes::ConditionReport(condition_report) => {
+                            validate_create_condition_report(
+                                EntryCreationAction::Update(action),
+                                condition_report,
+                            )
+                        }
+                        EntryTypes::MaintenanceTask(maintenance_task) => {
+                            validate_create_maintenance_task(
+                                EntryCreationAction::Update(action),
+                                maintenance_task,
+                            )
+                        }
+                        EntryTypes::MaintenanceWindow(maintenance_window) => {
+                            validate_create_maintenance_window(
+                                EntryCreationAction::Update(action),
+                                maintenance_window,
+                            )
+                        }
+                        EntryTypes::CreditTransfer(credit_transfer) => {
+                            validate_create_credit_transfer(
+                                EntryCreationAction::Update(action),
+                                credit_transfer,
+                            )
+                        }
+                        EntryTypes::Invoice(invoice) => {
+                            validate_create_invoice(
+                                EntryCreationAction::Update(action),
+                                invoice,
+                            )
+                        }
+                        EntryTypes::PaymentAcknowledgement(payment_acknowledgement) => {
+                            validate_create_payment_acknowledgement(
+                                EntryCreationAction::Update(action),
+                                payment_acknowledgement,
+                            )
+                        }
+                        EntryTypes::BookingInvitation(booking_invitation) => {
+                            validate_create_booking_invitation(
+                                EntryCreationAction::Update(action),
+                                booking_invitation,
+                            )
+                        }
+                        EntryTypes::BookingInvitationResponse(booking_invitation_response) => {
+                            validate_create_booking_invitation_response(
+                                EntryCreationAction::Update(action),
+                                booking_invitation_response,
+                            )
+                        }
+                        EntryTypes::BookingBundle(booking_bundle) => {
+                            validate_create_booking_bundle(
+                                EntryCreationAction::Update(action),
+                                booking_bundle,
+                            )
+                        }
+                        EntryTypes::AttributeSchema(attribute_schema) => {
+                            validate_create_attribute_schema(
+                                EntryCreationAction::Update(action),
+                                attribute_schema,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
@@ -134,6 +375,160 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_resource,
                             )
                         }
+                        (
+                            EntryTypes::Hold(hold),
+                            EntryTypes::Hold(original_hold),
+                        ) => {
+                            validate_update_hold(
+                                action,
+                                hold,
+                                original_action,
+                                original_hold,
+                            )
+                        }
+                        (
+                            EntryTypes::BookingCancellation(booking_cancellation),
+                            EntryTypes::BookingCancellation(original_booking_cancellation),
+                        ) => {
+                            validate_update_booking_cancellation(
+                                action,
+                                booking_cancellation,
+                                original_action,
+                                original_booking_cancellation,
+                            )
+                        }
+                        (
+                            EntryTypes::RescheduleProposal(reschedule_proposal),
+                            EntryTypes::RescheduleProposal(original_reschedule_proposal),
+                        ) => {
+                            validate_update_reschedule_proposal(
+                                action,
+                                reschedule_proposal,
+                                original_action,
+                                original_reschedule_proposal,
+                            )
+                        }
+                        (
+                            EntryTypes::UsageEvent(usage_event),
+                            EntryTypes::UsageEvent(original_usage_event),
+                        ) => {
+                            validate_update_usage_event(
+                                action,
+                                usage_event,
+                                original_action,
+                                original_usage_event,
+                            )
+                        }
+                        (
+                            EntryTypes::ConditionReport(condition_report),
+                            EntryTypes::ConditionReport(original_condition_report),
+                        ) => {
+                            validate_update_condition_report(
+                                action,
+                                condition_report,
+                                original_action,
+                                original_condition_report,
+                            )
+                        }
+                        (
+                            EntryTypes::MaintenanceTask(maintenance_task),
+                            EntryTypes::MaintenanceTask(original_maintenance_task),
+                        ) => {
+                            validate_update_maintenance_task(
+                                action,
+                                maintenance_task,
+                                original_action,
+                                original_maintenance_task,
+                            )
+                        }
+                        (
+                            EntryTypes::MaintenanceWindow(maintenance_window),
+                            EntryTypes::MaintenanceWindow(original_maintenance_window),
+                        ) => {
+                            validate_update_maintenance_window(
+                                action,
+                                maintenance_window,
+                                original_action,
+                                original_maintenance_window,
+                            )
+                        }
+                        (
+                            EntryTypes::CreditTransfer(credit_transfer),
+                            EntryTypes::CreditTransfer(original_credit_transfer),
+                        ) => {
+                            validate_update_credit_transfer(
+                                action,
+                                credit_transfer,
+                                original_action,
+                                original_credit_transfer,
+                            )
+                        }
+                        (
+                            EntryTypes::Invoice(invoice),
+                            EntryTypes::Invoice(original_invoice),
+                        ) => {
+                            validate_update_invoice(
+                                action,
+                                invoice,
+                                original_action,
+                                original_invoice,
+                            )
+                        }
+                        (
+                            EntryTypes::PaymentAcknowledgement(payment_acknowledgement),
+                            EntryTypes::PaymentAcknowledgement(original_payment_acknowledgement),
+                        ) => {
+                            validate_update_payment_acknowledgement(
+                                action,
+                                payment_acknowledgement,
+                                original_action,
+                                original_payment_acknowledgement,
+                            )
+                        }
+                        (
+                            EntryTypes::BookingInvitation(booking_invitation),
+                            EntryTypes::BookingInvitation(original_booking_invitation),
+                        ) => {
+                            validate_update_booking_invitation(
+                                action,
+                                booking_invitation,
+                                original_action,
+                                original_booking_invitation,
+                            )
+                        }
+                        (
+                            EntryTypes::BookingInvitationResponse(booking_invitation_response),
+                            EntryTypes::BookingInvitationResponse(original_booking_invitation_response),
+                        ) => {
+                            validate_update_booking_invitation_response(
+                                action,
+                                booking_invitation_response,
+                                original_action,
+                                original_booking_invitation_response,
+                            )
+                        }
+                        (
+                            EntryTypes::BookingBundle(booking_bundle),
+                            EntryTypes::BookingBundle(original_booking_bundle),
+                        ) => {
+                            validate_update_booking_bundle(
+                                action,
+                                booking_bundle,
+                                original_action,
+                                original_booking_bundle,
+                            )
+                        }
+                        (
+                            EntryTypes::AttributeSchema(attribute_schema),
+                            EntryTypes::AttributeSchema(original_attribute_schema),
+                        ) => {
+                            validate_update_attribute_schema(
+                                action,
+                                attribute_schema,
+                                original_action,
+                                original_attribute_schema,
+                            )
+                        }
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
@@ -164,6 +559,48 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         EntryTypes::Booking(booking) => {
                             validate_delete_booking(action, original_action, booking)
                         }
+                        EntryTypes::Hold(hold) => {
+                            validate_delete_hold(action, original_action, hold)
+                        }
+                        EntryTypes::BookingCancellation(booking_cancellation) => {
+                            validate_delete_booking_cancellation(action, original_action, booking_cancellation)
+                        }
+                        EntryTypes::RescheduleProposal(reschedule_proposal) => {
+                            validate_delete_reschedule_proposal(action, original_action, reschedule_proposal)
+                        }
+                        EntryTypes::UsageEvent(usage_event) => {
+                            validate_delete_usage_event(action, original_action, usage_event)
+                        }
+                        EntryTypes::ConditionReport(condition_report) => {
+                            validate_delete_condition_report(action, original_action, condition_report)
+                        }
+                        EntryTypes::MaintenanceTask(maintenance_task) => {
+                            validate_delete_maintenance_task(action, original_action, maintenance_task)
+                        }
+                        EntryTypes::MaintenanceWindow(maintenance_window) => {
+                            validate_delete_maintenance_window(action, original_action, maintenance_window)
+                        }
+                        EntryTypes::CreditTransfer(credit_transfer) => {
+                            validate_delete_credit_transfer(action, original_action, credit_transfer)
+                        }
+                        EntryTypes::Invoice(invoice) => {
+                            validate_delete_invoice(action, original_action, invoice)
+                        }
+                        EntryTypes::PaymentAcknowledgement(payment_acknowledgement) => {
+                            validate_delete_payment_acknowledgement(action, original_action, payment_acknowledgement)
+                        }
+                        EntryTypes::BookingInvitation(booking_invitation) => {
+                            validate_delete_booking_invitation(action, original_action, booking_invitation)
+                        }
+                        EntryTypes::BookingInvitationResponse(booking_invitation_response) => {
+                            validate_delete_booking_invitation_response(action, original_action, booking_invitation_response)
+                        }
+                        EntryTypes::BookingBundle(booking_bundle) => {
+                            validate_delete_booking_bundle(action, original_action, booking_bundle)
+                        }
+                        EntryTypes::AttributeSchema(attribute_schema) => {
+                            validate_delete_attribute_schema(action, original_action, attribute_schema)
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
@@ -241,124 +678,565 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
-            }
-        }
-        FlatOp::RegisterDeleteLink {
-            link_type,
-            base_address,
-            target_address,
-            tag,
-            original_action,
-            action,
-        } => {
-            match link_type {
-                LinkTypes::ResourceUpdates => {
-                    validate_delete_link_resource_updates(
+                LinkTypes::ResourceToWaitlist => {
+                    validate_create_link_resource_to_waitlist(
                         action,
-                        original_action,
                         base_address,
                         target_address,
                         tag,
                     )
                 }
-                LinkTypes::ResourceToBookingRequests => {
-                    validate_delete_link_resource_to_booking_requests(
+                LinkTypes::ResourceToHolds => {
+                    validate_create_link_resource_to_holds(
                         action,
-                        original_action,
                         base_address,
                         target_address,
                         tag,
                     )
                 }
-                LinkTypes::BookingRequestToBookings => {
-                    validate_delete_link_booking_request_to_bookings(
+                LinkTypes::BookingToCancellations => {
+                    validate_create_link_booking_to_cancellations(
                         action,
-                        original_action,
                         base_address,
                         target_address,
                         tag,
                     )
                 }
-                LinkTypes::ResourceToBookings => {
-                    validate_delete_link_resource_to_bookings(
+                LinkTypes::BookingToRescheduleProposals => {
+                    validate_create_link_booking_to_reschedule_proposals(
                         action,
-                        original_action,
                         base_address,
                         target_address,
                         tag,
                     )
                 }
-                LinkTypes::AllResources => {
-                    validate_delete_link_all_resources(
+                LinkTypes::BookingToUsageEvents => {
+                    validate_create_link_booking_to_usage_events(
                         action,
-                        original_action,
                         base_address,
                         target_address,
                         tag,
                     )
                 }
-                LinkTypes::MyResources => {
-                    validate_delete_link_my_resources(
+                LinkTypes::ResourceToConditionReports => {
+                    validate_create_link_resource_to_condition_reports(
                         action,
-                        original_action,
                         base_address,
                         target_address,
                         tag,
                     )
                 }
-                LinkTypes::MyBookingRequests => {
-                    validate_delete_link_my_booking_requests(
+                LinkTypes::ResourceToMaintenanceTasks => {
+                    validate_create_link_resource_to_maintenance_tasks(
                         action,
-                        original_action,
                         base_address,
                         target_address,
                         tag,
                     )
                 }
-                LinkTypes::BookerToBookings => {
-                    validate_delete_link_booker_to_bookings(
+                LinkTypes::ResourceToMaintenanceWindows => {
+                    validate_create_link_resource_to_maintenance_windows(
                         action,
-                        original_action,
                         base_address,
                         target_address,
                         tag,
                     )
                 }
-            }
-        }
-        FlatOp::StoreRecord(store_record) => {
-            match store_record {
-                OpRecord::CreateEntry { app_entry, action } => {
-                    match app_entry {
-                        EntryTypes::Resource(resource) => {
-                            validate_create_resource(
-                                EntryCreationAction::Create(action),
-                                resource,
-                            )
-                        }
-                        EntryTypes::BookingRequest(booking_request) => {
-                            validate_create_booking_request(
-                                EntryCreationAction::Create(action),
-                                booking_request,
-                            )
-                        }
-                        EntryTypes::Booking(booking) => {
-                            validate_create_booking(
-                                EntryCreationAction::Create(action),
-                                booking,
-                            )
-                        }
-                    }
+                LinkTypes::BookingToCreditTransfers => {
+                    validate_create_link_booking_to_credit_transfers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
                 }
-                OpRecord::UpdateEntry {
-                    original_action_hash,
-                    app_entry,
-                    action,
-                    ..
-                } => {
-                    let original_record = must_get_valid_record(original_action_hash)?;
-                    let original_action = original_record.action().clone();
-                    let original_action = match original_action {
+                LinkTypes::AgentToCreditTransfers => {
+                    validate_create_link_agent_to_credit_transfers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToInvoices => {
+                    validate_create_link_agent_to_invoices(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingToPaymentAcknowledgements => {
+                    validate_create_link_booking_to_payment_acknowledgements(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingToInvitations => {
+                    validate_create_link_booking_to_invitations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteeToInvitations => {
+                    validate_create_link_invitee_to_invitations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InvitationToResponses => {
+                    validate_create_link_invitation_to_responses(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BundleToBookingRequests => {
+                    validate_create_link_bundle_to_booking_requests(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BundleToBookings => {
+                    validate_create_link_bundle_to_bookings(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ParentToChildResources => {
+                    validate_create_link_parent_to_child_resources(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ChildToParentResources => {
+                    validate_create_link_child_to_parent_resources(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::LocationToResources => {
+                    validate_create_link_location_to_resources(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CategoryToAttributeSchemas => {
+                    validate_create_link_category_to_attribute_schemas(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+            }
+        }
+        FlatOp::RegisterDeleteLink {
+            link_type,
+            base_address,
+            target_address,
+            tag,
+            original_action,
+            action,
+        } => {
+            match link_type {
+                LinkTypes::ResourceUpdates => {
+                    validate_delete_link_resource_updates(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ResourceToBookingRequests => {
+                    validate_delete_link_resource_to_booking_requests(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingRequestToBookings => {
+                    validate_delete_link_booking_request_to_bookings(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ResourceToBookings => {
+                    validate_delete_link_resource_to_bookings(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllResources => {
+                    validate_delete_link_all_resources(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::MyResources => {
+                    validate_delete_link_my_resources(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::MyBookingRequests => {
+                    validate_delete_link_my_booking_requests(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookerToBookings => {
+                    validate_delete_link_booker_to_bookings(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ResourceToWaitlist => {
+                    validate_delete_link_resource_to_waitlist(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ResourceToHolds => {
+                    validate_delete_link_resource_to_holds(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingToCancellations => {
+                    validate_delete_link_booking_to_cancellations(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingToRescheduleProposals => {
+                    validate_delete_link_booking_to_reschedule_proposals(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingToUsageEvents => {
+                    validate_delete_link_booking_to_usage_events(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ResourceToConditionReports => {
+                    validate_delete_link_resource_to_condition_reports(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ResourceToMaintenanceTasks => {
+                    validate_delete_link_resource_to_maintenance_tasks(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ResourceToMaintenanceWindows => {
+                    validate_delete_link_resource_to_maintenance_windows(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingToCreditTransfers => {
+                    validate_delete_link_booking_to_credit_transfers(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToCreditTransfers => {
+                    validate_delete_link_agent_to_credit_transfers(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToInvoices => {
+                    validate_delete_link_agent_to_invoices(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingToPaymentAcknowledgements => {
+                    validate_delete_link_booking_to_payment_acknowledgements(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BookingToInvitations => {
+                    validate_delete_link_booking_to_invitations(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteeToInvitations => {
+                    validate_delete_link_invitee_to_invitations(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InvitationToResponses => {
+                    validate_delete_link_invitation_to_responses(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BundleToBookingRequests => {
+                    validate_delete_link_bundle_to_booking_requests(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BundleToBookings => {
+                    validate_delete_link_bundle_to_bookings(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ParentToChildResources => {
+                    validate_delete_link_parent_to_child_resources(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::ChildToParentResources => {
+                    validate_delete_link_child_to_parent_resources(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::LocationToResources => {
+                    validate_delete_link_location_to_resources(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CategoryToAttributeSchemas => {
+                    validate_delete_link_category_to_attribute_schemas(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+            }
+        }
+        FlatOp::StoreRecord(store_record) => {
+            match store_record {
+                OpRecord::CreateEntry { app_entry, action } => {
+                    match app_entry {
+                        EntryTypes::Resource(resource) => {
+                            validate_create_resource(
+                                EntryCreationAction::Create(action),
+                                resource,
+                            )
+                        }
+                        EntryTypes::BookingRequest(booking_request) => {
+                            validate_create_booking_request(
+                                EntryCreationAction::Create(action),
+                                booking_request,
+                            )
+                        }
+                        EntryTypes::Booking(booking) => {
+                            validate_create_booking(
+                                EntryCreationAction::Create(action),
+                                booking,
+                            )
+                        }
+                        EntryTypes::Hold(hold) => {
+                            validate_create_hold(
+                                EntryCreationAction::Create(action),
+                                hold,
+                            )
+                        }
+                        EntryTypes::BookingCancellation(booking_cancellation) => {
+                            validate_create_booking_cancellation(
+                                EntryCreationAction::Create(action),
+                                booking_cancellation,
+                            )
+                        }
+                        EntryTypes::RescheduleProposal(reschedule_proposal) => {
+                            validate_create_reschedule_proposal(
+                                EntryCreationAction::Create(action),
+                                reschedule_proposal,
+                            )
+                        }
+                        EntryTypes::UsageEvent(usage_event) => {
+                            validate_create_usage_event(
+                                EntryCreationAction::Create(action),
+                                usage_event,
+                            )
+                        }
+                        EntryTypes::ConditionReport(condition_report) => {
+                            validate_create_condition_report(
+                                EntryCreationAction::Create(action),
+                                condition_report,
+                            )
+                        }
+                        EntryTypes::MaintenanceTask(maintenance_task) => {
+                            validate_create_maintenance_task(
+                                EntryCreationAction::Create(action),
+                                maintenance_task,
+                            )
+                        }
+                        EntryTypes::MaintenanceWindow(maintenance_window) => {
+                            validate_create_maintenance_window(
+                                EntryCreationAction::Create(action),
+                                maintenance_window,
+                            )
+                        }
+                        EntryTypes::CreditTransfer(credit_transfer) => {
+                            validate_create_credit_transfer(
+                                EntryCreationAction::Create(action),
+                                credit_transfer,
+                            )
+                        }
+                        EntryTypes::Invoice(invoice) => {
+                            validate_create_invoice(
+                                EntryCreationAction::Create(action),
+                                invoice,
+                            )
+                        }
+                        EntryTypes::PaymentAcknowledgement(payment_acknowledgement) => {
+                            validate_create_payment_acknowledgement(
+                                EntryCreationAction::Create(action),
+                                payment_acknowledgement,
+                            )
+                        }
+                        EntryTypes::BookingInvitation(booking_invitation) => {
+                            validate_create_booking_invitation(
+                                EntryCreationAction::Create(action),
+                                booking_invitation,
+                            )
+                        }
+                        EntryTypes::BookingInvitationResponse(booking_invitation_response) => {
+                            validate_create_booking_invitation_response(
+                                EntryCreationAction::Create(action),
+                                booking_invitation_response,
+                            )
+                        }
+                        EntryTypes::BookingBundle(booking_bundle) => {
+                            validate_create_booking_bundle(
+                                EntryCreationAction::Create(action),
+                                booking_bundle,
+                            )
+                        }
+                        EntryTypes::AttributeSchema(attribute_schema) => {
+                            validate_create_attribute_schema(
+                                EntryCreationAction::Create(action),
+                                attribute_schema,
+                            )
+                        }
+                    }
+                }
+                OpRecord::UpdateEntry {
+                    original_action_hash,
+                    app_entry,
+                    action,
+                    ..
+                } => {
+                    let original_record = must_get_valid_record(original_action_hash)?;
+                    let original_action = original_record.action().clone();
+                    let original_action = match original_action {
                         Action::Create(create) => EntryCreationAction::Create(create),
                         Action::Update(update) => EntryCreationAction::Update(update),
                         _ => {
@@ -369,20 +1247,454 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 ),
                             );
                         }
-                    };
-                    match app_entry {
-                        EntryTypes::Resource(resource) => {
-                            let result = validate_create_resource(
+                    };
+                    match app_entry {
+                        EntryTypes::Resource(resource) => {
+                            let result = validate_create_resource(
+                                EntryCreationAction::Update(action.clone()),
+                                resource.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_resource: Option<Resource> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_resource = match original_resource {
+                                    Some(resource) => resource,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_resource(
+                                    action,
+                                    resource,
+                                    original_action,
+                                    original_resource,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::BookingRequest(booking_request) => {
+                            let result = validate_create_booking_request(
+                                EntryCreationAction::Update(action.clone()),
+                                booking_request.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_booking_request: Option<BookingRequest> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_booking_request = match original_booking_request {
+                                    Some(booking_request) => booking_request,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_booking_request(
+                                    action,
+                                    booking_request,
+                                    original_action,
+                                    original_booking_request,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Booking(booking) => {
+                            let result = validate_create_booking(
+                                EntryCreationAction::Update(action.clone()),
+                                booking.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_booking: Option<Booking> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_booking = match original_booking {
+                                    Some(booking) => booking,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_booking(
+                                    action,
+                                    booking,
+                                    original_action,
+                                    original_booking,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Hold(hold) => {
+                            let result = validate_create_hold(
+                                EntryCreationAction::Update(action.clone()),
+                                hold.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_hold: Option<Hold> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_hold = match original_hold {
+                                    Some(hold) => hold,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_hold(
+                                    action,
+                                    hold,
+                                    original_action,
+                                    original_hold,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::BookingCancellation(booking_cancellation) => {
+                            let result = validate_create_booking_cancellation(
+                                EntryCreationAction::Update(action.clone()),
+                                booking_cancellation.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_booking_cancellation: Option<BookingCancellation> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_booking_cancellation = match original_booking_cancellation {
+                                    Some(booking_cancellation) => booking_cancellation,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_booking_cancellation(
+                                    action,
+                                    booking_cancellation,
+                                    original_action,
+                                    original_booking_cancellation,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::RescheduleProposal(reschedule_proposal) => {
+                            let result = validate_create_reschedule_proposal(
+                                EntryCreationAction::Update(action.clone()),
+                                reschedule_proposal.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_reschedule_proposal: Option<RescheduleProposal> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_reschedule_proposal = match original_reschedule_proposal {
+                                    Some(reschedule_proposal) => reschedule_proposal,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_reschedule_proposal(
+                                    action,
+                                    reschedule_proposal,
+                                    original_action,
+                                    original_reschedule_proposal,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::UsageEvent(usage_event) => {
+                            let result = validate_create_usage_event(
+                                EntryCreationAction::Update(action.clone()),
+                                usage_event.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_usage_event: Option<UsageEvent> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_usage_event = match original_usage_event {
+                                    Some(usage_event) => usage_event,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_usage_event(
+                                    action,
+                                    usage_event,
+                                    original_action,
+                                    original_usage_event,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ConditionReport(condition_report) => {
+                            let result = validate_create_condition_report(
+                                EntryCreationAction::Update(action.clone()),
+                                condition_report.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_condition_report: Option<ConditionReport> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_condition_report = match original_condition_report {
+                                    Some(condition_report) => condition_report,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_condition_report(
+                                    action,
+                                    condition_report,
+                                    original_action,
+                                    original_condition_report,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::MaintenanceTask(maintenance_task) => {
+                            let result = validate_create_maintenance_task(
+                                EntryCreationAction::Update(action.clone()),
+                                maintenance_task.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_maintenance_task: Option<MaintenanceTask> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_maintenance_task = match original_maintenance_task {
+                                    Some(maintenance_task) => maintenance_task,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_maintenance_task(
+                                    action,
+                                    maintenance_task,
+                                    original_action,
+                                    original_maintenance_task,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::MaintenanceWindow(maintenance_window) => {
+                            let result = validate_create_maintenance_window(
+                                EntryCreationAction::Update(action.clone()),
+                                maintenance_window.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_maintenance_window: Option<MaintenanceWindow> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_maintenance_window = match original_maintenance_window {
+                                    Some(maintenance_window) => maintenance_window,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_maintenance_window(
+                                    action,
+                                    maintenance_window,
+                                    original_action,
+                                    original_maintenance_window,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::CreditTransfer(credit_transfer) => {
+                            let result = validate_create_credit_transfer(
+                                EntryCreationAction::Update(action.clone()),
+                                credit_transfer.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_credit_transfer: Option<CreditTransfer> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_credit_transfer = match original_credit_transfer {
+                                    Some(credit_transfer) => credit_transfer,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_credit_transfer(
+                                    action,
+                                    credit_transfer,
+                                    original_action,
+                                    original_credit_transfer,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Invoice(invoice) => {
+                            let result = validate_create_invoice(
+                                EntryCreationAction::Update(action.clone()),
+                                invoice.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_invoice: Option<Invoice> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_invoice = match original_invoice {
+                                    Some(invoice) => invoice,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_invoice(
+                                    action,
+                                    invoice,
+                                    original_action,
+                                    original_invoice,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::PaymentAcknowledgement(payment_acknowledgement) => {
+                            let result = validate_create_payment_acknowledgement(
+                                EntryCreationAction::Update(action.clone()),
+                                payment_acknowledgement.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_payment_acknowledgement: Option<PaymentAcknowledgement> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_payment_acknowledgement = match original_payment_acknowledgement {
+                                    Some(payment_acknowledgement) => payment_acknowledgement,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_payment_acknowledgement(
+                                    action,
+                                    payment_acknowledgement,
+                                    original_action,
+                                    original_payment_acknowledgement,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::BookingInvitation(booking_invitation) => {
+                            let result = validate_create_booking_invitation(
+                                EntryCreationAction::Update(action.clone()),
+                                booking_invitation.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_booking_invitation: Option<BookingInvitation> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_booking_invitation = match original_booking_invitation {
+                                    Some(booking_invitation) => booking_invitation,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_booking_invitation(
+                                    action,
+                                    booking_invitation,
+                                    original_action,
+                                    original_booking_invitation,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::BookingInvitationResponse(booking_invitation_response) => {
+                            let result = validate_create_booking_invitation_response(
                                 EntryCreationAction::Update(action.clone()),
-                                resource.clone(),
+                                booking_invitation_response.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_resource: Option<Resource> = original_record
+                                let original_booking_invitation_response: Option<BookingInvitationResponse> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_resource = match original_resource {
-                                    Some(resource) => resource,
+                                let original_booking_invitation_response = match original_booking_invitation_response {
+                                    Some(booking_invitation_response) => booking_invitation_response,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
@@ -392,28 +1704,28 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                         );
                                     }
                                 };
-                                validate_update_resource(
+                                validate_update_booking_invitation_response(
                                     action,
-                                    resource,
+                                    booking_invitation_response,
                                     original_action,
-                                    original_resource,
+                                    original_booking_invitation_response,
                                 )
                             } else {
                                 Ok(result)
                             }
                         }
-                        EntryTypes::BookingRequest(booking_request) => {
-                            let result = validate_create_booking_request(
+                        EntryTypes::BookingBundle(booking_bundle) => {
+                            let result = validate_create_booking_bundle(
                                 EntryCreationAction::Update(action.clone()),
-                                booking_request.clone(),
+                                booking_bundle.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_booking_request: Option<BookingRequest> = original_record
+                                let original_booking_bundle: Option<BookingBundle> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_booking_request = match original_booking_request {
-                                    Some(booking_request) => booking_request,
+                                let original_booking_bundle = match original_booking_bundle {
+                                    Some(booking_bundle) => booking_bundle,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
@@ -423,28 +1735,28 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                         );
                                     }
                                 };
-                                validate_update_booking_request(
+                                validate_update_booking_bundle(
                                     action,
-                                    booking_request,
+                                    booking_bundle,
                                     original_action,
-                                    original_booking_request,
+                                    original_booking_bundle,
                                 )
                             } else {
                                 Ok(result)
                             }
                         }
-                        EntryTypes::Booking(booking) => {
-                            let result = validate_create_booking(
+                        EntryTypes::AttributeSchema(attribute_schema) => {
+                            let result = validate_create_attribute_schema(
                                 EntryCreationAction::Update(action.clone()),
-                                booking.clone(),
+                                attribute_schema.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_booking: Option<Booking> = original_record
+                                let original_attribute_schema: Option<AttributeSchema> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_booking = match original_booking {
-                                    Some(booking) => booking,
+                                let original_attribute_schema = match original_attribute_schema {
+                                    Some(attribute_schema) => attribute_schema,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
@@ -454,11 +1766,11 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                         );
                                     }
                                 };
-                                validate_update_booking(
+                                validate_update_attribute_schema(
                                     action,
-                                    booking,
+                                    attribute_schema,
                                     original_action,
-                                    original_booking,
+                                    original_attribute_schema,
                                 )
                             } else {
                                 Ok(result)
@@ -480,133 +1792,399 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 ),
                             );
                         }
-                    };
-                    let app_entry_type = match original_action.entry_type() {
-                        EntryType::App(app_entry_type) => app_entry_type,
-                        _ => {
-                            return Ok(ValidateCallbackResult::Valid);
+                    };
+                    let app_entry_type = match original_action.entry_type() {
+                        EntryType::App(app_entry_type) => app_entry_type,
+                        _ => {
+                            return Ok(ValidateCallbackResult::Valid);
+                        }
+                    };
+                    let entry = match original_record.entry().as_option() {
+                        Some(entry) => entry,
+                        None => {
+                            if original_action.entry_type().visibility().is_public() {
+                                return Ok(
+                                    ValidateCallbackResult::Invalid(
+                                        "Original record for a delete of a public entry must contain an entry"
+                                            .to_string(),
+                                    ),
+                                );
+                            } else {
+                                return Ok(ValidateCallbackResult::Valid);
+                            }
+                        }
+                    };
+                    let original_app_entry = match EntryTypes::deserialize_from_type(
+                        app_entry_type.zome_index.clone(),
+                        app_entry_type.entry_index.clone(),
+                        &entry,
+                    )? {
+                        Some(app_entry) => app_entry,
+                        None => {
+                            return Ok(
+                                ValidateCallbackResult::Invalid(
+                                    "Original app entry must be one of the defined entry types for this zome"
+                                        .to_string(),
+                                ),
+                            );
+                        }
+                    };
+                    match original_app_entry {
+                        EntryTypes::Resource(original_resource) => {
+                            validate_delete_resource(
+                                action,
+                                original_action,
+                                original_resource,
+                            )
+                        }
+                        EntryTypes::BookingRequest(original_booking_request) => {
+                            validate_delete_booking_request(
+                                action,
+                                original_action,
+                                original_booking_request,
+                            )
+                        }
+                        EntryTypes::Booking(original_booking) => {
+                            validate_delete_booking(
+                                action,
+                                original_action,
+                                original_booking,
+                            )
+                        }
+                        EntryTypes::Hold(original_hold) => {
+                            validate_delete_hold(
+                                action,
+                                original_action,
+                                original_hold,
+                            )
+                        }
+                        EntryTypes::BookingCancellation(original_booking_cancellation) => {
+                            validate_delete_booking_cancellation(
+                                action,
+                                original_action,
+                                original_booking_cancellation,
+                            )
+                        }
+                        EntryTypes::RescheduleProposal(original_reschedule_proposal) => {
+                            validate_delete_reschedule_proposal(
+                                action,
+                                original_action,
+                                original_reschedule_proposal,
+                            )
+                        }
+                        EntryTypes::UsageEvent(original_usage_event) => {
+                            validate_delete_usage_event(
+                                action,
+                                original_action,
+                                original_usage_event,
+                            )
+                        }
+                        EntryTypes::ConditionReport(original_condition_report) => {
+                            validate_delete_condition_report(
+                                action,
+                                original_action,
+                                original_condition_report,
+                            )
+                        }
+                        EntryTypes::MaintenanceTask(original_maintenance_task) => {
+                            validate_delete_maintenance_task(
+                                action,
+                                original_action,
+                                original_maintenance_task,
+                            )
+                        }
+                        EntryTypes::MaintenanceWindow(original_maintenance_window) => {
+                            validate_delete_maintenance_window(
+                                action,
+                                original_action,
+                                original_maintenance_window,
+                            )
+                        }
+                        EntryTypes::CreditTransfer(original_credit_transfer) => {
+                            validate_delete_credit_transfer(
+                                action,
+                                original_action,
+                                original_credit_transfer,
+                            )
+                        }
+                        EntryTypes::Invoice(original_invoice) => {
+                            validate_delete_invoice(
+                                action,
+                                original_action,
+                                original_invoice,
+                            )
+                        }
+                        EntryTypes::PaymentAcknowledgement(original_payment_acknowledgement) => {
+                            validate_delete_payment_acknowledgement(
+                                action,
+                                original_action,
+                                original_payment_acknowledgement,
+                            )
+                        }
+                        EntryTypes::BookingInvitation(original_booking_invitation) => {
+                            validate_delete_booking_invitation(
+                                action,
+                                original_action,
+                                original_booking_invitation,
+                            )
+                        }
+                        EntryTypes::BookingInvitationResponse(original_booking_invitation_response) => {
+                            validate_delete_booking_invitation_response(
+                                action,
+                                original_action,
+                                original_booking_invitation_response,
+                            )
+                        }
+                        EntryTypes::BookingBundle(original_booking_bundle) => {
+                            validate_delete_booking_bundle(
+                                action,
+                                original_action,
+                                original_booking_bundle,
+                            )
+                        }
+                        EntryTypes::AttributeSchema(original_attribute_schema) => {
+                            validate_delete_attribute_schema(
+                                action,
+                                original_action,
+                                original_attribute_schema,
+                            )
+                        }
+                    }
+                }
+                OpRecord::CreateLink {
+                    base_address,
+                    target_address,
+                    tag,
+                    link_type,
+                    action,
+                } => {
+                    match link_type {
+                        LinkTypes::ResourceUpdates => {
+                            validate_create_link_resource_updates(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ResourceToBookingRequests => {
+                            validate_create_link_resource_to_booking_requests(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::BookingRequestToBookings => {
+                            validate_create_link_booking_request_to_bookings(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ResourceToBookings => {
+                            validate_create_link_resource_to_bookings(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllResources => {
+                            validate_create_link_all_resources(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::MyResources => {
+                            validate_create_link_my_resources(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::MyBookingRequests => {
+                            validate_create_link_my_booking_requests(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::BookerToBookings => {
+                            validate_create_link_booker_to_bookings(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ResourceToWaitlist => {
+                            validate_create_link_resource_to_waitlist(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ResourceToHolds => {
+                            validate_create_link_resource_to_holds(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::BookingToCancellations => {
+                            validate_create_link_booking_to_cancellations(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::BookingToRescheduleProposals => {
+                            validate_create_link_booking_to_reschedule_proposals(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::BookingToUsageEvents => {
+                            validate_create_link_booking_to_usage_events(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::ResourceToConditionReports => {
+                            validate_create_link_resource_to_condition_reports(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
                         }
-                    };
-                    let entry = match original_record.entry().as_option() {
-                        Some(entry) => entry,
-                        None => {
-                            if original_action.entry_type().visibility().is_public() {
-                                return Ok(
-                                    ValidateCallbackResult::Invalid(
-                                        "Original record for a delete of a public entry must contain an entry"
-                                            .to_string(),
-                                    ),
-                                );
-                            } else {
-                                return Ok(ValidateCallbackResult::Valid);
-                            }
+                        LinkTypes::ResourceToMaintenanceTasks => {
+                            validate_create_link_resource_to_maintenance_tasks(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
                         }
-                    };
-                    let original_app_entry = match EntryTypes::deserialize_from_type(
-                        app_entry_type.zome_index.clone(),
-                        app_entry_type.entry_index.clone(),
-                        &entry,
-                    )? {
-                        Some(app_entry) => app_entry,
-                        None => {
-                            return Ok(
-                                ValidateCallbackResult::Invalid(
-                                    "Original app entry must be one of the defined entry types for this zome"
-                                        .to_string(),
-                                ),
-                            );
+                        LinkTypes::ResourceToMaintenanceWindows => {
+                            validate_create_link_resource_to_maintenance_windows(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
                         }
-                    };
-                    match original_app_entry {
-                        EntryTypes::Resource(original_resource) => {
-                            validate_delete_resource(
+                        LinkTypes::BookingToCreditTransfers => {
+                            validate_create_link_booking_to_credit_transfers(
                                 action,
-                                original_action,
-                                original_resource,
+                                base_address,
+                                target_address,
+                                tag,
                             )
                         }
-                        EntryTypes::BookingRequest(original_booking_request) => {
-                            validate_delete_booking_request(
+                        LinkTypes::AgentToCreditTransfers => {
+                            validate_create_link_agent_to_credit_transfers(
                                 action,
-                                original_action,
-                                original_booking_request,
+                                base_address,
+                                target_address,
+                                tag,
                             )
                         }
-                        EntryTypes::Booking(original_booking) => {
-                            validate_delete_booking(
+                        LinkTypes::AgentToInvoices => {
+                            validate_create_link_agent_to_invoices(
                                 action,
-                                original_action,
-                                original_booking,
+                                base_address,
+                                target_address,
+                                tag,
                             )
                         }
-                    }
-                }
-                OpRecord::CreateLink {
-                    base_address,
-                    target_address,
-                    tag,
-                    link_type,
-                    action,
-                } => {
-                    match link_type {
-                        LinkTypes::ResourceUpdates => {
-                            validate_create_link_resource_updates(
+                        LinkTypes::BookingToPaymentAcknowledgements => {
+                            validate_create_link_booking_to_payment_acknowledgements(
                                 action,
                                 base_address,
                                 target_address,
                                 tag,
                             )
                         }
-                        LinkTypes::ResourceToBookingRequests => {
-                            validate_create_link_resource_to_booking_requests(
+                        LinkTypes::BookingToInvitations => {
+                            validate_create_link_booking_to_invitations(
                                 action,
                                 base_address,
                                 target_address,
                                 tag,
                             )
                         }
-                        LinkTypes::BookingRequestToBookings => {
-                            validate_create_link_booking_request_to_bookings(
+                        LinkTypes::InviteeToInvitations => {
+                            validate_create_link_invitee_to_invitations(
                                 action,
                                 base_address,
                                 target_address,
                                 tag,
                             )
                         }
-                        LinkTypes::ResourceToBookings => {
-                            validate_create_link_resource_to_bookings(
+                        LinkTypes::InvitationToResponses => {
+                            validate_create_link_invitation_to_responses(
                                 action,
                                 base_address,
                                 target_address,
                                 tag,
                             )
                         }
-                        LinkTypes::AllResources => {
-                            validate_create_link_all_resources(
+                        LinkTypes::BundleToBookingRequests => {
+                            validate_create_link_bundle_to_booking_requests(
                                 action,
                                 base_address,
                                 target_address,
                                 tag,
                             )
                         }
-                        LinkTypes::MyResources => {
-                            validate_create_link_my_resources(
+                        LinkTypes::BundleToBookings => {
+                            validate_create_link_bundle_to_bookings(
                                 action,
                                 base_address,
                                 target_address,
                                 tag,
                             )
                         }
-                        LinkTypes::MyBookingRequests => {
-                            validate_create_link_my_booking_requests(
+                        LinkTypes::ParentToChildResources => {
+                            validate_create_link_parent_to_child_resources(
                                 action,
                                 base_address,
                                 target_address,
                                 tag,
                             )
                         }
-                        LinkTypes::BookerToBookings => {
-                            validate_create_link_booker_to_bookings(
+                        LinkTypes::ChildToParentResources => {
+                            validate_create_link_child_to_parent_resources(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::LocationToResources => {
+                            validate_create_link_location_to_resources(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::CategoryToAttributeSchemas => {
+                            validate_create_link_category_to_attribute_schemas(
                                 action,
                                 base_address,
                                 target_address,
@@ -710,6 +2288,195 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 create_link.tag,
                             )
                         }
+                        LinkTypes::ResourceToWaitlist => {
+                            validate_delete_link_resource_to_waitlist(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ResourceToHolds => {
+                            validate_delete_link_resource_to_holds(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::BookingToCancellations => {
+                            validate_delete_link_booking_to_cancellations(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::BookingToRescheduleProposals => {
+                            validate_delete_link_booking_to_reschedule_proposals(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::BookingToUsageEvents => {
+                            validate_delete_link_booking_to_usage_events(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ResourceToConditionReports => {
+                            validate_delete_link_resource_to_condition_reports(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ResourceToMaintenanceTasks => {
+                            validate_delete_link_resource_to_maintenance_tasks(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ResourceToMaintenanceWindows => {
+                            validate_delete_link_resource_to_maintenance_windows(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::BookingToCreditTransfers => {
+                            validate_delete_link_booking_to_credit_transfers(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToCreditTransfers => {
+                            validate_delete_link_agent_to_credit_transfers(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToInvoices => {
+                            validate_delete_link_agent_to_invoices(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::BookingToPaymentAcknowledgements => {
+                            validate_delete_link_booking_to_payment_acknowledgements(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::BookingToInvitations => {
+                            validate_delete_link_booking_to_invitations(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::InviteeToInvitations => {
+                            validate_delete_link_invitee_to_invitations(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::InvitationToResponses => {
+                            validate_delete_link_invitation_to_responses(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::BundleToBookingRequests => {
+                            validate_delete_link_bundle_to_booking_requests(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::BundleToBookings => {
+                            validate_delete_link_bundle_to_bookings(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ParentToChildResources => {
+                            validate_delete_link_parent_to_child_resources(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::ChildToParentResources => {
+                            validate_delete_link_child_to_parent_resources(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::LocationToResources => {
+                            validate_delete_link_location_to_resources(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::CategoryToAttributeSchemas => {
+                            validate_delete_link_category_to_attribute_schemas(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
@@ -0,0 +1,193 @@
+use hdi::hash_path::path::Path;
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Location {
+    pub address: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+// Resources are indexed under every prefix of their geohash up to this precision,
+// which is a cell of about 150 x 150 meters
+pub const LOCATION_INDEX_PRECISION: usize = 7;
+const GEOHASH_ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+pub fn validate_location(location: &Location) -> ExternResult<ValidateCallbackResult> {
+    if !location.latitude.is_finite() || location.latitude < -90.0
+        || location.latitude > 90.0
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The latitude must be between -90 and 90 degrees"),
+            ),
+        );
+    }
+    if !location.longitude.is_finite() || location.longitude < -180.0
+        || location.longitude > 180.0
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The longitude must be between -180 and 180 degrees"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn geohash_encode(latitude: f64, longitude: f64, precision: usize) -> String {
+    let mut latitude_range = (-90.0, 90.0);
+    let mut longitude_range = (-180.0, 180.0);
+    let mut geohash = String::new();
+    let mut even_bit = true;
+    let mut bits = 0;
+    let mut index = 0;
+    while geohash.len() < precision {
+        let (range, value) = if even_bit {
+            (&mut longitude_range, longitude)
+        } else {
+            (&mut latitude_range, latitude)
+        };
+        let middle = (range.0 + range.1) / 2.0;
+        index <<= 1;
+        if value >= middle {
+            index |= 1;
+            range.0 = middle;
+        } else {
+            range.1 = middle;
+        }
+        even_bit = !even_bit;
+        bits += 1;
+        if bits == 5 {
+            geohash.push(GEOHASH_ALPHABET[index] as char);
+            bits = 0;
+            index = 0;
+        }
+    }
+    geohash
+}
+// Height and width in degrees of the cells of the given precision
+pub fn geohash_cell_size(precision: usize) -> (f64, f64) {
+    let bits = 5 * precision as i32;
+    let longitude_bits = (bits + 1) / 2;
+    let latitude_bits = bits / 2;
+    (180.0 / 2f64.powi(latitude_bits), 360.0 / 2f64.powi(longitude_bits))
+}
+pub fn location_path(geohash_prefix: &str) -> Path {
+    let mut components = vec![String::from("resource_locations")];
+    components.extend(geohash_prefix.chars().map(|c| c.to_string()));
+    Path::from(components.join("."))
+}
+pub fn distance_meters(
+    latitude_a: f64,
+    longitude_a: f64,
+    latitude_b: f64,
+    longitude_b: f64,
+) -> f64 {
+    let delta_latitude = (latitude_b - latitude_a).to_radians();
+    let delta_longitude = (longitude_b - longitude_a).to_radians();
+    let a = (delta_latitude / 2.0).sin().powi(2)
+        + latitude_a.to_radians().cos() * latitude_b.to_radians().cos()
+            * (delta_longitude / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+}
+// The tag of the links holds the full geohash of the resource followed by the hash of
+// the version of the resource whose location is indexed
+pub fn location_link_tag(geohash: &str, resource_version_hash: &ActionHash) -> LinkTag {
+    let mut tag = geohash.as_bytes().to_vec();
+    tag.extend(resource_version_hash.get_raw_39());
+    LinkTag::new(tag)
+}
+// The geohash in the tag must be the one of the location of the indexed version, and the
+// base must be the path of one of its prefixes
+pub fn validate_create_link_location_to_resources(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let resource_hash = ActionHash::from(target_address);
+    if tag.0.len() <= LOCATION_INDEX_PRECISION {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The tag must hold the geohash and the version of the resource"),
+            ),
+        );
+    }
+    let (geohash, resource_version_hash) = tag.0.split_at(LOCATION_INDEX_PRECISION);
+    let Ok(resource_version_hash) = ActionHash::from_raw_39(resource_version_hash.to_vec())
+    else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The tag must hold the geohash and the version of the resource"),
+            ),
+        );
+    };
+    let Some(record) = crate::must_get_resource_version(&resource_hash, &resource_version_hash)?
+    else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The indexed version must be a version of the linked resource made by its owner"),
+            ),
+        );
+    };
+    let resource: crate::Resource = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if record.action().author().ne(&action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of a resource can index its location"),
+            ),
+        );
+    }
+    let Some(location) = resource.location else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The indexed version of the resource has no location"),
+            ),
+        );
+    };
+    let expected_geohash = geohash_encode(
+        location.latitude,
+        location.longitude,
+        LOCATION_INDEX_PRECISION,
+    );
+    if expected_geohash.as_bytes().ne(geohash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The tag must hold the geohash of the location of the resource"),
+            ),
+        );
+    }
+    for precision in 1..=LOCATION_INDEX_PRECISION {
+        let path_hash = location_path(&expected_geohash[..precision]).path_entry_hash()?;
+        if AnyLinkableHash::from(path_hash).eq(&base_address) {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+    }
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Resources can only be linked from the prefixes of their geohash"),
+        ),
+    )
+}
+pub fn validate_delete_link_location_to_resources(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of a resource can remove its location"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,225 @@
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum MaintenanceTrigger {
+    Interval { interval_seconds: u64 },
+    Usage { odometer_interval: u64 },
+    AfterEachUse,
+}
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct MaintenanceTask {
+    pub resource_hash: ActionHash,
+    pub title: String,
+    pub trigger: MaintenanceTrigger,
+    pub duration_seconds: u64,
+}
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct MaintenanceWindow {
+    pub resource_hash: ActionHash,
+    pub maintenance_task_hash: Option<ActionHash>,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+}
+fn validate_resource_owner(
+    resource_hash: &ActionHash,
+    author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(resource_hash.clone())?;
+    let _resource: crate::Resource = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    if record.action().author().ne(author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of the resource can manage its maintenance"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_maintenance_task(
+    action: EntryCreationAction,
+    maintenance_task: MaintenanceTask,
+) -> ExternResult<ValidateCallbackResult> {
+    let result = validate_resource_owner(&maintenance_task.resource_hash, action.author())?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    match maintenance_task.trigger {
+        MaintenanceTrigger::Interval { interval_seconds: 0 }
+        | MaintenanceTrigger::Usage { odometer_interval: 0 } => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Maintenance intervals must be greater than 0"),
+                ),
+            );
+        }
+        _ => {}
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_maintenance_task(
+    _action: Update,
+    maintenance_task: MaintenanceTask,
+    _original_action: EntryCreationAction,
+    original_maintenance_task: MaintenanceTask,
+) -> ExternResult<ValidateCallbackResult> {
+    if maintenance_task.resource_hash.ne(&original_maintenance_task.resource_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The resource of a maintenance task can't be changed"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_maintenance_task(
+    action: Delete,
+    _original_action: EntryCreationAction,
+    original_maintenance_task: MaintenanceTask,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_resource_owner(&original_maintenance_task.resource_hash, &action.author)
+}
+pub fn validate_create_maintenance_window(
+    action: EntryCreationAction,
+    maintenance_window: MaintenanceWindow,
+) -> ExternResult<ValidateCallbackResult> {
+    let result = validate_resource_owner(&maintenance_window.resource_hash, action.author())?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    if maintenance_window.end_time < maintenance_window.start_time {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The end time can't be before the start time"),
+            ),
+        );
+    }
+    if let Some(maintenance_task_hash) = maintenance_window.maintenance_task_hash {
+        let record = must_get_valid_record(maintenance_task_hash)?;
+        let maintenance_task: crate::MaintenanceTask = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                ),
+            )?;
+        if maintenance_task.resource_hash.ne(&maintenance_window.resource_hash) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The maintenance task must be for the same resource"),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_maintenance_window(
+    _action: Update,
+    _maintenance_window: MaintenanceWindow,
+    _original_action: EntryCreationAction,
+    _original_maintenance_window: MaintenanceWindow,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Maintenance Windows cannot be updated"),
+        ),
+    )
+}
+pub fn validate_delete_maintenance_window(
+    action: Delete,
+    _original_action: EntryCreationAction,
+    original_maintenance_window: MaintenanceWindow,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_resource_owner(&original_maintenance_window.resource_hash, &action.author)
+}
+pub fn validate_create_link_resource_to_maintenance_tasks(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let resource_hash = ActionHash::from(base_address);
+    let result = validate_resource_owner(&resource_hash, &action.author)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let maintenance_task: crate::MaintenanceTask = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if maintenance_task.resource_hash.ne(&resource_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The maintenance task must belong to the linked resource"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_resource_to_maintenance_tasks(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_resource_owner(&ActionHash::from(base), &action.author)
+}
+pub fn validate_create_link_resource_to_maintenance_windows(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let resource_hash = ActionHash::from(base_address);
+    let result = validate_resource_owner(&resource_hash, &action.author)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let maintenance_window: crate::MaintenanceWindow = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if maintenance_window.resource_hash.ne(&resource_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The maintenance window must belong to the linked resource"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_resource_to_maintenance_windows(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_resource_owner(&ActionHash::from(base), &action.author)
+}
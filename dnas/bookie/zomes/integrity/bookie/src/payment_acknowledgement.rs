@@ -0,0 +1,186 @@
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum PaymentKind {
+    Deposit,
+    Payment,
+    Refund,
+}
+// Payments happen outside of bookie: the owner of the resource acknowledges them here
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct PaymentAcknowledgement {
+    pub booking_hash: ActionHash,
+    pub kind: PaymentKind,
+    pub amount: u64,
+    pub currency: String,
+    pub note: String,
+}
+pub fn validate_create_payment_acknowledgement(
+    action: EntryCreationAction,
+    payment_acknowledgement: PaymentAcknowledgement,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(payment_acknowledgement.booking_hash.clone())?;
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let resource_record = must_get_valid_record(booking.resource_hash.clone())?;
+    if resource_record.action().author().ne(action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of the resource can acknowledge payments"),
+            ),
+        );
+    }
+    if payment_acknowledgement.amount == 0 {
+        return Ok(
+            ValidateCallbackResult::Invalid(String::from("Payments must have an amount")),
+        );
+    }
+    if let Some(price) = booking.price {
+        if price.currency.ne(&payment_acknowledgement.currency) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Payments must be in the currency of the booking"),
+                ),
+            );
+        }
+    }
+    if payment_acknowledgement.kind == PaymentKind::Refund {
+        let Some((paid_amount, refunded_amount)) = acknowledged_amounts_in_author_chain(
+            &action,
+            &payment_acknowledgement.booking_hash,
+        )? else {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The acknowledged amounts of the booking overflow"),
+                ),
+            );
+        };
+        let refunded_amount = refunded_amount.checked_add(payment_acknowledgement.amount);
+        if !matches!(refunded_amount, Some(refunded_amount) if refunded_amount <= paid_amount) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Can't refund more than what was paid"),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+// Paid and refunded amounts of the booking, or None if they overflow. Only the owner of the
+// resource can acknowledge payments, so all of them are in the author's chain
+fn acknowledged_amounts_in_author_chain(
+    action: &EntryCreationAction,
+    booking_hash: &ActionHash,
+) -> ExternResult<Option<(u64, u64)>> {
+    let payment_entry_def = ScopedEntryDefIndex::try_from(
+        crate::UnitEntryTypes::PaymentAcknowledgement,
+    )?;
+    let activity = must_get_agent_activity(
+        action.author().clone(),
+        ChainFilter::new(action.prev_action().clone()),
+    )?;
+    let mut paid_amount: u64 = 0;
+    let mut refunded_amount: u64 = 0;
+    for item in activity {
+        let Action::Create(create) = item.action.action() else {
+            continue;
+        };
+        let EntryType::App(app_entry_def) = &create.entry_type else {
+            continue;
+        };
+        if app_entry_def.zome_index != payment_entry_def.zome_index
+            || app_entry_def.entry_index != payment_entry_def.zome_type
+        {
+            continue;
+        }
+        let record = must_get_valid_record(item.action.action_address().clone())?;
+        let Some(previous): Option<PaymentAcknowledgement> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))? else {
+            continue;
+        };
+        if previous.booking_hash.ne(booking_hash) {
+            continue;
+        }
+        let total = match previous.kind {
+            PaymentKind::Refund => &mut refunded_amount,
+            _ => &mut paid_amount,
+        };
+        let Some(sum) = total.checked_add(previous.amount) else {
+            return Ok(None);
+        };
+        *total = sum;
+    }
+    Ok(Some((paid_amount, refunded_amount)))
+}
+pub fn validate_update_payment_acknowledgement(
+    _action: Update,
+    _payment_acknowledgement: PaymentAcknowledgement,
+    _original_action: EntryCreationAction,
+    _original_payment_acknowledgement: PaymentAcknowledgement,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Payment Acknowledgements cannot be updated"),
+        ),
+    )
+}
+pub fn validate_delete_payment_acknowledgement(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_payment_acknowledgement: PaymentAcknowledgement,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Payment Acknowledgements cannot be deleted"),
+        ),
+    )
+}
+pub fn validate_create_link_booking_to_payment_acknowledgements(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let payment_acknowledgement: crate::PaymentAcknowledgement = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address.ne(&AnyLinkableHash::from(payment_acknowledgement.booking_hash)) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Payments can only be linked from their booking"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_booking_to_payment_acknowledgements(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("BookingToPaymentAcknowledgements links cannot be deleted"),
+        ),
+    )
+}
@@ -0,0 +1,203 @@
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum PricingUnit {
+    Hour,
+    Day,
+}
+// Units from `from_unit` on (starting at 0) are charged at `amount_per_unit`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PriceTier {
+    pub from_unit: u64,
+    pub amount_per_unit: u64,
+}
+// Surcharge for every started hour of the booking inside the given weekdays and hours
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PeakRate {
+    pub weekdays: Vec<u8>,
+    pub start_hour: u8,
+    pub end_hour: u8,
+    pub surcharge_per_hour: u64,
+}
+// All amounts are in the minor unit of the currency (e.g. cents)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PricingModel {
+    pub currency: String,
+    pub flat_fee: u64,
+    pub unit: PricingUnit,
+    pub amount_per_unit: u64,
+    pub tiers: Vec<PriceTier>,
+    pub peak_rates: Vec<PeakRate>,
+    pub utc_offset_seconds: i64,
+    // Bookings stay tentative until the owner acknowledges a deposit of at least this amount
+    pub deposit_amount: Option<u64>,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BookingPrice {
+    pub amount: u64,
+    pub currency: String,
+    pub quantity: u32,
+    pub resource_version_hash: ActionHash,
+}
+// Peak hours from the start of the week of the unix epoch, a Thursday, up to the given
+// local hour
+fn peak_hours_before(peak_rate: &PeakRate, hour: i64) -> i64 {
+    let hours_per_day = (peak_rate.end_hour as i64 - peak_rate.start_hour as i64).max(0);
+    let mut weekdays = peak_rate.weekdays.clone();
+    weekdays.sort();
+    weekdays.dedup();
+    let weeks = hour.div_euclid(7 * 24);
+    let hour_of_week = hour.rem_euclid(7 * 24);
+    let mut hours = weeks.saturating_mul(weekdays.len() as i64 * hours_per_day);
+    for weekday in weekdays {
+        let peak_start = ((weekday as i64 + 4) % 7) * 24 + peak_rate.start_hour as i64;
+        hours = hours.saturating_add((hour_of_week - peak_start).clamp(0, hours_per_day));
+    }
+    hours
+}
+// None if the time range is out of range
+pub fn compute_price(
+    pricing_model: &PricingModel,
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+    quantity: u32,
+) -> Option<u64> {
+    let unit_micros = match pricing_model.unit {
+        PricingUnit::Hour => 60 * 60 * 1_000_000,
+        PricingUnit::Day => 24 * 60 * 60 * 1_000_000,
+    };
+    let duration = end_time.as_micros().checked_sub(start_time.as_micros())?.max(0);
+    let units = (duration.saturating_add(unit_micros - 1) / unit_micros) as u64;
+    let mut tiers: Vec<&PriceTier> = pricing_model.tiers.iter().collect();
+    tiers.sort_by_key(|tier| tier.from_unit);
+    let mut time_amount: u64 = 0;
+    let mut from_unit: u64 = 0;
+    let mut amount_per_unit = pricing_model.amount_per_unit;
+    for tier in tiers {
+        let to_unit = tier.from_unit.min(units);
+        time_amount = time_amount
+            .saturating_add(amount_per_unit.saturating_mul(to_unit.saturating_sub(from_unit)));
+        from_unit = to_unit;
+        amount_per_unit = tier.amount_per_unit;
+    }
+    time_amount = time_amount
+        .saturating_add(amount_per_unit.saturating_mul(units.saturating_sub(from_unit)));
+    // Every hour that starts before the end of the booking counts
+    let first_hour = crate::local_hour(start_time, pricing_model.utc_offset_seconds)?;
+    let last_hour = crate::local_hour(
+        &Timestamp::from_micros(end_time.as_micros().checked_sub(1)?),
+        pricing_model.utc_offset_seconds,
+    )?;
+    for peak_rate in pricing_model.peak_rates.iter() {
+        let peak_hours = peak_hours_before(peak_rate, last_hour + 1)
+            .saturating_sub(peak_hours_before(peak_rate, first_hour))
+            .max(0) as u64;
+        time_amount = time_amount
+            .saturating_add(peak_rate.surcharge_per_hour.saturating_mul(peak_hours));
+    }
+    Some(pricing_model.flat_fee.saturating_add(time_amount.saturating_mul(quantity as u64)))
+}
+pub fn validate_pricing_model(
+    pricing_model: &PricingModel,
+) -> ExternResult<ValidateCallbackResult> {
+    if pricing_model.currency.is_empty() {
+        return Ok(
+            ValidateCallbackResult::Invalid(String::from("The currency can't be empty")),
+        );
+    }
+    if pricing_model.deposit_amount == Some(0) {
+        return Ok(
+            ValidateCallbackResult::Invalid(String::from("The deposit can't be zero")),
+        );
+    }
+    // Time zones range from UTC-12:00 to UTC+14:00
+    if pricing_model.utc_offset_seconds.abs() > 14 * 60 * 60 {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The UTC offset must be within 14 hours"),
+            ),
+        );
+    }
+    // Every tier, and the base price before them, must cover at least one unit
+    let mut previous_from_unit = 0;
+    for tier in pricing_model.tiers.iter() {
+        if tier.from_unit <= previous_from_unit {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Price tiers must start after the first unit and be ascending"),
+                ),
+            );
+        }
+        previous_from_unit = tier.from_unit;
+    }
+    for peak_rate in pricing_model.peak_rates.iter() {
+        if peak_rate.weekdays.iter().any(|weekday| *weekday > 6)
+            || peak_rate.start_hour >= peak_rate.end_hour || peak_rate.end_hour > 24
+        {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Peak rates must cover valid weekdays and hours"),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+// The price must have been computed with the version of the resource the booking was
+// made against
+pub fn validate_booking_price(
+    booking: &crate::Booking,
+    resource_version_hash: &ActionHash,
+    resource: &crate::Resource,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(price) = &booking.price else {
+        if resource.pricing.is_some() {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Bookings of priced resources must include their price"),
+                ),
+            );
+        }
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    if price.quantity == 0 {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The booked quantity must be at least 1"),
+            ),
+        );
+    }
+    if price.resource_version_hash.ne(resource_version_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from(
+                    "The price must be computed with the resource version of the booking",
+                ),
+            ),
+        );
+    }
+    let Some(pricing_model) = &resource.pricing else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The resource version has no pricing model"),
+            ),
+        );
+    };
+    let Some(amount) =
+        compute_price(pricing_model, &booking.start_time, &booking.end_time, price.quantity)
+    else {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The time range of the booking is out of range"),
+            ),
+        );
+    };
+    if pricing_model.currency.ne(&price.currency) || amount != price.amount {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The price doesn't match the pricing model of the resource"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
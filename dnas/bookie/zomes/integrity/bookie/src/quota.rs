@@ -0,0 +1,133 @@
+use hdi::prelude::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum QuotaPeriod {
+    Week,
+    Month,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum QuotaScope {
+    EachResource,
+    Category { category: String },
+    AllResources,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BookingQuota {
+    pub scope: QuotaScope,
+    pub period: QuotaPeriod,
+    pub max_booked_seconds: u64,
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuotaBooking {
+    pub resource_hash: ActionHash,
+    pub category: Option<String>,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+}
+pub fn quota_period_bounds(period: &QuotaPeriod, timestamp: &Timestamp) -> (Timestamp, Timestamp) {
+    match period {
+        QuotaPeriod::Week => {
+            let start = crate::start_of_week(timestamp);
+            let end = Timestamp::from_micros(
+                start.as_micros().saturating_add(7 * 24 * 60 * 60 * 1_000_000),
+            );
+            (start, end)
+        }
+        QuotaPeriod::Month => {
+            (crate::start_of_month(timestamp), crate::start_of_next_month(timestamp))
+        }
+    }
+}
+// Whether the quota applies to the given booking at all
+pub fn quota_applies(quota: &BookingQuota, booking: &QuotaBooking) -> bool {
+    match &quota.scope {
+        QuotaScope::Category { category } => booking.category.as_ref() == Some(category),
+        _ => true,
+    }
+}
+// Whether `other` consumes the same quota as `booking`
+pub fn quota_shared(quota: &BookingQuota, booking: &QuotaBooking, other: &QuotaBooking) -> bool {
+    match &quota.scope {
+        QuotaScope::EachResource => booking.resource_hash.eq(&other.resource_hash),
+        QuotaScope::Category { category } => other.category.as_ref() == Some(category),
+        QuotaScope::AllResources => true,
+    }
+}
+pub fn booked_seconds_in_period<'a>(
+    bookings: impl Iterator<Item = &'a QuotaBooking>,
+    start: &Timestamp,
+    end: &Timestamp,
+) -> u64 {
+    bookings
+        .map(|booking| {
+            let overlap_start = booking.start_time.as_micros().max(start.as_micros());
+            let overlap_end = booking.end_time.as_micros().min(end.as_micros());
+            overlap_end.saturating_sub(overlap_start).max(0) as u64 / 1_000_000
+        })
+        .sum()
+}
+// Checks every period touched by the new booking against the other bookings of the same booker
+pub fn check_quotas(
+    quotas: &[BookingQuota],
+    booking: &QuotaBooking,
+    other_bookings: &[QuotaBooking],
+) -> Result<(), String> {
+    for quota in quotas.iter().filter(|quota| quota_applies(quota, booking)) {
+        let mut period_start = quota_period_bounds(&quota.period, &booking.start_time).0;
+        while period_start < booking.end_time {
+            let (start, end) = quota_period_bounds(&quota.period, &period_start);
+            let booked_seconds = booked_seconds_in_period(
+                other_bookings
+                    .iter()
+                    .filter(|other| quota_shared(quota, booking, other))
+                    .chain(std::iter::once(booking)),
+                &start,
+                &end,
+            );
+            if booked_seconds > quota.max_booked_seconds {
+                return Err(
+                    format!(
+                        "The booking exceeds the quota of {} hours per {:?}",
+                        quota.max_booked_seconds / 3600, quota.period
+                    ),
+                );
+            }
+            period_start = end;
+        }
+    }
+    Ok(())
+}
+// Integrity can only see the author's own chain, so this enforces the quotas over the
+// bookings that the same agent has linked to the booker before. The coordinator enforces
+// them over all the bookings of the booker.
+pub fn validate_quotas(
+    booking: &crate::Booking,
+    linked_bookings: &[crate::Booking],
+) -> ExternResult<ValidateCallbackResult> {
+    let quotas = crate::booking_policy()?.quotas;
+    if quotas.is_empty() {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let mut other_bookings = Vec::new();
+    for other in linked_bookings {
+        other_bookings.push(quota_booking(other)?);
+    }
+    match check_quotas(&quotas, &quota_booking(booking)?, &other_bookings) {
+        Ok(()) => Ok(ValidateCallbackResult::Valid),
+        Err(error) => Ok(ValidateCallbackResult::Invalid(error)),
+    }
+}
+fn quota_booking(booking: &crate::Booking) -> ExternResult<QuotaBooking> {
+    let resource_record = must_get_valid_record(booking.resource_hash.clone())?;
+    let resource: Option<crate::Resource> = resource_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(QuotaBooking {
+        resource_hash: booking.resource_hash.clone(),
+        category: resource.and_then(|resource| resource.category),
+        start_time: booking.start_time,
+        end_time: booking.end_time,
+    })
+}
@@ -0,0 +1,147 @@
+use hdi::prelude::*;
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct RescheduleProposal {
+    pub booking_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub counter_to: Option<ActionHash>,
+}
+pub fn validate_create_reschedule_proposal(
+    action: EntryCreationAction,
+    reschedule_proposal: RescheduleProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(reschedule_proposal.booking_hash.clone())?;
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let resource_record = must_get_valid_record(booking.resource_hash.clone())?;
+    let owner = resource_record.action().author();
+    if action.author().ne(owner) && !booking.bookers.contains(action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from(
+                    "Only the bookers or the owner of the resource can reschedule a booking",
+                ),
+            ),
+        );
+    }
+    let policy = crate::booking_policy_for_booking(&booking)?;
+    let result = crate::validate_booking_time_range(
+        &action,
+        &reschedule_proposal.start_time,
+        &reschedule_proposal.end_time,
+        &policy,
+    )?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    if let Some(counter_to) = reschedule_proposal.counter_to {
+        let countered_record = must_get_valid_record(counter_to)?;
+        let countered_proposal: crate::RescheduleProposal = countered_record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                ),
+            )?;
+        if countered_proposal.booking_hash.ne(&reschedule_proposal.booking_hash) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("Counter-proposals must be for the same booking"),
+                ),
+            );
+        }
+        if action.author().eq(owner) == countered_record.action().author().eq(owner) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from(
+                        "Only the other side of the booking can counter a proposal",
+                    ),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_reschedule_proposal(
+    _action: Update,
+    _reschedule_proposal: RescheduleProposal,
+    _original_action: EntryCreationAction,
+    _original_reschedule_proposal: RescheduleProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Reschedule Proposals cannot be updated"),
+        ),
+    )
+}
+pub fn validate_delete_reschedule_proposal(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_reschedule_proposal: RescheduleProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("Reschedule Proposals cannot be deleted"),
+        ),
+    )
+}
+pub fn validate_create_link_booking_to_reschedule_proposals(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(base_address.clone());
+    let record = must_get_valid_record(action_hash)?;
+    let _booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let reschedule_proposal: crate::RescheduleProposal = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(reschedule_proposal.booking_hash).ne(&base_address) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The proposal must belong to the linked booking"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_booking_to_reschedule_proposals(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("BookingToRescheduleProposals links cannot be deleted"),
+        ),
+    )
+}
@@ -1,23 +1,119 @@
+use hc_zome_file_storage_integrity::FileMetadata;
 use hdi::prelude::*;
+use std::collections::BTreeMap;
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct Resource {
     pub name: String,
     pub description: String,
-    pub image_hash: EntryHash,
+    // Files stored with the file_storage zome, as are the gallery and the documents
+    pub image_hash: Option<EntryHash>,
+    pub booking_policy: Option<crate::ResourceBookingPolicy>,
+    pub pricing: Option<crate::PricingModel>,
+    pub category: Option<String>,
+    // Maximum number of bookers of a single booking
+    pub capacity: Option<u32>,
+    pub location: Option<crate::Location>,
+    // Schema of the custom attributes of the category of the resource
+    pub attribute_schema_hash: Option<ActionHash>,
+    #[serde(default)]
+    pub attributes: BTreeMap<String, crate::AttributeValue>,
+    #[serde(default)]
+    pub gallery: Vec<EntryHash>,
+    // Manuals, house rules and other documents
+    #[serde(default)]
+    pub documents: Vec<EntryHash>,
 }
 pub fn validate_create_resource(
     _action: EntryCreationAction,
-    _resource: Resource,
+    resource: Resource,
 ) -> ExternResult<ValidateCallbackResult> {
+    if let Some(booking_policy) = &resource.booking_policy {
+        let result = crate::validate_resource_booking_policy(booking_policy)?;
+        if !matches!(result, ValidateCallbackResult::Valid) {
+            return Ok(result);
+        }
+    }
+    if resource.capacity == Some(0) {
+        return Ok(
+            ValidateCallbackResult::Invalid(String::from("The capacity can't be zero")),
+        );
+    }
+    if let Some(location) = &resource.location {
+        let result = crate::validate_location(location)?;
+        if !matches!(result, ValidateCallbackResult::Valid) {
+            return Ok(result);
+        }
+    }
+    for file_hash in resource
+        .image_hash
+        .iter()
+        .chain(resource.gallery.iter())
+        .chain(resource.documents.iter())
+    {
+        if !is_file(file_hash)? {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The images and documents of a resource must be stored files"),
+                ),
+            );
+        }
+    }
+    let result = crate::validate_resource_attributes(
+        &resource.category,
+        &resource.attribute_schema_hash,
+        &resource.attributes,
+    )?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    if let Some(pricing) = &resource.pricing {
+        return crate::validate_pricing_model(pricing);
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+// Files uploaded with the file_storage zome are referenced by the hash of their metadata
+pub fn is_file(file_hash: &EntryHash) -> ExternResult<bool> {
+    let entry = must_get_entry(file_hash.clone())?;
+    match entry.as_content() {
+        Entry::App(bytes) => Ok(FileMetadata::try_from(bytes.clone().into_sb()).is_ok()),
+        _ => Ok(false),
+    }
+}
+// The record of a version of the resource, if it was made by its owner and can be reached
+// from the original resource through its updates
+pub fn must_get_resource_version(
+    resource_hash: &ActionHash,
+    resource_version_hash: &ActionHash,
+) -> ExternResult<Option<Record>> {
+    let version_record = must_get_valid_record(resource_version_hash.clone())?;
+    let mut action_hash = resource_version_hash.clone();
+    let mut action = version_record.action().clone();
+    while action_hash.ne(resource_hash) {
+        let Action::Update(update) = action else {
+            return Ok(None);
+        };
+        action_hash = update.original_action_address.clone();
+        action = must_get_valid_record(action_hash.clone())?.action().clone();
+    }
+    if action.author().ne(version_record.action().author()) {
+        return Ok(None);
+    }
+    Ok(Some(version_record))
+}
 pub fn validate_update_resource(
-    _action: Update,
+    action: Update,
     _resource: Resource,
-    _original_action: EntryCreationAction,
+    original_action: EntryCreationAction,
     _original_resource: Resource,
 ) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of a resource can update it"),
+            ),
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_resource(
@@ -28,7 +124,7 @@ pub fn validate_delete_resource(
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_create_link_resource_updates(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
@@ -44,6 +140,13 @@ pub fn validate_create_link_resource_updates(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
+    if action.author.ne(record.action().author()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of a resource can link its updates"),
+            ),
+        );
+    }
     let action_hash = ActionHash::from(target_address);
     let record = must_get_valid_record(action_hash)?;
     let _resource: crate::Resource = record
@@ -0,0 +1,93 @@
+use hdi::prelude::*;
+// Both resources must exist, be different and belong to the author of the link
+fn validate_resource_pair(
+    action: &CreateLink,
+    parent_resource_hash: ActionHash,
+    child_resource_hash: ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+    if parent_resource_hash.eq(&child_resource_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("A resource can't contain itself"),
+            ),
+        );
+    }
+    for resource_hash in [parent_resource_hash, child_resource_hash] {
+        let record = must_get_valid_record(resource_hash)?;
+        let _resource: crate::Resource = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(
+                wasm_error!(
+                    WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                ),
+            )?;
+        if record.action().author().ne(&action.author) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from(
+                        "Only the owner of both resources can nest one inside the other",
+                    ),
+                ),
+            );
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_parent_to_child_resources(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_resource_pair(
+        &action,
+        ActionHash::from(base_address),
+        ActionHash::from(target_address),
+    )
+}
+pub fn validate_delete_link_parent_to_child_resources(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of the resources can delete ParentToChildResources links"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_child_to_parent_resources(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_resource_pair(
+        &action,
+        ActionHash::from(target_address),
+        ActionHash::from(base_address),
+    )
+}
+pub fn validate_delete_link_child_to_parent_resources(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("Only the owner of the resources can delete ChildToParentResources links"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,86 @@
+use hdi::prelude::*;
+// Durations come from DNA properties and resource policies, too long ones are rejected
+// instead of wrapping around
+pub fn seconds_to_micros(seconds: u64) -> ExternResult<i64> {
+    i64::try_from(seconds)
+        .ok()
+        .and_then(|seconds| seconds.checked_mul(1_000_000))
+        .ok_or(wasm_error!(WasmErrorInner::Guest(format!(
+            "The duration of {} seconds is out of range",
+            seconds
+        ))))
+}
+pub fn time_ranges_overlap(
+    start_a: &Timestamp,
+    end_a: &Timestamp,
+    start_b: &Timestamp,
+    end_b: &Timestamp,
+) -> bool {
+    start_a < end_b && start_b < end_a
+}
+const MICROS_PER_HOUR: i64 = 3_600_000_000;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+// None if the local time is out of range
+fn local_micros(timestamp: &Timestamp, utc_offset_seconds: i64) -> Option<i64> {
+    timestamp
+        .as_micros()
+        .checked_add(utc_offset_seconds.checked_mul(1_000_000)?)
+}
+// Day of the week of the given day since the unix epoch, from 0 (Monday) to 6 (Sunday)
+fn weekday_of_day(days: i64) -> u8 {
+    // The unix epoch was a Thursday
+    (days + 3).rem_euclid(7) as u8
+}
+pub fn weekday(timestamp: &Timestamp, utc_offset_seconds: i64) -> Option<u8> {
+    let days = local_micros(timestamp, utc_offset_seconds)?.div_euclid(MICROS_PER_DAY);
+    Some(weekday_of_day(days))
+}
+pub fn hour_of_day(timestamp: &Timestamp, utc_offset_seconds: i64) -> Option<u8> {
+    Some(
+        local_micros(timestamp, utc_offset_seconds)?
+            .rem_euclid(MICROS_PER_DAY)
+            .div_euclid(MICROS_PER_HOUR) as u8,
+    )
+}
+// Number of local hours between the unix epoch and the start of the hour of the timestamp
+pub fn local_hour(timestamp: &Timestamp, utc_offset_seconds: i64) -> Option<i64> {
+    Some(local_micros(timestamp, utc_offset_seconds)?.div_euclid(MICROS_PER_HOUR))
+}
+// Conversions between days since the unix epoch and (year, month, day) in the proleptic
+// Gregorian calendar
+pub fn days_to_civil(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+pub fn civil_to_days(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+// Weeks start on Monday and months on their first day, both in UTC
+pub fn start_of_week(timestamp: &Timestamp) -> Timestamp {
+    let days = timestamp.as_micros().div_euclid(MICROS_PER_DAY);
+    let monday = days - weekday_of_day(days) as i64;
+    Timestamp::from_micros(monday * MICROS_PER_DAY)
+}
+pub fn start_of_month(timestamp: &Timestamp) -> Timestamp {
+    let (year, month, _) = days_to_civil(timestamp.as_micros().div_euclid(MICROS_PER_DAY));
+    Timestamp::from_micros(civil_to_days(year, month, 1) * MICROS_PER_DAY)
+}
+pub fn start_of_next_month(timestamp: &Timestamp) -> Timestamp {
+    let (year, month, _) = days_to_civil(timestamp.as_micros().div_euclid(MICROS_PER_DAY));
+    let (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    Timestamp::from_micros(civil_to_days(year, month, 1) * MICROS_PER_DAY)
+}
@@ -0,0 +1,188 @@
+use hdi::prelude::*;
+// Check-ins are recorded when the resource is picked up, and check-outs when it's returned
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum UsageEventType {
+    CheckIn,
+    CheckOut,
+}
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct UsageEvent {
+    pub booking_hash: ActionHash,
+    pub event_type: UsageEventType,
+    // The check-in a check-out closes, so that its odometer reading can be checked against it
+    pub check_in_hash: Option<ActionHash>,
+    pub odometer: Option<u64>,
+    pub condition_note: Option<String>,
+    pub photo_hash: Option<EntryHash>,
+}
+pub fn validate_create_usage_event(
+    action: EntryCreationAction,
+    usage_event: UsageEvent,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(usage_event.booking_hash.clone())?;
+    let booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+            ),
+        )?;
+    let resource_record = must_get_valid_record(booking.resource_hash.clone())?;
+    if action.author().ne(resource_record.action().author())
+        && !booking.bookers.contains(action.author())
+        && !crate::accepted_invitation_in_author_chain(
+            action.author(),
+            action.prev_action(),
+            &usage_event.booking_hash,
+        )?
+    {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from(
+                    "Only the participants or the owner of the resource can check a booking in or out",
+                ),
+            ),
+        );
+    }
+    if let Some(photo_hash) = &usage_event.photo_hash {
+        if !crate::is_file(photo_hash)? {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    String::from("The photo of a usage event must be a stored file"),
+                ),
+            );
+        }
+    }
+    match usage_event.event_type {
+        UsageEventType::CheckIn => {
+            if usage_event.check_in_hash.is_some() {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from("A check-in can't reference another check-in"),
+                    ),
+                );
+            }
+        }
+        UsageEventType::CheckOut => {
+            let Some(check_in_hash) = usage_event.check_in_hash.clone() else {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from("A check-out must reference the check-in of the booking"),
+                    ),
+                );
+            };
+            let record = must_get_valid_record(check_in_hash)?;
+            let check_in: crate::UsageEvent = record
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?
+                .ok_or(
+                    wasm_error!(
+                        WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                    ),
+                )?;
+            if check_in.event_type != UsageEventType::CheckIn
+                || check_in.booking_hash.ne(&usage_event.booking_hash)
+            {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        String::from("A check-out must reference the check-in of the booking"),
+                    ),
+                );
+            }
+            if let Some(check_in_odometer) = check_in.odometer {
+                match usage_event.odometer {
+                    Some(odometer) if odometer >= check_in_odometer => {}
+                    Some(_) => {
+                        return Ok(
+                            ValidateCallbackResult::Invalid(
+                                String::from(
+                                    "The odometer reading can't be lower than the one at check-in",
+                                ),
+                            ),
+                        );
+                    }
+                    None => {
+                        return Ok(
+                            ValidateCallbackResult::Invalid(
+                                String::from(
+                                    "The odometer must be read at check-out if it was read at check-in",
+                                ),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_usage_event(
+    _action: Update,
+    _usage_event: UsageEvent,
+    _original_action: EntryCreationAction,
+    _original_usage_event: UsageEvent,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Usage Events cannot be updated")))
+}
+pub fn validate_delete_usage_event(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_usage_event: UsageEvent,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("Usage Events cannot be deleted")))
+}
+pub fn validate_create_link_booking_to_usage_events(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(base_address.clone());
+    let record = must_get_valid_record(action_hash)?;
+    let _booking: crate::Booking = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let usage_event: crate::UsageEvent = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if AnyLinkableHash::from(usage_event.booking_hash).ne(&base_address) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                String::from("The usage event must belong to the linked booking"),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_booking_to_usage_events(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("BookingToUsageEvents links cannot be deleted"),
+        ),
+    )
+}
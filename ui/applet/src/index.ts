@@ -1,18 +1,27 @@
 import '@darksoil/bookie/dist/elements/booking-detail.js';
 import '@darksoil/bookie/dist/elements/booking-request-detail.js';
 import '@darksoil/bookie/dist/elements/resource-detail.js';
+import '@darksoil/bookie/dist/elements/condition-report-detail.js';
 import '@darksoil/bookie/dist/elements/all-resources.js';
 import '@darksoil/bookie/dist/elements/all-resources-calendar.js';
 import '@darksoil/bookie/dist/elements/bookie-context.js';
 
 import { BookieStore, BookieClient, RequestStatus } from '@darksoil/bookie';
 
-import { AppAgentClient, CellType, EntryHash } from '@holochain/client';
+import {
+  ActionHash,
+  AppAgentClient,
+  CellType,
+  DnaHash,
+  EntryHash,
+  encodeHashToBase64,
+} from '@holochain/client';
 import { html, render, TemplateResult } from 'lit';
 
 import { ProfilesClient, ProfilesStore } from '@holochain-open-dev/profiles';
 import { FileStorageClient } from '@holochain-open-dev/file-storage';
 import '@holochain-open-dev/file-storage/dist/elements/file-storage-context.js';
+import '@shoelace-style/shoelace/dist/components/button/button.js';
 
 import '@holochain-open-dev/profiles/dist/elements/profiles-context.js';
 import '@lightningrodlabs/we-applet/dist/elements/we-services-context.js';
@@ -36,6 +45,8 @@ import {
   mdiCalendarQuestion,
   mdiCalendarRemove,
   mdiCalendarWeekend,
+  mdiAlertCircle,
+  mdiFileDocument,
   mdiTableClock,
   mdiTimetable,
 } from '@mdi/js';
@@ -51,6 +62,38 @@ import './applet-main';
 import './cross-applet-main';
 import { msg } from '@lit/localize';
 
+async function bookieDnaHash(client: AppAgentClient): Promise<DnaHash> {
+  const appInfo = await client.appInfo();
+  return (appInfo.cell_info.bookie[0] as any)[CellType.Provisioned].cell_id[0];
+}
+
+// The documents of a resource are attached to it, so that they show up with the
+// rest of its attachments
+async function attachResourceDocuments(
+  client: AppAgentClient,
+  attachmentsClient: AttachmentsClient,
+  resourceHash: ActionHash
+) {
+  const bookieClient = new BookieClient(client, 'bookie');
+  const resource = await bookieClient.getResource(resourceHash);
+  if (!resource) return;
+
+  const dnaHash = await bookieDnaHash(client);
+  const attachments = await attachmentsClient.getAttachments(resourceHash);
+  const attachedHashes = attachments.map(attachment =>
+    encodeHashToBase64(attachment.hrl[1])
+  );
+
+  for (const documentHash of resource.entry.documents ?? []) {
+    if (attachedHashes.includes(encodeHashToBase64(documentHash))) continue;
+
+    await attachmentsClient.addAttachment(resourceHash, {
+      hrl: [dnaHash, documentHash],
+      context: {},
+    });
+  }
+}
+
 function wrapAppletView(
   client: AppAgentClient,
   profilesClient: ProfilesClient,
@@ -59,9 +102,22 @@ function wrapAppletView(
 ): TemplateResult {
   const bookieStore = new BookieStore(new BookieClient(client, 'bookie'));
   const fileStorageClient = new FileStorageClient(client, 'bookie');
+  const attachmentsClient = new AttachmentsClient(client, 'bookie');
   return html`
     <attachments-context
-      .store=${new AttachmentsStore(new AttachmentsClient(client, 'bookie'))}
+      .store=${new AttachmentsStore(attachmentsClient)}
+      @resource-created=${(e: CustomEvent) =>
+        attachResourceDocuments(
+          client,
+          attachmentsClient,
+          e.detail.resourceHash
+        )}
+      @resource-updated=${(e: CustomEvent) =>
+        attachResourceDocuments(
+          client,
+          attachmentsClient,
+          e.detail.originalResourceHash
+        )}
     >
       <we-services-context .services=${weServices}>
         <profiles-context .store=${new ProfilesStore(profilesClient)}>
@@ -260,6 +316,80 @@ async function appletViews(
                 element
               ),
           },
+
+          condition_report: {
+            info: async (hrl: Hrl) => {
+              const bookieClient = new BookieClient(client, 'bookie');
+              const conditionReport = await bookieClient.getConditionReport(
+                hrl[1]
+              );
+              if (!conditionReport) return undefined;
+
+              return {
+                name: conditionReport.entry.description,
+                icon_src: wrapPathInSvg(mdiAlertCircle),
+              };
+            },
+            view: (element: HTMLElement, hrl: Hrl, context: any) =>
+              render(
+                wrapAppletView(
+                  client,
+                  profilesClient,
+                  weServices,
+                  html`
+                    <condition-report-detail
+                      .conditionReportHash=${hrl[1]}
+                      style="flex: 1"
+                    >
+                      <attachments-card
+                        slot="attachments"
+                        .hash=${hrl[1]}
+                      ></attachments-card>
+                    </condition-report-detail>
+                  `
+                ),
+                element
+              ),
+          },
+        },
+        file_storage_integrity: {
+          file_metadata: {
+            info: async (hrl: Hrl) => {
+              const fileStorageClient = new FileStorageClient(client, 'bookie');
+              const fileMetadata = await fileStorageClient.getFileMetadata(
+                hrl[1]
+              );
+
+              return {
+                name: fileMetadata.name,
+                icon_src: wrapPathInSvg(mdiFileDocument),
+              };
+            },
+            view: (element: HTMLElement, hrl: Hrl, context: any) =>
+              render(
+                wrapAppletView(
+                  client,
+                  profilesClient,
+                  weServices,
+                  html`
+                    <sl-button
+                      @click=${async () => {
+                        const fileStorageClient = new FileStorageClient(
+                          client,
+                          'bookie'
+                        );
+                        const file = await fileStorageClient.downloadFile(
+                          hrl[1]
+                        );
+                        window.open(URL.createObjectURL(file));
+                      }}
+                      >${msg('Download')}</sl-button
+                    >
+                  `
+                ),
+                element
+              ),
+          },
         },
       },
     },
@@ -4,11 +4,66 @@ import { BookingRequest } from './types';
 
 import { Resource } from './types';
 
+import { BookingPolicy } from './types';
+
+import { RequestBookingOutput } from './types';
+
+import { Hold } from './types';
+
+import { BookingCancellation } from './types';
+
+import { RescheduleProposal } from './types';
+
+import { UsageEvent } from './types';
+
+import { BookingUsage } from './types';
+
+import { ConditionReport } from './types';
+
+import { ResourceStatus } from './types';
+
+import { MaintenanceTask } from './types';
+
+import { MaintenanceWindow } from './types';
+
+import { UsageReport } from './types';
+
+import { BookingPrice } from './types';
+
+import { CreditTransfer } from './types';
+
+import { Invoice } from './types';
+
+import { InvoiceStatus } from './types';
+
+import { PaymentAcknowledgement } from './types';
+
+import { BookingPayment } from './types';
+
+import { QuotaUsage } from './types';
+
+import { AllocateSlotOutput } from './types';
+
+import { BookingInvitation } from './types';
+
+import { BookingInvitationResponse } from './types';
+
+import { BookingBundle } from './types';
+
+import { BookingBundleStatus } from './types';
+
+import { RequestBookingBundleOutput } from './types';
+
+import { AttributeSchema } from './types';
+
+import { AttributeFilter } from './types';
+
 import {
   AppAgentClient,
   Record,
   ActionHash,
   AgentPubKey,
+  EntryHash,
   SignedActionHashed,
 } from '@holochain/client';
 import { RecordDetails } from '@holochain-open-dev/core-types';
@@ -70,6 +125,7 @@ export class BookieClient extends ZomeClient<BookieSignal> {
     | {
         bookingRequest: EntryRecord<BookingRequest>;
         deletes: Array<SignedActionHashed>;
+        expired: boolean;
       }
     | undefined
   > {
@@ -82,6 +138,30 @@ export class BookieClient extends ZomeClient<BookieSignal> {
     return {
       bookingRequest: new EntryRecord(result.booking_request),
       deletes: result.deletes,
+      expired: result.expired,
+    };
+  }
+
+  clearExpiredBookingRequests(
+    resourceHash: ActionHash
+  ): Promise<Array<ActionHash>> {
+    return this.callZome('clear_expired_booking_requests', resourceHash);
+  }
+
+  async requestBooking(bookingRequest: BookingRequest): Promise<
+    | { type: 'Accepted'; booking: EntryRecord<Booking> }
+    | { type: 'Pending'; bookingRequest: EntryRecord<BookingRequest> }
+  > {
+    const result: RequestBookingOutput = await this.callZome(
+      'request_booking',
+      bookingRequest
+    );
+    if (result.type === 'Accepted') {
+      return { type: 'Accepted', booking: new EntryRecord(result.booking) };
+    }
+    return {
+      type: 'Pending',
+      bookingRequest: new EntryRecord(result.booking_request),
     };
   }
 
@@ -117,6 +197,54 @@ export class BookieClient extends ZomeClient<BookieSignal> {
     );
     return records.map(r => new EntryRecord(r));
   }
+  /** Waitlist */
+
+  async joinWaitlist(
+    bookingRequest: BookingRequest
+  ): Promise<EntryRecord<BookingRequest>> {
+    const record: Record = await this.callZome('join_waitlist', bookingRequest);
+    return new EntryRecord(record);
+  }
+
+  async getWaitlistForResource(
+    resourceHash: ActionHash
+  ): Promise<Array<EntryRecord<BookingRequest>>> {
+    const records: Record[] = await this.callZome(
+      'get_waitlist_for_resource',
+      resourceHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  /** Hold */
+
+  async createHold(
+    resourceHash: ActionHash,
+    startTime: number,
+    endTime: number
+  ): Promise<EntryRecord<Hold>> {
+    const record: Record = await this.callZome('create_hold', {
+      resource_hash: resourceHash,
+      start_time: startTime,
+      end_time: endTime,
+    });
+    return new EntryRecord(record);
+  }
+
+  releaseHold(holdHash: ActionHash): Promise<void> {
+    return this.callZome('release_hold', holdHash);
+  }
+
+  async getActiveHoldsForResource(
+    resourceHash: ActionHash
+  ): Promise<Array<EntryRecord<Hold>>> {
+    const records: Record[] = await this.callZome(
+      'get_active_holds_for_resource',
+      resourceHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
   /** Booking */
 
   async createBooking(booking: Booking): Promise<EntryRecord<Booking>> {
@@ -124,6 +252,21 @@ export class BookieClient extends ZomeClient<BookieSignal> {
     return new EntryRecord(record);
   }
 
+  async createInstantBooking(
+    resourceHash: ActionHash,
+    title: string,
+    startTime: number,
+    endTime: number
+  ): Promise<EntryRecord<Booking>> {
+    const record: Record = await this.callZome('create_instant_booking', {
+      resource_hash: resourceHash,
+      title,
+      start_time: startTime,
+      end_time: endTime,
+    });
+    return new EntryRecord(record);
+  }
+
   async getBooking(
     bookingHash: ActionHash
   ): Promise<EntryRecord<Booking> | undefined> {
@@ -131,21 +274,64 @@ export class BookieClient extends ZomeClient<BookieSignal> {
     return record ? new EntryRecord(record) : undefined;
   }
 
-  deleteBooking(originalBookingHash: ActionHash): Promise<ActionHash> {
-    return this.callZome('delete_booking', originalBookingHash);
+  async cancelBooking(
+    bookingHash: ActionHash,
+    reason: string,
+    bookingVersionHash?: ActionHash
+  ): Promise<EntryRecord<BookingCancellation>> {
+    const record: Record = await this.callZome('cancel_booking', {
+      booking_hash: bookingHash,
+      booking_version_hash: bookingVersionHash,
+      reason,
+    });
+    return new EntryRecord(record);
   }
 
-  async updateBooking(
-    previousBookingHash: ActionHash,
-    updatedBooking: Booking
-  ): Promise<EntryRecord<Booking>> {
-    const record: Record = await this.callZome('update_booking', {
-      previous_booking_hash: previousBookingHash,
-      updated_booking: updatedBooking,
+  async getCancellationForBooking(
+    bookingHash: ActionHash
+  ): Promise<EntryRecord<BookingCancellation> | undefined> {
+    const record: Record = await this.callZome(
+      'get_cancellation_for_booking',
+      bookingHash
+    );
+    return record ? new EntryRecord(record) : undefined;
+  }
+
+  async proposeReschedule(
+    bookingHash: ActionHash,
+    startTime: number,
+    endTime: number,
+    counterTo?: ActionHash
+  ): Promise<EntryRecord<RescheduleProposal>> {
+    const record: Record = await this.callZome('propose_reschedule', {
+      booking_hash: bookingHash,
+      start_time: startTime,
+      end_time: endTime,
+      counter_to: counterTo,
     });
     return new EntryRecord(record);
   }
 
+  async acceptRescheduleProposal(
+    rescheduleProposalHash: ActionHash
+  ): Promise<EntryRecord<Booking>> {
+    const record: Record = await this.callZome(
+      'accept_reschedule_proposal',
+      rescheduleProposalHash
+    );
+    return new EntryRecord(record);
+  }
+
+  async getRescheduleProposalsForBooking(
+    bookingHash: ActionHash
+  ): Promise<Array<EntryRecord<RescheduleProposal>>> {
+    const records: Record[] = await this.callZome(
+      'get_reschedule_proposals_for_booking',
+      bookingHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
   async getBookingsForBookingRequest(
     bookingRequestHash: ActionHash
   ): Promise<Array<EntryRecord<Booking>>> {
@@ -166,6 +352,558 @@ export class BookieClient extends ZomeClient<BookieSignal> {
     return records.map(r => new EntryRecord(r));
   }
 
+  /** Usage */
+
+  async checkIn(
+    bookingHash: ActionHash,
+    odometer?: number,
+    conditionNote?: string,
+    photoHash?: EntryHash
+  ): Promise<EntryRecord<UsageEvent>> {
+    const record: Record = await this.callZome('check_in', {
+      booking_hash: bookingHash,
+      odometer,
+      condition_note: conditionNote,
+      photo_hash: photoHash,
+    });
+    return new EntryRecord(record);
+  }
+
+  async checkOut(
+    bookingHash: ActionHash,
+    odometer?: number,
+    conditionNote?: string,
+    photoHash?: EntryHash
+  ): Promise<EntryRecord<UsageEvent>> {
+    const record: Record = await this.callZome('check_out', {
+      booking_hash: bookingHash,
+      odometer,
+      condition_note: conditionNote,
+      photo_hash: photoHash,
+    });
+    return new EntryRecord(record);
+  }
+
+  getBookingUsage(bookingHash: ActionHash): Promise<BookingUsage> {
+    return this.callZome('get_booking_usage', bookingHash);
+  }
+
+  async getOverdueBookings(
+    resourceHash: ActionHash
+  ): Promise<Array<EntryRecord<Booking>>> {
+    const records: Record[] = await this.callZome(
+      'get_overdue_bookings',
+      resourceHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  getResourceUsageReport(
+    resourceHash: ActionHash,
+    startTime: number,
+    endTime: number
+  ): Promise<UsageReport> {
+    return this.callZome('get_resource_usage_report', {
+      resource_hash: resourceHash,
+      start_time: startTime,
+      end_time: endTime,
+    });
+  }
+
+  getBookerUsageReport(
+    booker: AgentPubKey,
+    startTime: number,
+    endTime: number
+  ): Promise<UsageReport> {
+    return this.callZome('get_booker_usage_report', {
+      booker,
+      start_time: startTime,
+      end_time: endTime,
+    });
+  }
+
+  renderUsageReportCsv(report: UsageReport): Promise<string> {
+    return this.callZome('render_usage_report_csv', report);
+  }
+
+  /** Condition Report */
+
+  async createConditionReport(
+    conditionReport: ConditionReport
+  ): Promise<EntryRecord<ConditionReport>> {
+    const record: Record = await this.callZome(
+      'create_condition_report',
+      conditionReport
+    );
+    return new EntryRecord(record);
+  }
+
+  async getConditionReport(
+    originalConditionReportHash: ActionHash
+  ): Promise<EntryRecord<ConditionReport> | undefined> {
+    const record: Record = await this.callZome(
+      'get_condition_report',
+      originalConditionReportHash
+    );
+    return record ? new EntryRecord(record) : undefined;
+  }
+
+  async resolveConditionReport(
+    originalConditionReportHash: ActionHash
+  ): Promise<EntryRecord<ConditionReport>> {
+    const record: Record = await this.callZome(
+      'resolve_condition_report',
+      originalConditionReportHash
+    );
+    return new EntryRecord(record);
+  }
+
+  async getConditionReportsForResource(
+    resourceHash: ActionHash
+  ): Promise<Array<EntryRecord<ConditionReport>>> {
+    const records: Record[] = await this.callZome(
+      'get_condition_reports_for_resource',
+      resourceHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  getResourceStatus(resourceHash: ActionHash): Promise<ResourceStatus> {
+    return this.callZome('get_resource_status', resourceHash);
+  }
+
+  /** Maintenance */
+
+  async createMaintenanceTask(
+    maintenanceTask: MaintenanceTask
+  ): Promise<EntryRecord<MaintenanceTask>> {
+    const record: Record = await this.callZome(
+      'create_maintenance_task',
+      maintenanceTask
+    );
+    return new EntryRecord(record);
+  }
+
+  deleteMaintenanceTask(maintenanceTaskHash: ActionHash): Promise<ActionHash> {
+    return this.callZome('delete_maintenance_task', maintenanceTaskHash);
+  }
+
+  async getMaintenanceTasksForResource(
+    resourceHash: ActionHash
+  ): Promise<Array<EntryRecord<MaintenanceTask>>> {
+    const records: Record[] = await this.callZome(
+      'get_maintenance_tasks_for_resource',
+      resourceHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  async createMaintenanceWindow(
+    maintenanceWindow: MaintenanceWindow
+  ): Promise<EntryRecord<MaintenanceWindow>> {
+    const record: Record = await this.callZome(
+      'create_maintenance_window',
+      maintenanceWindow
+    );
+    return new EntryRecord(record);
+  }
+
+  deleteMaintenanceWindow(
+    maintenanceWindowHash: ActionHash
+  ): Promise<ActionHash> {
+    return this.callZome('delete_maintenance_window', maintenanceWindowHash);
+  }
+
+  async getUpcomingMaintenance(
+    resourceHash: ActionHash
+  ): Promise<Array<EntryRecord<MaintenanceWindow>>> {
+    const records: Record[] = await this.callZome(
+      'get_upcoming_maintenance',
+      resourceHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  async scheduleMaintenance(
+    resourceHash: ActionHash
+  ): Promise<Array<EntryRecord<MaintenanceWindow>>> {
+    const records: Record[] = await this.callZome(
+      'schedule_maintenance',
+      resourceHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  /** Pricing */
+
+  quoteBooking(
+    resourceHash: ActionHash,
+    startTime: number,
+    endTime: number,
+    quantity?: number
+  ): Promise<BookingPrice | undefined> {
+    return this.callZome('quote_booking', {
+      resource_hash: resourceHash,
+      start_time: startTime,
+      end_time: endTime,
+      quantity,
+    });
+  }
+
+  /** Booking Invitations */
+
+  async inviteToBooking(
+    bookingHash: ActionHash,
+    invitee: AgentPubKey
+  ): Promise<EntryRecord<BookingInvitation>> {
+    const record: Record = await this.callZome('invite_to_booking', {
+      booking_hash: bookingHash,
+      invitee,
+    });
+    return new EntryRecord(record);
+  }
+
+  async respondToBookingInvitation(
+    bookingInvitationHash: ActionHash,
+    accept: boolean
+  ): Promise<EntryRecord<BookingInvitationResponse>> {
+    const record: Record = await this.callZome(
+      'respond_to_booking_invitation',
+      {
+        booking_invitation_hash: bookingInvitationHash,
+        accept,
+      }
+    );
+    return new EntryRecord(record);
+  }
+
+  withdrawBookingInvitation(bookingInvitationHash: ActionHash): Promise<void> {
+    return this.callZome('withdraw_booking_invitation', bookingInvitationHash);
+  }
+
+  async getInvitationsForBooking(
+    bookingHash: ActionHash
+  ): Promise<Array<EntryRecord<BookingInvitation>>> {
+    const records: Record[] = await this.callZome(
+      'get_invitations_for_booking',
+      bookingHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  async getMyPendingBookingInvitations(): Promise<
+    Array<EntryRecord<BookingInvitation>>
+  > {
+    const records: Record[] = await this.callZome(
+      'get_my_pending_booking_invitations',
+      null
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  getBookingParticipants(bookingHash: ActionHash): Promise<Array<AgentPubKey>> {
+    return this.callZome('get_booking_participants', bookingHash);
+  }
+
+  /** Booking Bundles */
+
+  async requestBookingBundle(bookingBundle: BookingBundle): Promise<{
+    bookingBundle: EntryRecord<BookingBundle>;
+    bookingRequests: Array<EntryRecord<BookingRequest>>;
+  }> {
+    const output: RequestBookingBundleOutput = await this.callZome(
+      'request_booking_bundle',
+      bookingBundle
+    );
+    return {
+      bookingBundle: new EntryRecord(output.booking_bundle),
+      bookingRequests: output.booking_requests.map(r => new EntryRecord(r)),
+    };
+  }
+
+  async getBookingBundle(
+    bundleHash: ActionHash
+  ): Promise<EntryRecord<BookingBundle> | undefined> {
+    const record: Record = await this.callZome('get_booking_bundle', bundleHash);
+    return record ? new EntryRecord(record) : undefined;
+  }
+
+  getBookingBundleStatus(bundleHash: ActionHash): Promise<BookingBundleStatus> {
+    return this.callZome('get_booking_bundle_status', bundleHash);
+  }
+
+  async getBookingRequestsForBundle(
+    bundleHash: ActionHash
+  ): Promise<Array<EntryRecord<BookingRequest>>> {
+    const records: Record[] = await this.callZome(
+      'get_booking_requests_for_bundle',
+      bundleHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  async getBookingsForBundle(
+    bundleHash: ActionHash
+  ): Promise<Array<EntryRecord<Booking>>> {
+    const records: Record[] = await this.callZome(
+      'get_bookings_for_bundle',
+      bundleHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  cancelBookingBundle(bundleHash: ActionHash, reason: string): Promise<void> {
+    return this.callZome('cancel_booking_bundle', {
+      bundle_hash: bundleHash,
+      reason,
+    });
+  }
+
+  chargeBundleBookings(bundleHash: ActionHash): Promise<Array<ActionHash>> {
+    return this.callZome('charge_bundle_bookings', bundleHash);
+  }
+
+  /** Credit Transfers */
+
+  async getCreditTransfersForBooking(
+    bookingHash: ActionHash
+  ): Promise<Array<EntryRecord<CreditTransfer>>> {
+    const records: Record[] = await this.callZome(
+      'get_credit_transfers_for_booking',
+      bookingHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  async getCreditTransfersForAgent(
+    agent: AgentPubKey
+  ): Promise<Array<EntryRecord<CreditTransfer>>> {
+    const records: Record[] = await this.callZome(
+      'get_credit_transfers_for_agent',
+      agent
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  getCreditBalance(agent: AgentPubKey): Promise<{ [unit: string]: number }> {
+    return this.callZome('get_credit_balance', agent);
+  }
+
+  /** Invoices */
+
+  async createInvoice(
+    customer: AgentPubKey,
+    bookingHashes: Array<ActionHash>,
+    taxRateBasisPoints: number
+  ): Promise<EntryRecord<Invoice>> {
+    const record: Record = await this.callZome('create_invoice', {
+      customer,
+      booking_hashes: bookingHashes,
+      tax_rate_basis_points: taxRateBasisPoints,
+    });
+    return new EntryRecord(record);
+  }
+
+  async getInvoice(
+    invoiceHash: ActionHash
+  ): Promise<EntryRecord<Invoice> | undefined> {
+    const record: Record = await this.callZome('get_invoice', invoiceHash);
+    return record ? new EntryRecord(record) : undefined;
+  }
+
+  async updateInvoiceStatus(
+    originalInvoiceHash: ActionHash,
+    status: InvoiceStatus
+  ): Promise<EntryRecord<Invoice>> {
+    const record: Record = await this.callZome('update_invoice_status', {
+      original_invoice_hash: originalInvoiceHash,
+      status,
+    });
+    return new EntryRecord(record);
+  }
+
+  async getInvoicesForAgent(
+    agent: AgentPubKey
+  ): Promise<Array<EntryRecord<Invoice>>> {
+    const records: Record[] = await this.callZome(
+      'get_invoices_for_agent',
+      agent
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  renderInvoice(
+    invoiceHash: ActionHash,
+    format: 'Text' | 'Json'
+  ): Promise<string> {
+    return this.callZome('render_invoice', {
+      invoice_hash: invoiceHash,
+      format: { type: format },
+    });
+  }
+
+  /** Payments */
+
+  async acknowledgePayment(
+    paymentAcknowledgement: PaymentAcknowledgement
+  ): Promise<EntryRecord<PaymentAcknowledgement>> {
+    const record: Record = await this.callZome(
+      'acknowledge_payment',
+      paymentAcknowledgement
+    );
+    return new EntryRecord(record);
+  }
+
+  async getPaymentAcknowledgementsForBooking(
+    bookingHash: ActionHash
+  ): Promise<Array<EntryRecord<PaymentAcknowledgement>>> {
+    const records: Record[] = await this.callZome(
+      'get_payment_acknowledgements_for_booking',
+      bookingHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  getBookingPayment(bookingHash: ActionHash): Promise<BookingPayment> {
+    return this.callZome('get_booking_payment', bookingHash);
+  }
+
+  /** Booking Policy */
+
+  getBookingPolicy(): Promise<BookingPolicy> {
+    return this.callZome('get_booking_policy', null);
+  }
+
+  getBookingPolicyForResource(
+    resourceHash: ActionHash
+  ): Promise<BookingPolicy> {
+    return this.callZome('get_booking_policy_for_resource', resourceHash);
+  }
+
+  getMyQuotaUsage(): Promise<Array<QuotaUsage>> {
+    return this.callZome('get_my_quota_usage', null);
+  }
+
+  /** Allocation */
+
+  allocateSlot(
+    resourceHash: ActionHash,
+    startTime: number,
+    endTime: number,
+    deadline: number
+  ): Promise<AllocateSlotOutput> {
+    return this.callZome('allocate_slot', {
+      resource_hash: resourceHash,
+      start_time: startTime,
+      end_time: endTime,
+      deadline,
+    });
+  }
+
+  /** Resource Hierarchy */
+
+  addChildResource(
+    parentResourceHash: ActionHash,
+    childResourceHash: ActionHash
+  ): Promise<void> {
+    return this.callZome('add_child_resource', {
+      parent_resource_hash: parentResourceHash,
+      child_resource_hash: childResourceHash,
+    });
+  }
+
+  removeChildResource(
+    parentResourceHash: ActionHash,
+    childResourceHash: ActionHash
+  ): Promise<void> {
+    return this.callZome('remove_child_resource', {
+      parent_resource_hash: parentResourceHash,
+      child_resource_hash: childResourceHash,
+    });
+  }
+
+  async getChildResources(
+    resourceHash: ActionHash
+  ): Promise<Array<EntryRecord<Resource>>> {
+    const records: Record[] = await this.callZome(
+      'get_child_resources',
+      resourceHash
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
+  async getParentResource(
+    resourceHash: ActionHash
+  ): Promise<EntryRecord<Resource> | undefined> {
+    const record: Record = await this.callZome(
+      'get_parent_resource',
+      resourceHash
+    );
+    return record ? new EntryRecord(record) : undefined;
+  }
+
+  /** Resource Locations */
+
+  async getResourcesNear(
+    latitude: number,
+    longitude: number,
+    radiusMeters: number
+  ): Promise<Array<EntryRecord<Resource>>> {
+    const records: Record[] = await this.callZome('get_resources_near', {
+      latitude,
+      longitude,
+      radius_meters: radiusMeters,
+    });
+    return records.map(r => new EntryRecord(r));
+  }
+
+  /** Attribute Schemas */
+
+  async createAttributeSchema(
+    attributeSchema: AttributeSchema
+  ): Promise<EntryRecord<AttributeSchema>> {
+    const record: Record = await this.callZome(
+      'create_attribute_schema',
+      attributeSchema
+    );
+    return new EntryRecord(record);
+  }
+
+  async updateAttributeSchema(
+    previousAttributeSchemaHash: ActionHash,
+    updatedAttributeSchema: AttributeSchema
+  ): Promise<EntryRecord<AttributeSchema>> {
+    const record: Record = await this.callZome('update_attribute_schema', {
+      previous_attribute_schema_hash: previousAttributeSchemaHash,
+      updated_attribute_schema: updatedAttributeSchema,
+    });
+    return new EntryRecord(record);
+  }
+
+  async getAttributeSchemaForCategory(
+    category: string
+  ): Promise<EntryRecord<AttributeSchema> | undefined> {
+    const record: Record = await this.callZome(
+      'get_attribute_schema_for_category',
+      category
+    );
+    return record ? new EntryRecord(record) : undefined;
+  }
+
+  async getResourcesByAttributes(
+    category: string | undefined,
+    filters: Array<AttributeFilter>
+  ): Promise<Array<EntryRecord<Resource>>> {
+    const records: Record[] = await this.callZome(
+      'get_resources_by_attributes',
+      {
+        category,
+        filters,
+      }
+    );
+    return records.map(r => new EntryRecord(r));
+  }
+
   /** All Resources */
 
   async getAllResources(): Promise<Array<EntryRecord<Resource>>> {
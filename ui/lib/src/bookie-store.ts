@@ -35,6 +35,9 @@ export type RequestStatus =
   | {
       status: 'cancelled';
     }
+  | {
+      status: 'expired';
+    }
   | {
       status: 'accepted';
       bookingHash: ActionHash;
@@ -98,6 +101,13 @@ export class BookieStore {
                   } as RequestStatus,
                   bookingRequest,
                 };
+              } else if (requestAndDeletes.expired) {
+                return {
+                  status: {
+                    status: 'expired',
+                  } as RequestStatus,
+                  bookingRequest,
+                };
               } else {
                 return {
                   status: {
@@ -143,6 +153,15 @@ export class BookieStore {
     )
   );
 
+  /** Condition Report */
+
+  conditionReports = new LazyHoloHashMap((conditionReportHash: ActionHash) =>
+    lazyLoadAndPoll(
+      async () => this.client.getConditionReport(conditionReportHash),
+      4000
+    )
+  );
+
   /** All Resources */
 
   allResources = lazyLoadAndPoll(async () => {
@@ -7,11 +7,13 @@ import {
   sharedStyles,
   hashProperty,
   wrapPathInSvg,
+  notify,
   notifyError,
+  onSubmit,
 } from '@holochain-open-dev/elements';
 import { consume } from '@lit-labs/context';
 import { localized, msg } from '@lit/localize';
-import { mdiAlertCircleOutline, mdiPencil, mdiDelete } from '@mdi/js';
+import { mdiAlertCircleOutline, mdiCalendarClock, mdiCancel } from '@mdi/js';
 
 import '@shoelace-style/shoelace/dist/components/alert/alert.js';
 import '@shoelace-style/shoelace/dist/components/spinner/spinner.js';
@@ -20,9 +22,12 @@ import '@shoelace-style/shoelace/dist/components/card/card.js';
 import '@shoelace-style/shoelace/dist/components/format-date/format-date.js';
 import '@shoelace-style/shoelace/dist/components/icon-button/icon-button.js';
 import '@shoelace-style/shoelace/dist/components/button/button.js';
+import '@shoelace-style/shoelace/dist/components/dialog/dialog.js';
+import '@shoelace-style/shoelace/dist/components/textarea/textarea.js';
 import '@holochain-open-dev/elements/dist/elements/display-error.js';
 import SlAlert from '@shoelace-style/shoelace/dist/components/alert/alert.js';
-import './edit-booking.js';
+import SlDialog from '@shoelace-style/shoelace/dist/components/dialog/dialog.js';
+import './propose-reschedule.js';
 
 import { BookieStore } from '../bookie-store.js';
 import { bookieStoreContext } from '../context.js';
@@ -30,7 +35,7 @@ import { Booking } from '../types.js';
 
 /**
  * @element booking-detail
- * @fires booking-deleted: detail will contain { bookingHash }
+ * @fires booking-cancelled: detail will contain { bookingHash, cancellationHash }
  */
 @localized()
 @customElement('booking-detail')
@@ -60,23 +65,76 @@ export class BookingDetail extends LitElement {
   @state()
   _editing = false;
 
-  async deleteBooking() {
+  /**
+   * @internal
+   */
+  @state()
+  _cancelling = false;
+
+  async cancelBooking(fields: any) {
+    if (this._cancelling) return;
+    this._cancelling = true;
     try {
-      await this.bookieStore.client.deleteBooking(this.bookingHash);
+      const cancellation = await this.bookieStore.client.cancelBooking(
+        this.bookingHash,
+        fields.reason
+      );
 
+      notify(
+        cancellation.entry.fee_applies
+          ? msg(
+              'Booking cancelled. The cancellation was late, so the cancellation fee applies.'
+            )
+          : msg('Booking cancelled. No cancellation fee applies.')
+      );
+      (this.shadowRoot?.querySelector('sl-dialog') as SlDialog).hide();
       this.dispatchEvent(
-        new CustomEvent('booking-deleted', {
+        new CustomEvent('booking-cancelled', {
           bubbles: true,
           composed: true,
           detail: {
             bookingHash: this.bookingHash,
+            cancellationHash: cancellation.actionHash,
           },
         })
       );
     } catch (e: any) {
       console.error(e);
-      notifyError(msg('Error deleting the booking'));
+      notifyError(msg('Error cancelling the booking'));
     }
+    this._cancelling = false;
+  }
+
+  renderCancelDialog() {
+    return html`<sl-dialog
+      .label=${msg('Cancel Booking')}
+      @sl-request-close=${(e: Event) => {
+        if (this._cancelling) e.preventDefault();
+      }}
+    >
+      <form
+        style="display: flex; flex-direction: column"
+        ${onSubmit(fields => this.cancelBooking(fields))}
+      >
+        <span style="margin-bottom: 16px"
+          >${msg(
+            'Late cancellations may be charged the cancellation fee of the resource.'
+          )}</span
+        >
+        <sl-textarea
+          name="reason"
+          .label=${msg('Reason')}
+          required
+        ></sl-textarea>
+        <sl-button
+          style="margin-top: 16px"
+          variant="danger"
+          type="submit"
+          .loading=${this._cancelling}
+          >${msg('Cancel Booking')}</sl-button
+        >
+      </form>
+    </sl-dialog>`;
   }
 
   renderDetail(entryRecord: EntryRecord<Booking>) {
@@ -87,15 +145,18 @@ export class BookingDetail extends LitElement {
 
           <sl-icon-button
             style="margin-left: 8px"
-            .src=${wrapPathInSvg(mdiPencil)}
+            .src=${wrapPathInSvg(mdiCalendarClock)}
+            .label=${msg('Propose a new time')}
             @click=${() => {
               this._editing = true;
             }}
           ></sl-icon-button>
           <sl-icon-button
             style="margin-left: 8px"
-            .src=${wrapPathInSvg(mdiDelete)}
-            @click=${() => this.deleteBooking()}
+            .src=${wrapPathInSvg(mdiCancel)}
+            .label=${msg('Cancel booking')}
+            @click=${() =>
+              (this.shadowRoot?.querySelector('sl-dialog') as SlDialog).show()}
           ></sl-icon-button>
         </div>
 
@@ -138,6 +199,7 @@ export class BookingDetail extends LitElement {
           </div>
         </div>
       </sl-card>
+      ${this.renderCancelDialog()}
     `;
   }
 
@@ -160,16 +222,16 @@ export class BookingDetail extends LitElement {
           >`;
 
         if (this._editing) {
-          return html`<edit-booking
+          return html`<propose-reschedule
             .currentRecord=${booking}
-            @booking-updated=${async () => {
+            @reschedule-proposed=${async () => {
               this._editing = false;
             }}
             @edit-canceled=${() => {
               this._editing = false;
             }}
             style="display: flex; flex: 1;"
-          ></edit-booking>`;
+          ></propose-reschedule>`;
         }
 
         return this.renderDetail(booking);
@@ -0,0 +1,110 @@
+import { LitElement, html } from 'lit';
+import { property, customElement } from 'lit/decorators.js';
+import { ActionHash } from '@holochain/client';
+import { EntryRecord } from '@holochain-open-dev/utils';
+import { StoreSubscriber } from '@holochain-open-dev/stores';
+import { hashProperty, sharedStyles } from '@holochain-open-dev/elements';
+import { consume } from '@lit-labs/context';
+
+import { localized, msg } from '@lit/localize';
+
+import '@shoelace-style/shoelace/dist/components/spinner/spinner.js';
+import '@holochain-open-dev/elements/dist/elements/display-error.js';
+import '@shoelace-style/shoelace/dist/components/card/card.js';
+import '@shoelace-style/shoelace/dist/components/tag/tag.js';
+
+import { BookieStore } from '../bookie-store';
+import { bookieStoreContext } from '../context';
+import { ConditionReport } from '../types';
+
+/**
+ * @element condition-report-detail
+ * @slot attachments - Attachments of the report, like photos of the damage
+ */
+@localized()
+@customElement('condition-report-detail')
+export class ConditionReportDetail extends LitElement {
+  // REQUIRED. The hash of the original ConditionReport to show
+  @property(hashProperty('condition-report-hash'))
+  conditionReportHash!: ActionHash;
+
+  /**
+   * @internal
+   */
+  @consume({ context: bookieStoreContext, subscribe: true })
+  bookieStore!: BookieStore;
+
+  /**
+   * @internal
+   */
+  _conditionReport = new StoreSubscriber(
+    this,
+    () => this.bookieStore.conditionReports.get(this.conditionReportHash),
+    () => [this.conditionReportHash]
+  );
+
+  renderSeverity(conditionReport: ConditionReport) {
+    switch (conditionReport.severity.type) {
+      case 'Minor':
+        return html`<sl-tag variant="neutral">${msg('Minor')}</sl-tag>`;
+      case 'NeedsAttention':
+        return html`<sl-tag variant="warning"
+          >${msg('Needs attention')}</sl-tag
+        >`;
+      case 'OutOfService':
+        return html`<sl-tag variant="danger">${msg('Out of service')}</sl-tag>`;
+    }
+  }
+
+  renderDetail(conditionReport: EntryRecord<ConditionReport>) {
+    return html`
+      <div class="row" style="flex: 1">
+        <sl-card style="flex: 1; margin-right: 16px">
+          <div style="display: flex; flex-direction: column">
+            <div class="row" style="margin-bottom: 16px">
+              ${this.renderSeverity(conditionReport.entry)}
+              ${conditionReport.entry.resolved
+                ? html`<sl-tag variant="success" style="margin-left: 8px"
+                    >${msg('Resolved')}</sl-tag
+                  >`
+                : html``}
+            </div>
+
+            <span style="white-space: pre-line"
+              >${conditionReport.entry.description}</span
+            >
+          </div>
+        </sl-card>
+
+        <div class="column">
+          <slot name="attachments"></slot>
+        </div>
+      </div>
+    `;
+  }
+
+  render() {
+    switch (this._conditionReport.value.status) {
+      case 'pending':
+        return html`<div
+          style="display: flex; flex: 1; align-items: center; justify-content: center"
+        >
+          <sl-spinner style="font-size: 2rem;"></sl-spinner>
+        </div>`;
+      case 'complete':
+        if (!this._conditionReport.value.value)
+          return html`<span
+            >${msg("The requested condition report doesn't exist.")}</span
+          >`;
+
+        return this.renderDetail(this._conditionReport.value.value);
+      case 'error':
+        return html`<display-error
+          .headline=${msg('Error fetching the condition report')}
+          .error=${this._conditionReport.value.error.data.data}
+        ></display-error>`;
+    }
+  }
+
+  static styles = [sharedStyles];
+}
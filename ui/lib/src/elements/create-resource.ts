@@ -26,6 +26,7 @@ import '@holochain-open-dev/elements/dist/elements/display-error.js';
 import { BookieStore } from '../bookie-store.js';
 import { bookieStoreContext } from '../context.js';
 import { Resource } from '../types.js';
+import { uploadedFileHashes } from '../utils.js';
 import SlDialog from '@shoelace-style/shoelace/dist/components/dialog/dialog.js';
 
 /**
@@ -64,7 +65,9 @@ export class CreateResource extends LitElement {
     const resource: Resource = {
       name: fields.name,
       description: fields.description,
-      image_hash: fields.image_hash,
+      image_hash: fields.image_hash || undefined,
+      gallery: uploadedFileHashes(fields.gallery),
+      documents: uploadedFileHashes(fields.documents),
     };
 
     try {
@@ -118,14 +121,26 @@ export class CreateResource extends LitElement {
           ></sl-textarea>
         </div>
 
-        <span style="margin-bottom: 4px">${msg('Image')}*</span>
+        <span style="margin-bottom: 4px">${msg('Image')}</span>
         <upload-files
           name="image_hash"
           one-file
           accepted-files="image/jpeg,image/png,image/gif"
-          required
         ></upload-files>
 
+        <span style="margin-top: 16px; margin-bottom: 4px"
+          >${msg('Gallery')}</span
+        >
+        <upload-files
+          name="gallery"
+          accepted-files="image/jpeg,image/png,image/gif"
+        ></upload-files>
+
+        <span style="margin-top: 16px; margin-bottom: 4px"
+          >${msg('Documents')}</span
+        >
+        <upload-files name="documents"></upload-files>
+
         <sl-button
           style="margin-top: 16px"
           variant="primary"
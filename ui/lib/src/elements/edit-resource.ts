@@ -27,6 +27,7 @@ import '@shoelace-style/shoelace/dist/components/input/input.js';
 import { BookieStore } from '../bookie-store';
 import { bookieStoreContext } from '../context';
 import { Resource } from '../types';
+import { uploadedFileHashes } from '../utils';
 
 /**
  * @element edit-resource
@@ -61,9 +62,12 @@ export class EditResource extends LitElement {
 
   async updateResource(fields: any) {
     const resource: Resource = {
+      ...this.currentRecord.entry,
       name: fields.name,
       description: fields.description,
-      image_hash: fields.image_hash,
+      image_hash: fields.image_hash || undefined,
+      gallery: uploadedFileHashes(fields.gallery),
+      documents: uploadedFileHashes(fields.documents),
     };
 
     try {
@@ -124,11 +128,25 @@ export class EditResource extends LitElement {
             name="image_hash"
             one-file
             accepted-files="image/jpeg,image/png,image/gif"
-            required
             .defaultValue=${this.currentRecord.entry.image_hash}
           ></upload-files>
         </div>
 
+        <div style="margin-bottom: 16px">
+          <upload-files
+            name="gallery"
+            accepted-files="image/jpeg,image/png,image/gif"
+            .defaultValue=${this.currentRecord.entry.gallery}
+          ></upload-files>
+        </div>
+
+        <div style="margin-bottom: 16px">
+          <upload-files
+            name="documents"
+            .defaultValue=${this.currentRecord.entry.documents}
+          ></upload-files>
+        </div>
+
         <div style="display: flex; flex-direction: row">
           <sl-button
             @click=${() =>
@@ -1,39 +1,33 @@
 import { LitElement, html } from 'lit';
-import { repeat } from 'lit/directives/repeat.js';
 import { state, customElement, property } from 'lit/decorators.js';
-import { ActionHash, Record, EntryHash, AgentPubKey } from '@holochain/client';
 import { EntryRecord } from '@holochain-open-dev/utils';
 import {
-  hashState,
   notifyError,
   sharedStyles,
-  hashProperty,
-  wrapPathInSvg,
   onSubmit,
 } from '@holochain-open-dev/elements';
 import { consume } from '@lit-labs/context';
 import { localized, msg } from '@lit/localize';
-import { mdiAlertCircleOutline, mdiDelete } from '@mdi/js';
 
-import '@shoelace-style/shoelace/dist/components/icon/icon.js';
 import '@shoelace-style/shoelace/dist/components/card/card.js';
-import '@shoelace-style/shoelace/dist/components/alert/alert.js';
 import '@shoelace-style/shoelace/dist/components/button/button.js';
 import '@shoelace-style/shoelace/dist/components/input/input.js';
 
-import SlAlert from '@shoelace-style/shoelace/dist/components/alert/alert.js';
 import { BookieStore } from '../bookie-store';
 import { bookieStoreContext } from '../context';
 import { Booking } from '../types';
 
 /**
- * @element edit-booking
- * @fires booking-updated: detail will contain { previousBookingHash, updatedBookingHash }
+ * Bookings can only change through an accepted reschedule proposal, so this proposes the
+ * new time range to the other party instead of updating the booking
+ *
+ * @element propose-reschedule
+ * @fires reschedule-proposed: detail will contain { bookingHash, rescheduleProposalHash }
  */
 @localized()
-@customElement('edit-booking')
-export class EditBooking extends LitElement {
-  // REQUIRED. The current Booking record that should be updated
+@customElement('propose-reschedule')
+export class ProposeReschedule extends LitElement {
+  // REQUIRED. The current Booking record that should be rescheduled
   @property()
   currentRecord!: EntryRecord<Booking>;
 
@@ -53,36 +47,28 @@ export class EditBooking extends LitElement {
     this.shadowRoot?.querySelector('form')!.reset();
   }
 
-  async updateBooking(fields: any) {
-    const booking: Booking = {
-      title: fields.title,
-      bookers: this.currentRecord.entry.bookers,
-      start_time: new Date(fields.start_time).valueOf() * 1000,
-      end_time: new Date(fields.end_time).valueOf() * 1000,
-      booking_request_hash: this.currentRecord.entry.booking_request_hash,
-      resource_hash: this.currentRecord.entry.resource_hash,
-    };
-
+  async proposeReschedule(fields: any) {
     try {
       this.committing = true;
-      const updateRecord = await this.bookieStore.client.updateBooking(
+      const proposalRecord = await this.bookieStore.client.proposeReschedule(
         this.currentRecord.actionHash,
-        booking
+        new Date(fields.start_time).valueOf() * 1000,
+        new Date(fields.end_time).valueOf() * 1000
       );
 
       this.dispatchEvent(
-        new CustomEvent('booking-updated', {
+        new CustomEvent('reschedule-proposed', {
           composed: true,
           bubbles: true,
           detail: {
-            previousBookingHash: this.currentRecord.actionHash,
-            updatedBookingHash: updateRecord.actionHash,
+            bookingHash: this.currentRecord.actionHash,
+            rescheduleProposalHash: proposalRecord.actionHash,
           },
         })
       );
     } catch (e: any) {
       console.error(e);
-      notifyError(msg('Error updating the booking'));
+      notifyError(msg('Error proposing the new time'));
     }
 
     this.committing = false;
@@ -90,21 +76,12 @@ export class EditBooking extends LitElement {
 
   render() {
     return html` <sl-card>
-      <span slot="header">${msg('Edit Booking')}</span>
+      <span slot="header">${msg('Propose a New Time')}</span>
 
       <form
         style="display: flex; flex: 1; flex-direction: column;"
-        ${onSubmit(fields => this.updateBooking(fields))}
+        ${onSubmit(fields => this.proposeReschedule(fields))}
       >
-        <div style="margin-bottom: 16px">
-          <sl-input
-            name="title"
-            .label=${msg('Title')}
-            required
-            .defaultValue=${this.currentRecord.entry.title}
-          ></sl-input>
-        </div>
-
         <div style="margin-bottom: 16px">
           <sl-input
             name="start_time"
@@ -148,7 +125,7 @@ export class EditBooking extends LitElement {
             variant="primary"
             style="flex: 1;"
             .loading=${this.committing}
-            >${msg('Save')}</sl-button
+            >${msg('Propose')}</sl-button
           >
         </div>
       </form>
@@ -98,11 +98,13 @@ export class ResourceDetail extends LitElement {
       <div class="column" style="align-items: center; margin-top: 32px">
         <div class="row">
           <sl-card style="max-width: 600px; margin-right: 16px">
-            <show-image
-              style="max-height: 400px"
-              slot="image"
-              .imageHash=${entryRecord.entry.image_hash}
-            ></show-image>
+            ${entryRecord.entry.image_hash
+              ? html`<show-image
+                  style="max-height: 400px"
+                  slot="image"
+                  .imageHash=${entryRecord.entry.image_hash}
+                ></show-image>`
+              : html``}
             <div
               slot="header"
               style="display: flex; flex-direction: row; align-items: center"
@@ -142,6 +144,18 @@ export class ResourceDetail extends LitElement {
                   >${entryRecord.entry.description}</span
                 >
               </div>
+
+              ${entryRecord.entry.gallery?.length
+                ? html`<div class="row" style="flex-wrap: wrap; gap: 8px">
+                    ${entryRecord.entry.gallery.map(
+                      imageHash =>
+                        html`<show-image
+                          style="width: 120px; height: 90px"
+                          .imageHash=${imageHash}
+                        ></show-image>`
+                    )}
+                  </div>`
+                : html``}
             </div>
           </sl-card>
 
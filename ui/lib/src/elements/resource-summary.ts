@@ -44,11 +44,13 @@ export class ResourceSummary extends LitElement {
 
   renderSummary(entryRecord: EntryRecord<Resource>) {
     return html`
-      <show-image
-        slot="image"
-        .imageHash=${entryRecord.entry.image_hash}
-        style="width: 300px; height: 200px"
-      ></show-image>
+      ${entryRecord.entry.image_hash
+        ? html`<show-image
+            slot="image"
+            .imageHash=${entryRecord.entry.image_hash}
+            style="width: 300px; height: 200px"
+          ></show-image>`
+        : html``}
       <div style="display: flex; flex-direction: column">
         <span style="white-space: pre-line">${entryRecord.entry.name}</span>
       </div>
@@ -38,19 +38,195 @@ export type BookieSignal =
       type: 'LinkDeleted';
       action: SignedActionHashed<DeleteLink>;
       link_type: string;
+    }
+  | {
+      type: 'Notification';
+      notification: Notification;
+    };
+
+export type Notification =
+  | {
+      type: 'WaitlistPromoted';
+      booking_request_hash: ActionHash;
+    }
+  | {
+      type: 'BookingCancelled';
+      booking_hash: ActionHash;
+      cancellation_hash: ActionHash;
+    }
+  | {
+      type: 'RescheduleProposed';
+      booking_hash: ActionHash;
+      reschedule_proposal_hash: ActionHash;
+    }
+  | {
+      type: 'RescheduleAccepted';
+      booking_hash: ActionHash;
+      reschedule_proposal_hash: ActionHash;
+    }
+  | {
+      type: 'ConditionReported';
+      resource_hash: ActionHash;
+      condition_report_hash: ActionHash;
+    }
+  | {
+      type: 'PaymentAcknowledged';
+      booking_hash: ActionHash;
+      payment_acknowledgement_hash: ActionHash;
+    }
+  | {
+      type: 'BookingInvitationReceived';
+      booking_hash: ActionHash;
+      booking_invitation_hash: ActionHash;
+    }
+  | {
+      type: 'BookingInvitationAnswered';
+      booking_hash: ActionHash;
+      booking_invitation_hash: ActionHash;
+      accepted: boolean;
+    }
+  | {
+      type: 'BookingBundleConfirmed';
+      bundle_hash: ActionHash;
+    }
+  | {
+      type: 'BookingBundleRejected';
+      bundle_hash: ActionHash;
+      resource_hash: ActionHash;
+      unreleased_resource_hashes: Array<ActionHash>;
     };
 
 export type EntryTypes =
+  | ({ type: 'AttributeSchema' } & AttributeSchema)
   | ({ type: 'Booking' } & Booking)
+  | ({ type: 'BookingBundle' } & BookingBundle)
+  | ({ type: 'BookingCancellation' } & BookingCancellation)
+  | ({ type: 'BookingInvitation' } & BookingInvitation)
+  | ({ type: 'BookingInvitationResponse' } & BookingInvitationResponse)
   | ({ type: 'BookingRequest' } & BookingRequest)
-  | ({ type: 'Resource' } & Resource);
+  | ({ type: 'ConditionReport' } & ConditionReport)
+  | ({ type: 'CreditTransfer' } & CreditTransfer)
+  | ({ type: 'Hold' } & Hold)
+  | ({ type: 'Invoice' } & Invoice)
+  | ({ type: 'MaintenanceTask' } & MaintenanceTask)
+  | ({ type: 'MaintenanceWindow' } & MaintenanceWindow)
+  | ({ type: 'PaymentAcknowledgement' } & PaymentAcknowledgement)
+  | ({ type: 'RescheduleProposal' } & RescheduleProposal)
+  | ({ type: 'Resource' } & Resource)
+  | ({ type: 'UsageEvent' } & UsageEvent);
 
 export interface Resource {
   name: string;
 
   description: string;
 
-  image_hash: EntryHash;
+  image_hash?: EntryHash;
+
+  booking_policy?: ResourceBookingPolicy;
+
+  pricing?: PricingModel;
+
+  category?: string;
+
+  capacity?: number;
+
+  location?: Location;
+
+  attribute_schema_hash?: ActionHash;
+
+  attributes?: { [name: string]: AttributeValue };
+
+  gallery?: Array<EntryHash>;
+
+  documents?: Array<EntryHash>;
+}
+
+export type AttributeType =
+  | { type: 'String' }
+  | { type: 'Number' }
+  | { type: 'Bool' }
+  | { type: 'Enum'; options: Array<string> };
+
+export interface AttributeDefinition {
+  name: string;
+
+  attribute_type: AttributeType;
+
+  required: boolean;
+}
+
+export type AttributeValue =
+  | { type: 'String'; value: string }
+  | { type: 'Number'; value: number }
+  | { type: 'Bool'; value: boolean };
+
+export interface AttributeSchema {
+  category: string;
+
+  attributes: Array<AttributeDefinition>;
+}
+
+export type AttributeCondition =
+  | { type: 'Equals'; value: AttributeValue }
+  | { type: 'AtLeast'; value: number }
+  | { type: 'AtMost'; value: number };
+
+export interface AttributeFilter {
+  name: string;
+
+  condition: AttributeCondition;
+}
+
+export interface Location {
+  address: string;
+
+  latitude: number;
+
+  longitude: number;
+}
+
+export interface PriceTier {
+  from_unit: number;
+
+  amount_per_unit: number;
+}
+
+export interface PeakRate {
+  weekdays: Array<number>;
+
+  start_hour: number;
+
+  end_hour: number;
+
+  surcharge_per_hour: number;
+}
+
+export interface PricingModel {
+  currency: string;
+
+  flat_fee: number;
+
+  unit: { type: 'Hour' } | { type: 'Day' };
+
+  amount_per_unit: number;
+
+  tiers: Array<PriceTier>;
+
+  peak_rates: Array<PeakRate>;
+
+  utc_offset_seconds: number;
+
+  deposit_amount?: number;
+}
+
+export interface BookingPrice {
+  amount: number;
+
+  currency: string;
+
+  quantity: number;
+
+  resource_version_hash: ActionHash;
 }
 
 export interface BookingRequest {
@@ -63,6 +239,8 @@ export interface BookingRequest {
   start_time: number;
 
   end_time: number;
+
+  bundle_hash?: ActionHash;
 }
 
 export interface Booking {
@@ -77,4 +255,364 @@ export interface Booking {
   booking_request_hash: ActionHash | undefined;
 
   resource_hash: ActionHash;
+
+  resource_version_hash?: ActionHash;
+
+  reschedule_proposal_hash?: ActionHash;
+
+  price?: BookingPrice;
+}
+
+export interface RescheduleProposal {
+  booking_hash: ActionHash;
+
+  start_time: number;
+
+  end_time: number;
+
+  counter_to: ActionHash | undefined;
+}
+
+export interface BookingCancellation {
+  booking_hash: ActionHash;
+
+  booking_version_hash: ActionHash;
+
+  reason: string;
+
+  fee_applies: boolean;
+}
+
+export interface BookingInvitation {
+  booking_hash: ActionHash;
+
+  invitee: AgentPubKey;
+}
+
+export interface BookingInvitationResponse {
+  booking_invitation_hash: ActionHash;
+
+  accepted: boolean;
+
+  inviter_activity_hash: ActionHash;
+}
+
+export interface BookingBundle {
+  title: string;
+
+  comment: string;
+
+  start_time: number;
+
+  end_time: number;
+
+  resource_hashes: Array<ActionHash>;
+}
+
+export interface RequestBookingBundleOutput {
+  booking_bundle: Record;
+
+  booking_requests: Array<Record>;
+}
+
+export type BookingBundleStatus =
+  | { type: 'Pending'; accepted: number; total: number }
+  | { type: 'Confirmed' }
+  | { type: 'Rejected'; resource_hashes: Array<ActionHash> }
+  | { type: 'Cancelled' };
+
+export interface UsageEvent {
+  booking_hash: ActionHash;
+
+  event_type: { type: 'CheckIn' } | { type: 'CheckOut' };
+
+  check_in_hash: ActionHash | undefined;
+
+  odometer: number | undefined;
+
+  condition_note: string | undefined;
+
+  photo_hash: EntryHash | undefined;
+}
+
+export interface BookingUsage {
+  check_in: Record | undefined;
+
+  check_out: Record | undefined;
+
+  late_return: boolean;
+}
+
+export interface ConditionReport {
+  resource_hash: ActionHash;
+
+  booking_hash: ActionHash | undefined;
+
+  severity:
+    | { type: 'Minor' }
+    | { type: 'NeedsAttention' }
+    | { type: 'OutOfService' };
+
+  description: string;
+
+  resolved: boolean;
+}
+
+export type ResourceStatus =
+  | { type: 'Ok' }
+  | { type: 'NeedsAttention' }
+  | { type: 'OutOfService' };
+
+export type MaintenanceTrigger =
+  | { type: 'Interval'; interval_seconds: number }
+  | { type: 'Usage'; odometer_interval: number }
+  | { type: 'AfterEachUse' };
+
+export interface MaintenanceTask {
+  resource_hash: ActionHash;
+
+  title: string;
+
+  trigger: MaintenanceTrigger;
+
+  duration_seconds: number;
+}
+
+export interface MaintenanceWindow {
+  resource_hash: ActionHash;
+
+  maintenance_task_hash: ActionHash | undefined;
+
+  start_time: number;
+
+  end_time: number;
+}
+
+export interface UsageReportEntry {
+  resource_hash: ActionHash;
+
+  resource_name: string;
+
+  booker: AgentPubKey;
+
+  bookings_count: number;
+
+  booked_seconds: number;
+
+  used_seconds: number | undefined;
+}
+
+export interface UsageReport {
+  start_time: number;
+
+  end_time: number;
+
+  entries: Array<UsageReportEntry>;
+}
+
+export interface Hold {
+  resource_hash: ActionHash;
+
+  start_time: number;
+
+  end_time: number;
+
+  expires_at: number;
+}
+
+export interface BookingPolicy {
+  max_booking_duration_seconds: number | undefined;
+
+  min_booking_duration_seconds: number | undefined;
+
+  slot_granularity_seconds: number | undefined;
+
+  max_advance_booking_seconds: number | undefined;
+
+  min_notice_seconds: number | undefined;
+
+  buffer_seconds: number | undefined;
+
+  requests_require_approval: boolean;
+
+  auto_accept_requests: boolean;
+
+  max_concurrent_bookings_per_agent: number | undefined;
+
+  max_hold_seconds: number;
+
+  request_response_seconds: number | undefined;
+
+  cancellation_policy: CancellationPolicy | undefined;
+
+  credit_ledger: CreditLedgerSettings | undefined;
+
+  quotas: Array<BookingQuota>;
+
+  allocation_rule: AllocationRule | undefined;
+
+  attribute_schema_authority: AgentPubKey | undefined;
+}
+
+export interface PriorityRole {
+  name: string;
+
+  agents: Array<AgentPubKey>;
+}
+
+export type AllocationRule =
+  | { type: 'Priority'; roles: Array<PriorityRole> }
+  | { type: 'LeastRecentUsage' }
+  | { type: 'Lottery' };
+
+export interface AllocateSlotOutput {
+  bookings: Array<Record>;
+
+  rejected_booking_requests: Array<ActionHash>;
+}
+
+export interface BookingQuota {
+  scope:
+    | { type: 'EachResource' }
+    | { type: 'Category'; category: string }
+    | { type: 'AllResources' };
+
+  period: { type: 'Week' } | { type: 'Month' };
+
+  max_booked_seconds: number;
 }
+
+export interface QuotaUsage {
+  quota: BookingQuota;
+
+  resource_hash: ActionHash | undefined;
+
+  period_start: number;
+
+  period_end: number;
+
+  booked_seconds: number;
+}
+
+export interface CreditLedgerSettings {
+  unit: { type: 'Time' } | { type: 'Price' };
+
+  external_zome: string | undefined;
+}
+
+export interface CreditTransfer {
+  booking_hash: ActionHash;
+
+  from: AgentPubKey;
+
+  to: AgentPubKey;
+
+  amount: number;
+
+  unit: string;
+
+  refund_of: ActionHash | undefined;
+}
+
+export type InvoiceStatus =
+  | { type: 'Issued' }
+  | { type: 'Paid' }
+  | { type: 'Void' };
+
+export interface InvoiceLineItem {
+  booking_hash: ActionHash;
+
+  description: string;
+
+  amount: number;
+
+  invitation_response_hash: ActionHash | undefined;
+}
+
+export interface Invoice {
+  number: number;
+
+  customer: AgentPubKey;
+
+  currency: string;
+
+  line_items: Array<InvoiceLineItem>;
+
+  tax_rate_basis_points: number;
+
+  subtotal: number;
+
+  tax: number;
+
+  total: number;
+
+  status: InvoiceStatus;
+}
+
+export interface PaymentAcknowledgement {
+  booking_hash: ActionHash;
+
+  kind: { type: 'Deposit' } | { type: 'Payment' } | { type: 'Refund' };
+
+  amount: number;
+
+  currency: string;
+
+  note: string;
+}
+
+export type BookingPaymentStatus =
+  | { type: 'Unpaid' }
+  | { type: 'DepositPaid' }
+  | { type: 'FullyPaid' }
+  | { type: 'Refunded' };
+
+export interface BookingPayment {
+  status: BookingPaymentStatus;
+
+  paid_amount: number;
+
+  refunded_amount: number;
+
+  deposit_amount: number | undefined;
+
+  tentative: boolean;
+}
+
+export interface ResourceBookingPolicy {
+  slot_granularity_seconds?: number;
+
+  min_booking_duration_seconds?: number;
+
+  max_booking_duration_seconds?: number;
+
+  min_notice_seconds?: number;
+
+  buffer_seconds?: number;
+
+  requires_approval?: boolean;
+
+  auto_accept_requests?: boolean;
+
+  request_response_seconds?: number;
+
+  cancellation_policy?: CancellationPolicy;
+
+  allocation_rule?: AllocationRule;
+}
+
+export interface CancellationPolicy {
+  notice_seconds: number;
+
+  late_cancellation: { type: 'Forbidden' } | { type: 'AllowedWithFee' };
+}
+
+export type RequestBookingOutput =
+  | {
+      type: 'Accepted';
+      booking: Record;
+    }
+  | {
+      type: 'Pending';
+      booking_request: Record;
+    };
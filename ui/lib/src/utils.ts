@@ -1,5 +1,5 @@
 import { EntryRecord } from '@holochain-open-dev/utils';
-import { encodeHashToBase64 } from '@holochain/client';
+import { EntryHash, encodeHashToBase64 } from '@holochain/client';
 import { Booking, BookingRequest, Resource } from './types';
 
 export interface Event {
@@ -76,3 +76,11 @@ export function resourceToEventCalendarResource(
     title: resource.entry.name,
   };
 }
+
+// upload-files holds a single hash with one-file, and an array of hashes otherwise
+export function uploadedFileHashes(
+  value: EntryHash | Array<EntryHash> | undefined
+): Array<EntryHash> {
+  if (!value) return [];
+  return Array.isArray(value) ? value : [value];
+}